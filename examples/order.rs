@@ -5,7 +5,7 @@ use dotenv::dotenv;
 
 use paypal_rust::client::AppInfo;
 use paypal_rust::{
-    AmountWithBreakdown, Client, CreateOrderDto, CurrencyCode, Environment, Order,
+    AmountWithBreakdown, Client, CreateOrderDto, CurrencyCode, Environment, HateoasLinks, Order,
     OrderApplicationContext, OrderIntent, PurchaseUnitRequest,
 };
 
@@ -30,6 +30,7 @@ async fn main() {
         CreateOrderDto {
             intent: OrderIntent::Capture,
             payer: None,
+            payment_source: None,
             purchase_units: vec![PurchaseUnitRequest::new(AmountWithBreakdown::new(
                 CurrencyCode::Euro,
                 "10.00".to_string(),
@@ -46,7 +47,7 @@ async fn main() {
 
     println!(
         "Approval URL: {:?}. Waiting 15 seconds before capturing",
-        new_order.links.unwrap().get(1).unwrap().href
+        new_order.approve_url().unwrap().as_str()
     );
 
     sleep(Duration::from_secs(15));
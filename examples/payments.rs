@@ -28,6 +28,7 @@ async fn main() {
         CreateOrderDto {
             intent: OrderIntent::Authorize,
             payer: None,
+            payment_source: None,
             purchase_units: vec![PurchaseUnitRequest::new(AmountWithBreakdown::new(
                 CurrencyCode::Euro,
                 "10.00".to_string(),
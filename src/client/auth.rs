@@ -108,9 +108,14 @@ pub struct AuthData {
 
 impl AuthData {
     pub fn about_to_expire(&self) -> bool {
-        self.expiry_time.map_or(true, |expiry_time| {
-            expiry_time.sub(Utc::now()).num_seconds() < 10
-        })
+        self.about_to_expire_at(Utc::now())
+    }
+
+    /// Whether the access token expires within the next 10 seconds after `now`, or its expiry is
+    /// unknown.
+    pub fn about_to_expire_at(&self, now: DateTime<Utc>) -> bool {
+        self.expiry_time
+            .map_or(true, |expiry_time| expiry_time.sub(now).num_seconds() < 10)
     }
 
     pub fn update(&mut self, response: AuthResponse) {
@@ -120,3 +125,38 @@ impl AuthData {
             Some(Utc::now() + chrono::Duration::seconds(i64::from(response.expires_in)));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    #[test]
+    fn about_to_expire_at() {
+        let now = Utc.with_ymd_and_hms(2023, 5, 1, 12, 0, 0).unwrap();
+        let auth_data = |expiry_time| AuthData {
+            expiry_time,
+            ..Default::default()
+        };
+
+        assert!(auth_data(None).about_to_expire_at(now));
+        assert!(auth_data(Some(now - Duration::seconds(1))).about_to_expire_at(now));
+        assert!(auth_data(Some(now + Duration::seconds(9))).about_to_expire_at(now));
+        assert!(!auth_data(Some(now + Duration::seconds(10))).about_to_expire_at(now));
+        assert!(!auth_data(Some(now + Duration::seconds(32400))).about_to_expire_at(now));
+    }
+
+    #[test]
+    fn update_sets_expiry_time() {
+        let mut auth_data = AuthData::default();
+        auth_data.update(AuthResponse {
+            access_token: "A21AAFEpH4PsADK7qSS7pSRsgzfENtu-Q1ysgEDVDESseMHBYXVJYE8ovjj68elIDy8nF26AwPhfXTIeWAZHSLIsQkSYz9ifg".to_string(),
+            expires_in: 32400,
+            ..Default::default()
+        });
+
+        assert!(!auth_data.about_to_expire());
+        assert!(auth_data.about_to_expire_at(Utc::now() + Duration::seconds(32400)));
+    }
+}
@@ -1,13 +1,14 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
 
+use reqwest::multipart::Form;
 use reqwest::Url;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::client::auth::AuthStrategy;
-use crate::client::paypal::Environment;
-use crate::client::request::{HttpRequestHeaders, RequestStrategy, RequestUrl};
+use crate::client::error::PayPalError;
+use crate::client::request::{HttpRequestHeaders, RequestStrategy};
 
 pub trait Endpoint: Send + Sync {
     /// The query parameters the endpoint accepts.
@@ -35,7 +36,14 @@ pub trait Endpoint: Send + Sync {
         None
     }
 
-    /// The HTTP method to use for the request.
+    /// The `multipart/form-data` body to send with the request, instead of the JSON request body.
+    /// Only used for `POST` requests, for example to upload documents.
+    fn multipart_body(&self) -> Result<Option<Form>, PayPalError> {
+        Ok(None)
+    }
+
+    /// The HTTP method to use for the request. Defaults to `GET`, so only endpoints using another
+    /// method need to override this.
     fn request_method(&self) -> reqwest::Method {
         reqwest::Method::GET
     }
@@ -50,21 +58,38 @@ pub trait Endpoint: Send + Sync {
         AuthStrategy::default()
     }
 
-    /// The URL to send the request to. DO NOT OVERRIDE THIS METHOD.
-    fn request_url(&self, environment: Environment) -> Url {
-        let path = self.path();
-        let path = path.strip_prefix('/').unwrap_or(&path);
-
-        let mut request_url = match environment {
-            // Expects are fine here, as we can be sure that the URL is valid.
-            Environment::Sandbox => RequestUrl::Sandbox.as_url().expect("Invalid URL"),
-            Environment::Live => RequestUrl::Live.as_url().expect("Invalid URL"),
-        };
+    /// Deserializes the response body. Endpoints that do not respond with JSON need to override
+    /// this.
+    fn parse_response(&self, text: &str) -> Result<Self::ResponseBody, PayPalError> {
+        serde_json::from_str(text).or_else(|error| {
+            // Endpoints that return an empty response body can safely be deserialized into
+            // an empty struct.
+            if error.is_eof() {
+                Ok(serde_json::from_str("{}")?)
+            } else {
+                Err(error.into())
+            }
+        })
+    }
 
-        request_url.set_path(path);
-        request_url
+    /// The URL to send the request to, relative to the base URL of the client. The path of the
+    /// base URL is kept, so a base URL like `https://gateway.example.com/paypal` sends requests to
+    /// `https://gateway.example.com/paypal/v1/...`. Does not include the query parameters.
+    /// DO NOT OVERRIDE THIS METHOD.
+    fn request_url(&self, base_url: &Url) -> Url {
+        join_path(base_url, &self.path())
     }
 }
 
+/// Appends the path to the path of the base URL, instead of replacing it.
+pub(crate) fn join_path(base_url: &Url, path: &str) -> Url {
+    let path = path.strip_prefix('/').unwrap_or(path);
+    let base_path = base_url.path().strip_suffix('/').unwrap_or(base_url.path());
+
+    let mut url = base_url.clone();
+    url.set_path(&format!("{base_path}/{path}"));
+    url
+}
+
 #[derive(Debug, Copy, Clone, Deserialize, Default)]
 pub struct EmptyResponseBody {}
@@ -8,7 +8,7 @@ use std::fmt::Display;
 use thiserror::Error as ThisErr;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ErrorDetails {
     pub field: Option<String>,
     pub value: Option<String>,
@@ -17,12 +17,18 @@ pub struct ErrorDetails {
     pub description: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, ThisErr)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, ThisErr)]
 pub struct ValidationError {
+    /// The HTTP status code of the response. It is not part of the response body, so it is set by
+    /// the client after parsing the body.
+    #[serde(default, skip_serializing)]
+    pub status: u16,
     pub name: String,
     pub message: String,
     pub debug_id: Option<String>,
     pub details: Option<Vec<ErrorDetails>>,
+    /// Not every error response contains links.
+    #[serde(default)]
     pub links: Vec<LinkDescription>,
 }
 
@@ -30,8 +36,8 @@ impl Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "ValidationError: {} - {} - {:?} - {:?}\n Links: {:?}",
-            self.name, self.message, self.debug_id, self.details, self.links
+            "ValidationError ({}): {} - {} - {:?} - {:?}\n Links: {:?}",
+            self.status, self.name, self.message, self.debug_id, self.details, self.links
         )
     }
 }
@@ -45,18 +51,141 @@ pub trait ErrorResponse {
 pub enum PayPalError {
     Http(reqwest::Error),
     Json(serde_json::Error),
+    #[deprecated(note = "API errors are returned as `PayPalError::ApiError`")]
     Api(ValidationError),
+    /// An error response returned by the PayPal API.
+    ApiError {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The human-readable, unique name of the error.
+        name: String,
+        /// The message that describes the error.
+        message: String,
+        /// The PayPal internal ID. Used for correlation purposes.
+        debug_id: Option<String>,
+        /// An array of additional details about the error.
+        details: Vec<ErrorDetails>,
+    },
+    /// An error response of the PayPal API whose body is not a PayPal error, for example one
+    /// returned by a proxy in front of the API.
+    UnexpectedApiError {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The raw response body.
+        body: String,
+    },
     QueryString(serde_urlencoded::ser::Error),
     MissingAccessToken,
     LibraryError(String),
 }
 
+impl PayPalError {
+    /// Creates an `ApiError` from the error body of a response with the given status code.
+    pub fn from_api_response(status: u16, error: ValidationError) -> Self {
+        Self::from(ValidationError { status, ..error })
+    }
+
+    /// Creates an error from the body of a response with the given, unsuccessful status code.
+    /// Bodies that are not a PayPal error are returned as `UnexpectedApiError`, so the status code
+    /// is never lost.
+    pub fn from_error_response(status: u16, body: String) -> Self {
+        match serde_json::from_str::<ValidationError>(&body) {
+            Ok(error) => Self::from_api_response(status, error),
+            Err(_) => Self::UnexpectedApiError { status, body },
+        }
+    }
+
+    /// Converts an API error back into a `ValidationError`, to ease the migration away from the
+    /// deprecated `PayPalError::Api` variant. Returns `None` for all other errors.
+    #[allow(deprecated)]
+    pub fn into_validation_error(self) -> Option<ValidationError> {
+        match self {
+            Self::Api(error) => Some(error),
+            Self::ApiError {
+                status,
+                name,
+                message,
+                debug_id,
+                details,
+            } => Some(ValidationError {
+                status,
+                name,
+                message,
+                debug_id,
+                // Converting a `ValidationError` without details left an empty Vec.
+                details: Some(details).filter(|details| !details.is_empty()),
+                links: Vec::new(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Compares errors by variant. Wrapped errors of external crates are compared by their message.
+impl PartialEq for PayPalError {
+    #[allow(deprecated)]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Http(a), Self::Http(b)) => a.to_string() == b.to_string(),
+            (Self::Json(a), Self::Json(b)) => a.to_string() == b.to_string(),
+            (Self::Api(a), Self::Api(b)) => a == b,
+            (
+                Self::ApiError {
+                    status: a_status,
+                    name: a_name,
+                    message: a_message,
+                    debug_id: a_debug_id,
+                    details: a_details,
+                },
+                Self::ApiError {
+                    status: b_status,
+                    name: b_name,
+                    message: b_message,
+                    debug_id: b_debug_id,
+                    details: b_details,
+                },
+            ) => {
+                a_status == b_status
+                    && a_name == b_name
+                    && a_message == b_message
+                    && a_debug_id == b_debug_id
+                    && a_details == b_details
+            }
+            (
+                Self::UnexpectedApiError {
+                    status: a_status,
+                    body: a_body,
+                },
+                Self::UnexpectedApiError {
+                    status: b_status,
+                    body: b_body,
+                },
+            ) => a_status == b_status && a_body == b_body,
+            (Self::QueryString(a), Self::QueryString(b)) => a.to_string() == b.to_string(),
+            (Self::MissingAccessToken, Self::MissingAccessToken) => true,
+            (Self::LibraryError(a), Self::LibraryError(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
 impl Display for PayPalError {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Http(e) => write!(f, "HTTP error: {e}"),
             Self::Json(e) => write!(f, "Failed to serialize response body: {e}"),
             Self::Api(e) => write!(f, "API error: {e}"),
+            Self::ApiError {
+                status,
+                name,
+                message,
+                debug_id,
+                ..
+            } => write!(f, "API error ({status}): {name} - {message} - {debug_id:?}"),
+            Self::UnexpectedApiError { status, body } => {
+                write!(f, "Unexpected API error ({status}): {body}")
+            }
             Self::QueryString(e) => write!(f, "Failed to serialize query string: {e}"),
             Self::MissingAccessToken => write!(f, "Missing access token"),
             Self::LibraryError(e) => write!(f, "Library error: {e}"),
@@ -78,7 +207,13 @@ impl From<serde_json::Error> for PayPalError {
 
 impl From<ValidationError> for PayPalError {
     fn from(error: ValidationError) -> Self {
-        Self::Api(error)
+        Self::ApiError {
+            status: error.status,
+            name: error.name,
+            message: error.message,
+            debug_id: error.debug_id,
+            details: error.details.unwrap_or_default(),
+        }
     }
 }
 
@@ -99,3 +234,74 @@ impl From<Error> for PayPalError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PayPalError;
+
+    #[test]
+    fn error_response_with_paypal_error_body() {
+        let error = PayPalError::from_error_response(
+            422,
+            r#"{
+                "name": "UNPROCESSABLE_ENTITY",
+                "message": "The requested action could not be performed.",
+                "debug_id": "b2aaac7fd4a71",
+                "details": [{"issue": "ORDER_ALREADY_CAPTURED"}]
+            }"#
+            .to_string(),
+        );
+
+        match error {
+            PayPalError::ApiError {
+                status,
+                name,
+                debug_id,
+                details,
+                ..
+            } => {
+                assert_eq!(status, 422);
+                assert_eq!(name, "UNPROCESSABLE_ENTITY");
+                assert_eq!(debug_id.as_deref(), Some("b2aaac7fd4a71"));
+                assert_eq!(details[0].issue.as_deref(), Some("ORDER_ALREADY_CAPTURED"));
+            }
+            other => panic!("expected an API error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validation_error_round_trip_keeps_status() {
+        let error = PayPalError::from_error_response(
+            404,
+            r#"{"name": "RESOURCE_NOT_FOUND", "message": "The specified resource does not exist."}"#
+                .to_string(),
+        );
+        let validation_error = error.into_validation_error().unwrap();
+        assert_eq!(validation_error.status, 404);
+        assert_eq!(validation_error.name, "RESOURCE_NOT_FOUND");
+        assert_eq!(validation_error.details, None);
+
+        match PayPalError::from(validation_error) {
+            PayPalError::ApiError { status, .. } => assert_eq!(status, 404),
+            other => panic!("expected an API error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn error_response_with_unexpected_body_keeps_status() {
+        assert_eq!(
+            PayPalError::from_error_response(502, "<html>Bad Gateway</html>".to_string()),
+            PayPalError::UnexpectedApiError {
+                status: 502,
+                body: "<html>Bad Gateway</html>".to_string(),
+            }
+        );
+        assert_eq!(
+            PayPalError::from_error_response(404, String::new()),
+            PayPalError::UnexpectedApiError {
+                status: 404,
+                body: String::new(),
+            }
+        );
+    }
+}
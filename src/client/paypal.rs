@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use base64::{engine::general_purpose, Engine as _};
 use http_types::Url;
-use reqwest::header::AUTHORIZATION;
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE,
+};
 use reqwest::RequestBuilder;
 use reqwest_middleware;
 use reqwest_retry::policies::ExponentialBackoff;
@@ -12,8 +14,8 @@ use tokio::sync::RwLock;
 
 use crate::client::app_info::AppInfo;
 use crate::client::auth::{AuthData, AuthResponse, AuthStrategy, Authenticate};
-use crate::client::endpoint::Endpoint;
-use crate::client::error::{PayPalError, ValidationError};
+use crate::client::endpoint::{join_path, Endpoint};
+use crate::client::error::PayPalError;
 use crate::client::request;
 use crate::client::request::QueryParams;
 
@@ -70,9 +72,7 @@ impl Client {
     /// # Arguments
     ///  * `request_path` - The path to append to the base URL.
     pub fn compose_url(&self, request_path: &str) -> Url {
-        let mut url = self.base_url.clone();
-        url.set_path(request_path);
-        url
+        join_path(&self.base_url, request_path)
     }
 
     /// Composes an URL with query parameters.
@@ -90,13 +90,7 @@ impl Client {
         query: &QueryParams,
     ) -> Result<Url, serde_qs::Error> {
         let mut url = self.compose_url(request_path);
-        let params = serde_qs::to_string(query)?;
-
-        if params.is_empty() {
-            return Ok(url);
-        }
-
-        url.set_query(Some(&params));
+        set_query(&mut url, query)?;
         Ok(url)
     }
 
@@ -106,6 +100,34 @@ impl Client {
         self
     }
 
+    /// Sends all requests to the given base URL instead of the default URL of the environment,
+    /// for example to route them through a proxy or gateway.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: Url) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Returns the base URL all requests are sent to.
+    pub const fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Returns the environment the client is configured for.
+    pub const fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    /// Returns `true` if the client talks to the PayPal sandbox.
+    pub fn is_sandbox(&self) -> bool {
+        self.environment == Environment::Sandbox
+    }
+
+    /// Returns `true` if the client talks to the live PayPal API.
+    pub fn is_live(&self) -> bool {
+        self.environment == Environment::Live
+    }
+
     /// Performs a GET request.
     ///
     /// # Arguments
@@ -117,7 +139,7 @@ impl Client {
     /// # Errors
     /// Errors if the request fails or the response body cannot be deserialized.
     pub async fn get<T: Endpoint>(&self, endpoint: &T) -> Result<T::ResponseBody, PayPalError> {
-        let mut req = self.http.get(endpoint.request_url(self.environment));
+        let mut req = self.http.get(self.request_url(endpoint)?);
         req = self.set_request_headers(req, &endpoint.headers());
 
         let response = self.execute(endpoint, req).await?;
@@ -135,11 +157,10 @@ impl Client {
     /// # Errors
     /// Errors if the request fails or the response body cannot be deserialized.
     pub async fn post<T: Endpoint>(&self, endpoint: &T) -> Result<T::ResponseBody, PayPalError> {
-        let body = serde_json::to_string(&endpoint.request_body())?;
-        let mut req = self.http.post(endpoint.request_url(self.environment));
+        let mut req = self.http.post(self.request_url(endpoint)?);
 
-        req = self.set_request_headers(req, &endpoint.headers());
-        let response = self.execute(endpoint, req.body(body)).await?;
+        req = self.with_body(req, endpoint)?;
+        let response = self.execute(endpoint, req).await?;
 
         Ok(response)
     }
@@ -155,11 +176,31 @@ impl Client {
     /// # Errors
     /// Errors if the request fails or the response body cannot be deserialized.
     pub async fn patch<T: Endpoint>(&self, endpoint: &T) -> Result<T::ResponseBody, PayPalError> {
-        let body = serde_json::to_string(&endpoint.request_body())?;
-        let mut req = self.http.patch(endpoint.request_url(self.environment));
+        let mut req = self.http.patch(self.request_url(endpoint)?);
 
         req = self.set_request_headers(req, &endpoint.headers());
-        let response = self.execute(endpoint, req.body(body)).await?;
+        req = with_json_body(req, endpoint)?;
+        let response = self.execute(endpoint, req).await?;
+
+        Ok(response)
+    }
+
+    /// Performs a PUT request.
+    ///
+    /// # Arguments
+    /// * `endpoint` - The endpoint to call.
+    ///
+    /// # Returns
+    /// The response body serialized into the provided type.
+    ///
+    /// # Errors
+    /// Errors if the request fails or the response body cannot be deserialized.
+    pub async fn put<T: Endpoint>(&self, endpoint: &T) -> Result<T::ResponseBody, PayPalError> {
+        let mut req = self.http.put(self.request_url(endpoint)?);
+
+        req = self.set_request_headers(req, &endpoint.headers());
+        req = with_json_body(req, endpoint)?;
+        let response = self.execute(endpoint, req).await?;
 
         Ok(response)
     }
@@ -174,7 +215,7 @@ impl Client {
     /// # Errors
     /// Errors if the request fails or the response body cannot be deserialized.
     pub async fn delete<T: Endpoint>(&self, endpoint: &T) -> Result<T::ResponseBody, PayPalError> {
-        let mut req = self.http.delete(endpoint.request_url(self.environment));
+        let mut req = self.http.delete(self.request_url(endpoint)?);
         req = self.set_request_headers(req, &endpoint.headers());
 
         let response = self.execute(endpoint, req).await?;
@@ -182,7 +223,8 @@ impl Client {
         Ok(response)
     }
 
-    /// Sets the request headers for a request.
+    /// Sets the request headers for a request. `Accept: application/json` is set if the headers do
+    /// not specify an `Accept` header.
     ///
     /// # Arguments
     /// * `request_builder` - The request builder to set the headers on.
@@ -195,6 +237,10 @@ impl Client {
         mut request_builder: RequestBuilder,
         headers: &request::HttpRequestHeaders,
     ) -> RequestBuilder {
+        if headers.accept.is_empty() {
+            request_builder = request_builder.header(ACCEPT, "application/json");
+        }
+
         for (key, value) in headers.to_vec() {
             request_builder = request_builder.header(key, value);
         }
@@ -202,6 +248,48 @@ impl Client {
         request_builder
     }
 
+    /// Sets the headers and the body of a request. Endpoints with a multipart body are sent as
+    /// `multipart/form-data`, all others as JSON.
+    ///
+    /// # Errors
+    /// Errors if the body cannot be serialized.
+    fn with_body<T: Endpoint>(
+        &self,
+        request_builder: RequestBuilder,
+        endpoint: &T,
+    ) -> Result<RequestBuilder, PayPalError> {
+        match endpoint.multipart_body()? {
+            Some(form) => {
+                // The multipart body sets its own content type, including the boundary.
+                let mut headers = endpoint.headers();
+                headers.content_type.clear();
+                Ok(self
+                    .set_request_headers(request_builder, &headers)
+                    .multipart(form))
+            }
+            None => with_json_body(
+                self.set_request_headers(request_builder, &endpoint.headers()),
+                endpoint,
+            ),
+        }
+    }
+
+    /// Builds the URL for a request, including the query parameters of the endpoint.
+    ///
+    /// # Errors
+    /// Errors if the query parameters cannot be serialized.
+    fn request_url<T: Endpoint>(&self, endpoint: &T) -> Result<Url, PayPalError> {
+        let mut url = endpoint.request_url(&self.base_url);
+
+        if let Some(query) = endpoint.query() {
+            set_query(&mut url, &query).map_err(|error| {
+                PayPalError::LibraryError(format!("Failed to serialize query string: {error}"))
+            })?;
+        }
+
+        Ok(url)
+    }
+
     /// Executes a request.
     ///
     /// # Arguments
@@ -227,27 +315,14 @@ impl Client {
         );
 
         let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
 
-        println!("Got response: {:?}", &response);
-
-        if !response.status().is_success() {
-            return Err(PayPalError::from(response.json::<ValidationError>().await?));
+        if !status.is_success() {
+            return Err(PayPalError::from_error_response(status.as_u16(), text));
         }
 
-        let text = response.text().await;
-
-        println!("Got response text: {:?}", &text);
-
-        serde_json::from_str::<T::ResponseBody>(&text?).or_else(|error| {
-            println!("Got error: {:?}", &error);
-            // Endpoints that return an empty response body can safely be deserialized into
-            // an empty struct.
-            if error.is_eof() {
-                Ok(serde_json::from_str::<T::ResponseBody>("{}")?)
-            } else {
-                Err(error.into())
-            }
-        })
+        endpoint.parse_response(&text)
     }
 
     /// Authenticates the client with PayPal. This gets called automatically when the auth strategy
@@ -265,7 +340,7 @@ impl Client {
 
         let mut request = self
             .http
-            .post(endpoint.request_url(self.environment))
+            .post(endpoint.request_url(&self.base_url))
             .body(serde_urlencoded::to_string(endpoint.request_body())?);
 
         let mut retries = 0;
@@ -291,6 +366,72 @@ impl Client {
         self.auth_data.write().await.update(parsed_response);
         Ok(())
     }
+
+    /// Refreshes the access token if it is about to expire. Useful to refresh the token ahead of a
+    /// burst of requests instead of in the middle of it.
+    ///
+    /// # Returns
+    /// `true` if the access token was refreshed.
+    ///
+    /// # Errors
+    /// Errors if the authentication request fails.
+    pub async fn refresh_token_if_needed(&self) -> Result<bool, PayPalError> {
+        if !self.auth_data.read().await.about_to_expire() {
+            return Ok(false);
+        }
+
+        self.authenticate().await?;
+        Ok(true)
+    }
+
+    /// Refreshes the access token, regardless of whether it is about to expire.
+    ///
+    /// # Errors
+    /// Errors if the authentication request fails.
+    pub async fn force_refresh(&self) -> Result<(), PayPalError> {
+        self.authenticate().await
+    }
+}
+
+/// Sets the query string of the URL. Empty query parameters leave the URL untouched.
+///
+/// # Errors
+/// Errors if the query parameters cannot be serialized.
+fn set_query<Q: Serialize>(url: &mut Url, query: &Q) -> Result<(), serde_qs::Error> {
+    let params = serde_qs::to_string(query)?;
+
+    if !params.is_empty() {
+        url.set_query(Some(&params));
+    }
+
+    Ok(())
+}
+
+/// Sets the JSON request body of the endpoint and `Content-Type: application/json`, replacing the
+/// content type of the endpoint headers. Without it, some endpoints fail to parse the body.
+///
+/// Endpoints without a request body get an empty body instead of `null`, which some endpoints
+/// reject.
+fn with_json_body<T: Endpoint>(
+    request_builder: RequestBuilder,
+    endpoint: &T,
+) -> Result<RequestBuilder, PayPalError> {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    let body = match endpoint.request_body() {
+        Some(body) => serde_json::to_value(body)?,
+        None => serde_json::Value::Null,
+    };
+
+    if body.is_null() {
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static("0"));
+        return Ok(request_builder.headers(headers).body(""));
+    }
+
+    Ok(request_builder
+        .headers(headers)
+        .body(serde_json::to_string(&body)?))
 }
 
 fn get_basic_auth_for_user_service(username: &str, client_secret: &str) -> String {
@@ -333,7 +474,71 @@ mod tests {
 
     use http_types::Url;
 
-    use super::{Client, Environment, QueryParams};
+    use reqwest::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE};
+
+    use std::borrow::Cow;
+
+    use reqwest::multipart::Form;
+
+    use super::{with_json_body, Client, Environment, QueryParams};
+    use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+    use crate::client::error::PayPalError;
+    use crate::client::request::HttpRequestHeaders;
+
+    /// A POST endpoint without a request body, like activating a subscription.
+    struct ActivateSubscription {
+        body: Option<Option<String>>,
+    }
+
+    impl Endpoint for ActivateSubscription {
+        type QueryParams = ();
+        type RequestBody = Option<String>;
+        type ResponseBody = EmptyResponseBody;
+
+        fn path(&self) -> Cow<str> {
+            Cow::Borrowed("v1/billing/subscriptions/I-BW452GLLEP1G/activate")
+        }
+
+        fn request_body(&self) -> Option<Self::RequestBody> {
+            self.body.clone()
+        }
+    }
+
+    /// A GET endpoint with query parameters, like listing billing plans.
+    struct ListPlans {
+        query: Option<QueryParams>,
+    }
+
+    impl Endpoint for ListPlans {
+        type QueryParams = QueryParams;
+        type RequestBody = ();
+        type ResponseBody = EmptyResponseBody;
+
+        fn path(&self) -> Cow<str> {
+            Cow::Borrowed("v1/billing/plans")
+        }
+
+        fn query(&self) -> Option<Self::QueryParams> {
+            self.query.clone()
+        }
+    }
+
+    /// A POST endpoint with a multipart body, like providing evidence for a dispute.
+    struct ProvideEvidence;
+
+    impl Endpoint for ProvideEvidence {
+        type QueryParams = ();
+        type RequestBody = ();
+        type ResponseBody = EmptyResponseBody;
+
+        fn path(&self) -> Cow<str> {
+            Cow::Borrowed("v1/customer/disputes/PP-D-27803/provide-evidence")
+        }
+
+        fn multipart_body(&self) -> Result<Option<Form>, PayPalError> {
+            Ok(Some(Form::new().text("input", "{}")))
+        }
+    }
 
     #[test]
     fn test_environment() {
@@ -341,6 +546,103 @@ mod tests {
         assert_eq!(Environment::Live.as_str(), "live");
     }
 
+    #[test]
+    fn test_client_environment() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Sandbox,
+        )
+        .unwrap();
+        assert_eq!(client.environment(), Environment::Sandbox);
+        assert!(client.is_sandbox());
+        assert!(!client.is_live());
+
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Live,
+        )
+        .unwrap();
+        assert_eq!(client.environment(), Environment::Live);
+        assert!(client.is_live());
+        assert!(!client.is_sandbox());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_if_needed_keeps_valid_token() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Sandbox,
+        )
+        .unwrap()
+        .with_base_url(Url::parse("http://127.0.0.1:9").unwrap());
+        client.auth_data.write().await.expiry_time =
+            Some(chrono::Utc::now() + chrono::Duration::seconds(32400));
+
+        // No request is sent, so the unreachable base URL does not matter.
+        assert_eq!(client.refresh_token_if_needed().await, Ok(false));
+    }
+
+    #[test]
+    fn test_custom_base_url() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Live,
+        )
+        .unwrap()
+        .with_base_url(Url::parse("https://paypal.gateway.example.com").unwrap());
+        let endpoint = ActivateSubscription { body: None };
+
+        assert_eq!(
+            client.request_url(&endpoint).unwrap().as_str(),
+            "https://paypal.gateway.example.com/v1/billing/subscriptions/I-BW452GLLEP1G/activate"
+        );
+    }
+
+    #[test]
+    fn test_base_url_with_path_prefix() {
+        let endpoint = ActivateSubscription { body: None };
+        for base_url in [
+            "https://gw.example.com/paypal/",
+            "https://gw.example.com/paypal",
+        ] {
+            let client = Client::new(
+                "username".to_string(),
+                "password".to_string(),
+                Environment::Live,
+            )
+            .unwrap()
+            .with_base_url(Url::parse(base_url).unwrap());
+
+            assert_eq!(
+                client.request_url(&endpoint).unwrap().as_str(),
+                "https://gw.example.com/paypal/v1/billing/subscriptions/I-BW452GLLEP1G/activate"
+            );
+            assert_eq!(
+                client.compose_url("/v1/billing/plans").as_str(),
+                "https://gw.example.com/paypal/v1/billing/plans"
+            );
+        }
+
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Live,
+        )
+        .unwrap()
+        .with_base_url(Url::parse("https://gw.example.com/paypal/").unwrap());
+        let endpoint = ListPlans {
+            query: Some(QueryParams::new().page(2)),
+        };
+        assert_eq!(
+            client.request_url(&endpoint).unwrap().as_str(),
+            "https://gw.example.com/paypal/v1/billing/plans?page=2"
+        );
+    }
+
     #[test]
     fn test_compose_url() {
         let client = Client::new(
@@ -385,4 +687,108 @@ mod tests {
             Url::from_str("https://api-m.sandbox.paypal.com/test?page=1&page_size=10&total_count_required=true").unwrap()
         );
     }
+
+    #[test]
+    fn test_request_url_with_query() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Sandbox,
+        )
+        .unwrap();
+
+        let endpoint = ListPlans {
+            query: Some(QueryParams::new().page(2).page_size(5)),
+        };
+        assert_eq!(
+            client.request_url(&endpoint).unwrap(),
+            client
+                .compose_url_with_query("v1/billing/plans", &endpoint.query.clone().unwrap())
+                .unwrap()
+        );
+
+        let endpoint = ListPlans {
+            query: Some(QueryParams::new()),
+        };
+        assert_eq!(
+            client.request_url(&endpoint).unwrap().as_str(),
+            "https://api-m.sandbox.paypal.com/v1/billing/plans"
+        );
+
+        let endpoint = ListPlans { query: None };
+        assert_eq!(client.request_url(&endpoint).unwrap().query(), None);
+    }
+
+    #[test]
+    fn test_json_content_type() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Sandbox,
+        )
+        .unwrap();
+        let headers = HttpRequestHeaders {
+            accept: String::new(),
+            content_type: "text/plain".to_string(),
+            ..Default::default()
+        };
+
+        let request = client.set_request_headers(
+            client.http.post("https://api-m.sandbox.paypal.com/test"),
+            &headers,
+        );
+        let endpoint = ActivateSubscription {
+            body: Some(Some("reason".to_string())),
+        };
+        let request = with_json_body(request, &endpoint).unwrap().build().unwrap();
+
+        assert_eq!(request.headers()[ACCEPT], "application/json");
+        assert_eq!(request.headers().get_all(CONTENT_TYPE).iter().count(), 1);
+        assert_eq!(request.headers()[CONTENT_TYPE], "application/json");
+        assert_eq!(request.body().unwrap().as_bytes(), Some(&b"\"reason\""[..]));
+    }
+
+    #[test]
+    fn test_empty_request_body() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Sandbox,
+        )
+        .unwrap();
+
+        for body in [None, Some(None)] {
+            let endpoint = ActivateSubscription { body };
+            let request = client.http.post(endpoint.request_url(&client.base_url));
+            let request = with_json_body(request, &endpoint).unwrap().build().unwrap();
+
+            assert_eq!(request.body().unwrap().as_bytes(), Some(&b""[..]));
+            assert_eq!(request.headers()[CONTENT_LENGTH], "0");
+        }
+    }
+
+    #[test]
+    fn test_multipart_content_type() {
+        let client = Client::new(
+            "username".to_string(),
+            "password".to_string(),
+            Environment::Sandbox,
+        )
+        .unwrap();
+
+        let request = client
+            .http
+            .post(ProvideEvidence.request_url(&client.base_url));
+        let request = client
+            .with_body(request, &ProvideEvidence)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(request.headers().get_all(CONTENT_TYPE).iter().count(), 1);
+        assert!(request.headers()[CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("multipart/form-data; boundary="));
+    }
 }
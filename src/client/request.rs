@@ -135,7 +135,8 @@ pub struct HttpRequestHeaders {
     #[serde(rename = "Authorization")]
     pub authorization: String,
 
-    /// The request format, which is required for operations with a request body.
+    /// The request format, which is required for operations with a request body. Not sent if
+    /// empty.
     #[serde(rename = "Content-Type")]
     pub content_type: String,
 
@@ -225,6 +226,12 @@ pub struct HttpRequestHeaders {
     #[serde(rename = "PayPal-Request-Id", skip_serializing_if = "Option::is_none")]
     pub paypal_request_id: Option<String>,
 
+    /// The preferred server response upon successful completion of the request. Some list
+    /// endpoints only return the full resources with `return=representation`, instead of the
+    /// default `return=minimal`.
+    #[serde(rename = "Prefer", skip_serializing_if = "Option::is_none")]
+    pub prefer: Option<String>,
+
     #[serde(rename = "User-Agent")]
     pub user_agent: String,
 }
@@ -240,6 +247,7 @@ impl Default for HttpRequestHeaders {
             client_client_metadata_id: None,
             paypal_auth_assertion: None,
             paypal_request_id: None,
+            prefer: None,
         }
     }
 }
@@ -254,8 +262,12 @@ impl HttpRequestHeaders {
 
     pub fn to_vec(&self) -> Vec<(&str, &str)> {
         let mut headers = Vec::new();
-        headers.push(("Accept", self.accept.as_str()));
-        headers.push(("Content-Type", self.content_type.as_str()));
+        if !self.accept.is_empty() {
+            headers.push(("Accept", self.accept.as_str()));
+        }
+        if !self.content_type.is_empty() {
+            headers.push(("Content-Type", self.content_type.as_str()));
+        }
         headers.push(("User-Agent", self.user_agent.as_str()));
         if let Some(paypal_partner_attribution_id) = &self.paypal_partner_attribution_id {
             headers.push((
@@ -275,6 +287,9 @@ impl HttpRequestHeaders {
         if let Some(paypal_request_id) = &self.paypal_request_id {
             headers.push(("PayPal-Request-Id", paypal_request_id.as_str()));
         }
+        if let Some(prefer) = &self.prefer {
+            headers.push(("Prefer", prefer.as_str()));
+        }
         headers
     }
 }
@@ -42,6 +42,7 @@
 //!         CreateOrderDto {
 //!             intent: OrderIntent::Capture,
 //!             payer: None,
+//!             payment_source: None,
 //!             purchase_units: vec![PurchaseUnitRequest::new(AmountWithBreakdown::new(
 //!                 CurrencyCode::Euro,
 //!                 "10.00".to_string(),
@@ -65,8 +66,16 @@
 //!
 //! - `Order::get_maximum_reauthorization_amount()`
 //! - `Order::get_authorization_id()`
+//!
+//! The "test-utils" feature exports assertion macros for tests against error conditions:
+//!
+//! - `assert_is_not_found!(err)`
+//! - `assert_is_validation_error!(err, expected_issue)`
 
 #![forbid(unsafe_code)]
+// `PayPalError` is large because of the `ValidationError` in the deprecated `PayPalError::Api`
+// variant. Boxing it would break code that still matches on that variant.
+#![allow(clippy::result_large_err)]
 
 pub mod client;
 pub mod resources;
@@ -76,3 +85,6 @@ pub use resources::*;
 
 #[cfg(feature = "utils")]
 pub mod utils;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::invoice_item::{InvoiceDiscount, InvoiceTax};
+use crate::resources::money::Money;
+
+/// The invoice amount summary of item total, discount, tax total, and shipping.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AmountSummaryDetail {
+    /// The three-character ISO-4217 currency code that identifies the currency.
+    pub currency_code: Option<String>,
+
+    /// The value, which might be:
+    /// * An integer for currencies like JPY that are not typically fractional.
+    /// * A decimal fraction for currencies like TND that are subdivided into thousandths.
+    pub value: Option<String>,
+
+    /// The breakdown of the amount. Includes total item amount, total tax amount, custom amount,
+    /// and shipping and discounts, if any.
+    pub breakdown: Option<InvoiceAmountBreakdown>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceAmountBreakdown {
+    /// The subtotal for all items.
+    pub item_total: Option<Money>,
+
+    /// The discount can be at the item or invoice level, or both.
+    pub discount: Option<AggregatedDiscount>,
+
+    /// The aggregated amount of the item and shipping taxes.
+    pub tax_total: Option<Money>,
+
+    /// The shipping fee for all items. Includes tax on shipping.
+    pub shipping: Option<ShippingCost>,
+
+    /// The custom amount to apply to an invoice.
+    pub custom: Option<CustomAmount>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AggregatedDiscount {
+    /// The discount as a percent or amount at invoice level.
+    pub invoice_discount: Option<InvoiceDiscount>,
+
+    /// The discount as a percent or amount at item level.
+    pub item_discount: Option<Money>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ShippingCost {
+    /// The shipping amount.
+    pub amount: Option<Money>,
+
+    /// The tax associated with the shipping.
+    pub tax: Option<InvoiceTax>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CustomAmount {
+    /// The label to the custom amount of the invoice.
+    pub label: String,
+
+    /// The custom amount value.
+    pub amount: Option<Money>,
+}
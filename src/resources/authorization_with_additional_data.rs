@@ -1,4 +1,7 @@
+use crate::resources::enums::payment_status::PaymentStatus;
+use crate::resources::money::Money;
 use crate::resources::processor_response::ProcessorResponse;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -8,4 +11,19 @@ pub struct AuthorizationWithAdditionalData {
     pub processor_response: Option<ProcessorResponse>,
 
     pub id: Option<String>,
+
+    /// The status for the authorized payment.
+    pub status: Option<PaymentStatus>,
+
+    /// The amount for this authorized payment.
+    pub amount: Option<Money>,
+
+    /// The date and time when the authorized payment expires, in Internet date and time format.
+    pub expiration_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the transaction occurred, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the transaction was last updated, in Internet date and time format.
+    pub update_time: Option<String>,
 }
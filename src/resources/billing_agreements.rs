@@ -0,0 +1,161 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::enums::op::Op;
+
+/// Legacy billing agreements of the v1 Payments API.
+pub struct BillingAgreement;
+
+impl BillingAgreement {
+    /// Updates the shipping address of a billing agreement, by ID.
+    #[deprecated(note = "Billing agreements are a legacy API, migrate to the Subscriptions API")]
+    pub async fn update_shipping_address(
+        client: &Client,
+        agreement_id: &str,
+        address: BillingAgreementAddress,
+    ) -> Result<(), PayPalError> {
+        client
+            .patch(&UpdateBillingAgreement::shipping_address(
+                agreement_id.to_string(),
+                address,
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+/// The address format of the legacy v1 Payments API. [`crate::Address`] cannot be used instead,
+/// as it carries the country name instead of the two-character country code the API requires.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingAgreementAddress {
+    /// The first line of the address. For example, number or street.
+    pub line1: String,
+
+    /// The second line of the address. For example, suite or apartment number.
+    pub line2: Option<String>,
+
+    /// The city name.
+    pub city: String,
+
+    /// The two-character ISO 3166-1 code that identifies the country or region.
+    pub country_code: String,
+
+    /// The postal code, which is the zip code or equivalent.
+    pub postal_code: Option<String>,
+
+    /// The code for a US state or the equivalent for other countries.
+    pub state: Option<String>,
+
+    /// The name of the recipient at this address.
+    pub recipient_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct BillingAgreementPatch {
+    op: Op,
+    path: String,
+    value: BillingAgreementPatchValue,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct BillingAgreementPatchValue {
+    shipping_address: BillingAgreementAddress,
+}
+
+#[derive(Debug)]
+struct UpdateBillingAgreement {
+    agreement_id: String,
+    body: Vec<BillingAgreementPatch>,
+}
+
+impl UpdateBillingAgreement {
+    pub const fn new(agreement_id: String, body: Vec<BillingAgreementPatch>) -> Self {
+        Self { agreement_id, body }
+    }
+
+    /// Replaces the shipping address of the billing agreement.
+    pub fn shipping_address(agreement_id: String, address: BillingAgreementAddress) -> Self {
+        Self::new(
+            agreement_id,
+            vec![BillingAgreementPatch {
+                op: Op::Replace,
+                path: "/".to_string(),
+                value: BillingAgreementPatchValue {
+                    shipping_address: address,
+                },
+            }],
+        )
+    }
+}
+
+impl Endpoint for UpdateBillingAgreement {
+    type QueryParams = ();
+    type RequestBody = Vec<BillingAgreementPatch>;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/payments/billing-agreements/{}",
+            self.agreement_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::PATCH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_shipping_address_request() {
+        let endpoint = UpdateBillingAgreement::shipping_address(
+            "I-0LN988D3JACS".to_string(),
+            BillingAgreementAddress {
+                line1: "2211 N First Street".to_string(),
+                line2: Some("Building 17".to_string()),
+                city: "San Jose".to_string(),
+                country_code: "US".to_string(),
+                postal_code: Some("95131".to_string()),
+                state: Some("CA".to_string()),
+                recipient_name: None,
+            },
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v1/payments/billing-agreements/I-0LN988D3JACS"
+        );
+        assert_eq!(endpoint.request_method(), Method::PATCH);
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!([{
+                "op": "replace",
+                "path": "/",
+                "value": {
+                    "shipping_address": {
+                        "line1": "2211 N First Street",
+                        "line2": "Building 17",
+                        "city": "San Jose",
+                        "country_code": "US",
+                        "postal_code": "95131",
+                        "state": "CA"
+                    }
+                }
+            }])
+        );
+    }
+}
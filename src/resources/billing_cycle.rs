@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::interval_unit::IntervalUnit;
+use crate::resources::enums::tenure_type::TenureType;
+use crate::resources::money::Money;
+use crate::resources::payment_preferences::PaymentPreferences;
+use crate::resources::pricing_scheme::{FixedPricing, PricingScheme};
+
+/// A billing cycle of a subscription plan, either a trial or a regular cycle.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BillingCycle {
+    /// The frequency details for this billing cycle.
+    pub frequency: Frequency,
+
+    /// The tenure type of the billing cycle. A plan can have at most two trial cycles and only
+    /// one regular cycle.
+    pub tenure_type: TenureType,
+
+    /// The order in which this cycle is to run among other billing cycles.
+    pub sequence: u32,
+
+    /// The number of times this billing cycle gets executed. `0` means infinite cycles.
+    pub total_cycles: Option<u32>,
+
+    /// The active pricing scheme for this billing cycle. A free trial billing cycle does not
+    /// require a pricing scheme.
+    pub pricing_scheme: Option<PricingScheme>,
+}
+
+impl BillingCycle {
+    /// A regular billing cycle charging `price` every `frequency`. `total_cycles` of `0` bills
+    /// until the subscription is cancelled.
+    #[must_use]
+    pub const fn regular(
+        frequency: Frequency,
+        sequence: u32,
+        total_cycles: u32,
+        price: Money,
+    ) -> Self {
+        Self {
+            frequency,
+            tenure_type: TenureType::Regular,
+            sequence,
+            total_cycles: Some(total_cycles),
+            pricing_scheme: Some(PricingScheme::Fixed(FixedPricing { fixed_price: price })),
+        }
+    }
+
+    /// A free trial billing cycle. Add a pricing scheme with [`BillingCycle::pricing_scheme`]
+    /// for a discounted trial.
+    #[must_use]
+    pub const fn trial(frequency: Frequency, sequence: u32, total_cycles: u32) -> Self {
+        Self {
+            frequency,
+            tenure_type: TenureType::Trial,
+            sequence,
+            total_cycles: Some(total_cycles),
+            pricing_scheme: None,
+        }
+    }
+
+    #[must_use]
+    pub fn pricing_scheme(mut self, pricing_scheme: PricingScheme) -> Self {
+        self.pricing_scheme = Some(pricing_scheme);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Frequency {
+    /// The interval at which the subscription is charged or billed.
+    pub interval_unit: IntervalUnit,
+
+    /// The number of intervals after which a subscriber is billed.
+    pub interval_count: Option<u32>,
+}
+
+impl Frequency {
+    #[must_use]
+    pub const fn new(interval_unit: IntervalUnit, interval_count: u32) -> Self {
+        Self {
+            interval_unit,
+            interval_count: Some(interval_count),
+        }
+    }
+
+    /// Bills every `count` days.
+    #[must_use]
+    pub const fn days(count: u32) -> Self {
+        Self::new(IntervalUnit::Day, count)
+    }
+
+    /// Bills every `count` weeks.
+    #[must_use]
+    pub const fn weeks(count: u32) -> Self {
+        Self::new(IntervalUnit::Week, count)
+    }
+
+    /// Bills every `count` months.
+    #[must_use]
+    pub const fn months(count: u32) -> Self {
+        Self::new(IntervalUnit::Month, count)
+    }
+
+    /// Bills once a month.
+    #[must_use]
+    pub const fn monthly() -> Self {
+        Self::new(IntervalUnit::Month, 1)
+    }
+
+    /// Bills once a year.
+    #[must_use]
+    pub const fn yearly() -> Self {
+        Self::new(IntervalUnit::Year, 1)
+    }
+}
+
+/// Builds the billing cycles of a plan, numbering their sequences and setting their tenure types.
+///
+/// ```
+/// # use paypal_rust::{BillingCycles, CurrencyCode, Frequency, Money};
+/// let usd = |value: &str| Money::new(CurrencyCode::UnitedStatesDollar, value.to_string());
+///
+/// let cycles = BillingCycles::trial(Frequency::days(14))
+///     .then_monthly(usd("9.99"))
+///     .with_setup_fee(usd("4.99"));
+///
+/// let payment_preferences = cycles.payment_preferences();
+/// let billing_cycles = cycles.build();
+/// assert_eq!(billing_cycles.len(), 2);
+/// assert_eq!(billing_cycles[1].sequence, 2);
+/// assert!(payment_preferences.setup_fee.is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BillingCycles {
+    cycles: Vec<BillingCycle>,
+    setup_fee: Option<Money>,
+}
+
+impl BillingCycles {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts with a single free trial cycle of the given length.
+    #[must_use]
+    pub fn trial(frequency: Frequency) -> Self {
+        Self::new().then_trial(frequency, None)
+    }
+
+    /// Adds a trial cycle, free if no price is given. PayPal supports up to two trial cycles.
+    #[must_use]
+    pub fn then_trial(self, frequency: Frequency, price: Option<Money>) -> Self {
+        let sequence = self.next_sequence();
+        let cycle = BillingCycle::trial(frequency, sequence, 1);
+
+        self.push(match price {
+            Some(price) => {
+                cycle.pricing_scheme(PricingScheme::Fixed(FixedPricing { fixed_price: price }))
+            }
+            None => cycle,
+        })
+    }
+
+    /// Adds a regular cycle charging `price` every month until the subscription is cancelled.
+    #[must_use]
+    pub fn then_monthly(self, price: Money) -> Self {
+        self.then_regular(Frequency::monthly(), price, 0)
+    }
+
+    /// Adds a regular cycle charging `price` every year until the subscription is cancelled.
+    #[must_use]
+    pub fn then_yearly(self, price: Money) -> Self {
+        self.then_regular(Frequency::yearly(), price, 0)
+    }
+
+    /// Adds a regular cycle charging `price` every `frequency`, `total_cycles` times. `0` bills
+    /// until the subscription is cancelled.
+    #[must_use]
+    pub fn then_regular(self, frequency: Frequency, price: Money, total_cycles: u32) -> Self {
+        let sequence = self.next_sequence();
+
+        self.push(BillingCycle::regular(
+            frequency,
+            sequence,
+            total_cycles,
+            price,
+        ))
+    }
+
+    /// Charges a one-time setup fee when the subscription is activated.
+    #[must_use]
+    pub fn with_setup_fee(mut self, setup_fee: Money) -> Self {
+        self.setup_fee = Some(setup_fee);
+        self
+    }
+
+    /// The payment preferences for the plan, including the setup fee.
+    pub fn payment_preferences(&self) -> PaymentPreferences {
+        PaymentPreferences {
+            setup_fee: self.setup_fee.clone(),
+            ..PaymentPreferences::default()
+        }
+    }
+
+    /// The billing cycles, in the order they were added.
+    pub fn build(self) -> Vec<BillingCycle> {
+        self.cycles
+    }
+
+    fn next_sequence(&self) -> u32 {
+        self.cycles.len() as u32 + 1
+    }
+
+    fn push(mut self, cycle: BillingCycle) -> Self {
+        self.cycles.push(cycle);
+        self
+    }
+}
@@ -0,0 +1,859 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::client::request::HttpRequestHeaders;
+use crate::resources::billing_cycle::{BillingCycle, BillingCycles};
+use crate::resources::enums::failed_payment_action::FailedPaymentAction;
+use crate::resources::enums::op::Op;
+use crate::resources::enums::plan_status::PlanStatus;
+use crate::resources::link_description::LinkDescription;
+use crate::resources::money::Money;
+use crate::resources::page::Page;
+use crate::resources::patch::{Patch, PatchValue};
+use crate::resources::payment_preferences::PaymentPreferences;
+use crate::resources::pricing_scheme::PricingScheme;
+use crate::resources::taxes::Taxes;
+
+pub struct BillingPlan;
+
+impl BillingPlan {
+    /// Creates a plan that defines pricing and billing cycle details for subscriptions.
+    ///
+    /// # Errors
+    /// Errors without calling PayPal if the payment preferences are invalid.
+    pub async fn create(
+        client: &Client,
+        dto: CreateBillingPlanDto,
+    ) -> Result<BillingPlanDetails, PayPalError> {
+        dto.payment_preferences.validate()?;
+
+        client.post(&CreateBillingPlan::new(dto)).await
+    }
+
+    /// Shows details for a plan, by ID.
+    pub async fn show(client: &Client, plan_id: &str) -> Result<BillingPlanDetails, PayPalError> {
+        client.get(&ShowBillingPlan::new(plan_id.to_string())).await
+    }
+
+    /// Updates a plan with the `CREATED` or `ACTIVE` status, by ID. See [`BillingPlanPatch`] for
+    /// the supported operations.
+    pub async fn update(
+        client: &Client,
+        plan_id: &str,
+        patch: Vec<Patch>,
+    ) -> Result<(), PayPalError> {
+        client
+            .patch(&UpdateBillingPlan::new(plan_id.to_string(), patch))
+            .await?;
+        Ok(())
+    }
+
+    /// Activates a plan, by ID. Subscriptions can only be created for active plans.
+    pub async fn activate(client: &Client, plan_id: &str) -> Result<(), PayPalError> {
+        client
+            .post(&ActivateBillingPlan::new(plan_id.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    /// Deactivates a plan, by ID. Existing subscriptions of the plan are not affected.
+    pub async fn deactivate(client: &Client, plan_id: &str) -> Result<(), PayPalError> {
+        client
+            .post(&DeactivateBillingPlan::new(plan_id.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    /// Updates the pricing schemes of the billing cycles of a plan, by ID. Existing subscriptions
+    /// of the plan are charged the new price from their next billing cycle on.
+    pub async fn update_pricing_schemes(
+        client: &Client,
+        plan_id: &str,
+        dto: UpdatePricingSchemesDto,
+    ) -> Result<(), PayPalError> {
+        client
+            .post(&UpdatePricingSchemes::new(plan_id.to_string(), dto))
+            .await?;
+        Ok(())
+    }
+
+    /// Lists billing plans. The plans are returned in their full representation, including the
+    /// billing cycles and payment preferences.
+    pub async fn list(
+        client: &Client,
+        query: ListBillingPlansQuery,
+    ) -> Result<ListBillingPlansResponse, PayPalError> {
+        client.get(&ListBillingPlans::new(query)).await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingPlanDto {
+    /// The ID of the product created through the Catalog Products API.
+    pub product_id: String,
+
+    /// The plan name.
+    pub name: String,
+
+    /// The initial state of the plan. Defaults to `ACTIVE`. Only plans in the `CREATED` or
+    /// `ACTIVE` state can be created.
+    pub status: Option<PlanStatus>,
+
+    /// The detailed description of the plan.
+    pub description: Option<String>,
+
+    /// An array of billing cycles for trial billing and regular billing. A plan can have at most
+    /// two trial cycles and only one regular cycle.
+    pub billing_cycles: Vec<BillingCycle>,
+
+    /// The payment preferences for a subscription.
+    pub payment_preferences: PaymentPreferences,
+
+    /// The tax details.
+    pub taxes: Option<Taxes>,
+
+    /// Indicates whether you can subscribe to this plan by providing a quantity for the goods or
+    /// service.
+    pub quantity_supported: Option<bool>,
+}
+
+impl CreateBillingPlanDto {
+    #[must_use]
+    pub fn new(
+        product_id: String,
+        name: String,
+        billing_cycles: Vec<BillingCycle>,
+        payment_preferences: PaymentPreferences,
+    ) -> Self {
+        Self {
+            product_id,
+            name,
+            status: None,
+            description: None,
+            billing_cycles,
+            payment_preferences,
+            taxes: None,
+            quantity_supported: None,
+        }
+    }
+
+    /// A plan with the billing cycles and the setup fee of `billing_cycles`.
+    #[must_use]
+    pub fn from_billing_cycles(
+        product_id: String,
+        name: String,
+        billing_cycles: BillingCycles,
+    ) -> Self {
+        let payment_preferences = billing_cycles.payment_preferences();
+
+        Self::new(
+            product_id,
+            name,
+            billing_cycles.build(),
+            payment_preferences,
+        )
+    }
+
+    #[must_use]
+    pub const fn status(mut self, status: PlanStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    #[must_use]
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    #[must_use]
+    pub fn taxes(mut self, taxes: Taxes) -> Self {
+        self.taxes = Some(taxes);
+        self
+    }
+
+    #[must_use]
+    pub const fn quantity_supported(mut self, quantity_supported: bool) -> Self {
+        self.quantity_supported = Some(quantity_supported);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct BillingPlanDetails {
+    /// The unique PayPal-generated ID for the plan.
+    pub id: String,
+
+    /// The ID for the product.
+    pub product_id: Option<String>,
+
+    /// The plan name.
+    pub name: Option<String>,
+
+    /// The plan status.
+    pub status: Option<PlanStatus>,
+
+    /// The detailed description of the plan.
+    pub description: Option<String>,
+
+    /// An array of billing cycles for trial billing and regular billing.
+    #[serde(default = "Vec::new")]
+    pub billing_cycles: Vec<BillingCycle>,
+
+    /// The payment preferences for a subscription.
+    pub payment_preferences: Option<PaymentPreferences>,
+
+    /// The tax details.
+    pub taxes: Option<Taxes>,
+
+    /// Indicates whether you can subscribe to this plan by providing a quantity for the goods or
+    /// service.
+    pub quantity_supported: Option<bool>,
+
+    /// The date and time when the plan was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the plan was last updated, in Internet date and time format.
+    pub update_time: Option<String>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+#[derive(Debug)]
+struct CreateBillingPlan {
+    body: CreateBillingPlanDto,
+}
+
+impl CreateBillingPlan {
+    pub const fn new(body: CreateBillingPlanDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateBillingPlan {
+    type QueryParams = ();
+    type RequestBody = CreateBillingPlanDto;
+    type ResponseBody = BillingPlanDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/billing/plans")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ShowBillingPlan {
+    plan_id: String,
+}
+
+impl ShowBillingPlan {
+    pub const fn new(plan_id: String) -> Self {
+        Self { plan_id }
+    }
+}
+
+impl Endpoint for ShowBillingPlan {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = BillingPlanDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/billing/plans/{}", self.plan_id))
+    }
+}
+
+/// Typed patch operations for [`BillingPlan::update`].
+pub struct BillingPlanPatch;
+
+impl BillingPlanPatch {
+    /// Replaces the name of the plan.
+    pub fn replace_name(name: String) -> Patch {
+        Self::replace("/name", PatchValue::String(name))
+    }
+
+    /// Replaces the description of the plan.
+    pub fn replace_description(description: String) -> Patch {
+        Self::replace("/description", PatchValue::String(description))
+    }
+
+    /// Replaces whether the outstanding amount is billed automatically in the next billing cycle.
+    pub fn replace_auto_bill_outstanding(auto_bill_outstanding: bool) -> Patch {
+        Self::replace(
+            "/payment_preferences/auto_bill_outstanding",
+            PatchValue::Boolean(auto_bill_outstanding),
+        )
+    }
+
+    /// Replaces the initial set-up fee for the service.
+    pub fn replace_setup_fee(setup_fee: Money) -> Patch {
+        Self::replace(
+            "/payment_preferences/setup_fee",
+            PatchValue::Money(setup_fee),
+        )
+    }
+
+    /// Replaces the action to take on the subscription if the initial payment for the setup fails.
+    pub fn replace_setup_fee_failure_action(action: FailedPaymentAction) -> Patch {
+        Self::replace(
+            "/payment_preferences/setup_fee_failure_action",
+            PatchValue::String(action.as_str().to_string()),
+        )
+    }
+
+    /// Replaces the maximum number of consecutive payment failures before a subscription is
+    /// suspended. Must be between 1 and 999.
+    pub fn replace_payment_failure_threshold(threshold: u16) -> Patch {
+        Self::replace(
+            "/payment_preferences/payment_failure_threshold",
+            PatchValue::Int(i32::from(threshold)),
+        )
+    }
+
+    /// Replaces the tax percentage on the billing amount.
+    pub fn replace_tax_percentage(percentage: String) -> Patch {
+        Self::replace("/taxes/percentage", PatchValue::String(percentage))
+    }
+
+    fn replace(path: &str, value: PatchValue) -> Patch {
+        Patch::new(Op::Replace).path(path.to_string()).value(value)
+    }
+}
+
+#[derive(Debug)]
+struct UpdateBillingPlan {
+    plan_id: String,
+    body: Vec<Patch>,
+}
+
+impl UpdateBillingPlan {
+    pub const fn new(plan_id: String, body: Vec<Patch>) -> Self {
+        Self { plan_id, body }
+    }
+}
+
+impl Endpoint for UpdateBillingPlan {
+    type QueryParams = ();
+    type RequestBody = Vec<Patch>;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/billing/plans/{}", self.plan_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::PATCH
+    }
+}
+
+#[derive(Debug)]
+struct ActivateBillingPlan {
+    plan_id: String,
+}
+
+impl ActivateBillingPlan {
+    pub const fn new(plan_id: String) -> Self {
+        Self { plan_id }
+    }
+}
+
+impl Endpoint for ActivateBillingPlan {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/billing/plans/{}/activate", self.plan_id))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct DeactivateBillingPlan {
+    plan_id: String,
+}
+
+impl DeactivateBillingPlan {
+    pub const fn new(plan_id: String) -> Self {
+        Self { plan_id }
+    }
+}
+
+impl Endpoint for DeactivateBillingPlan {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/billing/plans/{}/deactivate", self.plan_id))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdatePricingSchemesDto {
+    /// An array of pricing schemes, each for the billing cycle with the given sequence.
+    pub pricing_schemes: Vec<UpdatePricingScheme>,
+}
+
+impl UpdatePricingSchemesDto {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pricing scheme of the billing cycle with the given sequence.
+    #[must_use]
+    pub fn pricing_scheme(
+        mut self,
+        billing_cycle_sequence: u32,
+        pricing_scheme: PricingScheme,
+    ) -> Self {
+        self.pricing_schemes.push(UpdatePricingScheme {
+            billing_cycle_sequence,
+            pricing_scheme,
+        });
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdatePricingScheme {
+    /// The billing cycle sequence.
+    pub billing_cycle_sequence: u32,
+
+    /// The pricing scheme for the billing cycle.
+    pub pricing_scheme: PricingScheme,
+}
+
+#[derive(Debug)]
+struct UpdatePricingSchemes {
+    plan_id: String,
+    body: UpdatePricingSchemesDto,
+}
+
+impl UpdatePricingSchemes {
+    pub const fn new(plan_id: String, body: UpdatePricingSchemesDto) -> Self {
+        Self { plan_id, body }
+    }
+}
+
+impl Endpoint for UpdatePricingSchemes {
+    type QueryParams = ();
+    type RequestBody = UpdatePricingSchemesDto;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/plans/{}/update-pricing-schemes",
+            self.plan_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListBillingPlansQuery {
+    /// Filters the response by a product ID.
+    pub product_id: Option<String>,
+
+    /// Filters the response by a comma-separated list of plan IDs. Supports up to 10 plan IDs.
+    pub plan_ids: Option<String>,
+
+    /// The number of items to return in the response.
+    pub page_size: Option<u32>,
+
+    /// A non-zero integer which is the start index of the entire list of items to return in the
+    /// response.
+    pub page: Option<u32>,
+
+    /// Indicates whether to show the total items and total pages in the response.
+    pub total_required: Option<bool>,
+}
+
+impl ListBillingPlansQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn product_id(mut self, product_id: String) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    #[must_use]
+    pub fn plan_ids(mut self, plan_ids: &[&str]) -> Self {
+        self.plan_ids = Some(plan_ids.join(","));
+        self
+    }
+
+    #[must_use]
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Requests the `total_items` and `total_pages` fields in the response.
+    #[must_use]
+    pub const fn with_total_count(mut self) -> Self {
+        self.total_required = Some(true);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ListBillingPlansResponse {
+    /// An array of plans.
+    #[serde(default = "Vec::new")]
+    pub plans: Vec<BillingPlanDetails>,
+
+    /// The total number of items. Only returned when `total_required` is set.
+    pub total_items: Option<u32>,
+
+    /// The total number of pages. Only returned when `total_required` is set.
+    pub total_pages: Option<u32>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ListBillingPlansResponse {
+    /// Converts the response into a generic [`Page`] of plans.
+    pub fn into_page(self) -> Page<BillingPlanDetails> {
+        Page {
+            items: self.plans,
+            total_items: self.total_items,
+            total_pages: self.total_pages,
+            links: self.links,
+        }
+    }
+}
+
+/// A plan as returned when listing plans. Listing plans returns their full representation.
+#[deprecated(note = "use `BillingPlanDetails` instead")]
+pub type BillingPlanSummary = BillingPlanDetails;
+
+#[derive(Debug)]
+struct ListBillingPlans {
+    query: ListBillingPlansQuery,
+}
+
+impl ListBillingPlans {
+    pub const fn new(query: ListBillingPlansQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListBillingPlans {
+    type QueryParams = ListBillingPlansQuery;
+    type RequestBody = ();
+    type ResponseBody = ListBillingPlansResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/billing/plans")
+    }
+
+    fn headers(&self) -> HttpRequestHeaders {
+        HttpRequestHeaders {
+            prefer: Some("return=representation".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::billing_cycle::Frequency;
+    use crate::resources::enums::currency_code::CurrencyCode;
+    use crate::resources::pricing_scheme::FixedPricing;
+
+    #[test]
+    fn create_billing_plan_request_body() {
+        let billing_cycles = vec![
+            BillingCycle::trial(Frequency::monthly(), 1, 1),
+            BillingCycle::regular(
+                Frequency::monthly(),
+                2,
+                0,
+                Money::new(CurrencyCode::UnitedStatesDollar, "10".to_string()),
+            ),
+        ];
+        let payment_preferences = PaymentPreferences {
+            auto_bill_outstanding: Some(true),
+            setup_fee: None,
+            setup_fee_failure_action: Some(FailedPaymentAction::Continue),
+            payment_failure_threshold: Some(3),
+        };
+        let endpoint = CreateBillingPlan::new(
+            CreateBillingPlanDto::new(
+                "PROD-XXCD1234QWER65782".to_string(),
+                "Video Streaming Service Plan".to_string(),
+                billing_cycles,
+                payment_preferences,
+            )
+            .taxes(Taxes::new("10".to_string()).inclusive(false)),
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "product_id": "PROD-XXCD1234QWER65782",
+                "name": "Video Streaming Service Plan",
+                "billing_cycles": [
+                    {
+                        "frequency": {"interval_unit": "MONTH", "interval_count": 1},
+                        "tenure_type": "TRIAL",
+                        "sequence": 1,
+                        "total_cycles": 1
+                    },
+                    {
+                        "frequency": {"interval_unit": "MONTH", "interval_count": 1},
+                        "tenure_type": "REGULAR",
+                        "sequence": 2,
+                        "total_cycles": 0,
+                        "pricing_scheme": {
+                            "fixed_price": {"currency_code": "USD", "value": "10"}
+                        }
+                    }
+                ],
+                "payment_preferences": {
+                    "auto_bill_outstanding": true,
+                    "setup_fee_failure_action": "CONTINUE",
+                    "payment_failure_threshold": 3
+                },
+                "taxes": {"percentage": "10", "inclusive": false}
+            })
+        );
+    }
+
+    #[test]
+    fn list_billing_plans_request() {
+        let endpoint = ListBillingPlans::new(
+            ListBillingPlansQuery::new()
+                .product_id("PROD-XXCD1234QWER65782".to_string())
+                .plan_ids(&["P-5ML4271244454362WXNWU5NQ", "P-6ML4271244454362WXNWU5NQ"])
+                .page_size(2)
+                .with_total_count(),
+        );
+
+        assert_eq!(
+            endpoint.headers().prefer.as_deref(),
+            Some("return=representation")
+        );
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "product_id=PROD-XXCD1234QWER65782\
+             &plan_ids=P-5ML4271244454362WXNWU5NQ%2CP-6ML4271244454362WXNWU5NQ\
+             &page_size=2&total_required=true"
+        );
+    }
+
+    #[test]
+    fn list_billing_plans_response() {
+        let response: ListBillingPlansResponse = serde_json::from_str(
+            r#"{
+                "plans": [
+                    {
+                        "id": "P-5ML4271244454362WXNWU5NQ",
+                        "product_id": "PROD-XXCD1234QWER65782",
+                        "name": "Basic Plan",
+                        "status": "ACTIVE",
+                        "description": "Basic plan",
+                        "usage_type": "LICENSED",
+                        "create_time": "2018-12-10T21:20:49Z",
+                        "links": [
+                            {
+                                "href": "https://api-m.paypal.com/v1/billing/plans/P-5ML4271244454362WXNWU5NQ",
+                                "rel": "self",
+                                "method": "GET",
+                                "encType": "application/json"
+                            }
+                        ]
+                    },
+                    {
+                        "id": "P-7GL4271244454362WXNWU5NQ",
+                        "product_id": "PROD-XXCD1234QWER65782",
+                        "name": "Premium Plan",
+                        "status": "INACTIVE",
+                        "description": "Premium plan",
+                        "usage_type": "LICENSED",
+                        "create_time": "2018-12-10T21:20:49Z",
+                        "links": [
+                            {
+                                "href": "https://api-m.paypal.com/v1/billing/plans/P-7GL4271244454362WXNWU5NQ",
+                                "rel": "self",
+                                "method": "GET",
+                                "encType": "application/json"
+                            }
+                        ]
+                    }
+                ],
+                "total_items": 12,
+                "total_pages": 6,
+                "links": [
+                    {
+                        "href": "https://api-m.paypal.com/v1/billing/plans?page_size=2&page=1&total_required=true",
+                        "rel": "self",
+                        "method": "GET",
+                        "encType": "application/json"
+                    },
+                    {
+                        "href": "https://api-m.paypal.com/v1/billing/plans?page_size=2&page=2&total_required=true",
+                        "rel": "next",
+                        "method": "GET",
+                        "encType": "application/json"
+                    },
+                    {
+                        "href": "https://api-m.paypal.com/v1/billing/plans?page_size=2&page=6&total_required=true",
+                        "rel": "last",
+                        "method": "GET",
+                        "encType": "application/json"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let page = response.into_page();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].id, "P-5ML4271244454362WXNWU5NQ");
+        assert_eq!(page.items[0].status, Some(PlanStatus::Active));
+        assert_eq!(page.items[1].name.as_deref(), Some("Premium Plan"));
+        assert_eq!(page.items[1].status, Some(PlanStatus::Inactive));
+        assert_eq!(page.total_items, Some(12));
+        assert_eq!(page.total_pages, Some(6));
+        assert_eq!(
+            page.next_page_url(),
+            Some(
+                "https://api-m.paypal.com/v1/billing/plans?page_size=2&page=2&total_required=true"
+            )
+        );
+    }
+
+    #[test]
+    fn update_billing_plan_request_body() {
+        let endpoint = UpdateBillingPlan::new(
+            "P-5ML4271244454362WXNWU5NQ".to_string(),
+            vec![
+                BillingPlanPatch::replace_payment_failure_threshold(7),
+                BillingPlanPatch::replace_setup_fee(Money::new(
+                    CurrencyCode::UnitedStatesDollar,
+                    "5".to_string(),
+                )),
+                BillingPlanPatch::replace_tax_percentage("6".to_string()),
+            ],
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!([
+                {
+                    "op": "replace",
+                    "path": "/payment_preferences/payment_failure_threshold",
+                    "value": 7
+                },
+                {
+                    "op": "replace",
+                    "path": "/payment_preferences/setup_fee",
+                    "value": {"currency_code": "USD", "value": "5"}
+                },
+                {"op": "replace", "path": "/taxes/percentage", "value": "6"}
+            ])
+        );
+    }
+
+    #[test]
+    fn activate_and_deactivate_billing_plan() {
+        let activate = ActivateBillingPlan::new("P-5ML4271244454362WXNWU5NQ".to_string());
+        assert_eq!(
+            activate.path(),
+            "v1/billing/plans/P-5ML4271244454362WXNWU5NQ/activate"
+        );
+        assert!(activate.request_body().is_none());
+
+        let deactivate = DeactivateBillingPlan::new("P-5ML4271244454362WXNWU5NQ".to_string());
+        assert_eq!(
+            deactivate.path(),
+            "v1/billing/plans/P-5ML4271244454362WXNWU5NQ/deactivate"
+        );
+        assert!(deactivate.request_body().is_none());
+    }
+
+    #[test]
+    fn update_pricing_schemes_request_body() {
+        let endpoint = UpdatePricingSchemes::new(
+            "P-5ML4271244454362WXNWU5NQ".to_string(),
+            UpdatePricingSchemesDto::new().pricing_scheme(
+                2,
+                PricingScheme::Fixed(FixedPricing {
+                    fixed_price: Money::new(CurrencyCode::UnitedStatesDollar, "50".to_string()),
+                }),
+            ),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v1/billing/plans/P-5ML4271244454362WXNWU5NQ/update-pricing-schemes"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "pricing_schemes": [
+                    {
+                        "billing_cycle_sequence": 2,
+                        "pricing_scheme": {
+                            "fixed_price": {"currency_code": "USD", "value": "50"}
+                        }
+                    }
+                ]
+            })
+        );
+    }
+}
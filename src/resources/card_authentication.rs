@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::authentication_status::AuthenticationStatus;
+use crate::resources::enums::card_verification_method::CardVerificationMethod;
+use crate::resources::enums::enrollment_status::EnrollmentStatus;
+use crate::resources::enums::liability_shift::LiabilityShift;
+
+/// Additional attributes of a card payment source.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardAttributes {
+    /// How the card is verified, for example with 3D Secure.
+    pub verification: Option<CardVerification>,
+}
+
+impl CardAttributes {
+    /// Verifies the card with the given method.
+    #[must_use]
+    pub const fn verification(method: CardVerificationMethod) -> Self {
+        Self {
+            verification: Some(CardVerification { method }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CardVerification {
+    /// The method used for card verification.
+    pub method: CardVerificationMethod,
+}
+
+/// The result of the 3D Secure authentication of a card payment.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AuthenticationResult {
+    /// Whether the liability for a fraudulent payment shifted to the card issuer.
+    pub liability_shift: Option<LiabilityShift>,
+
+    /// The results of the 3D Secure authentication.
+    pub three_d_secure: Option<ThreeDSecureAuthenticationResponse>,
+}
+
+impl AuthenticationResult {
+    /// Whether the liability shifted, or might shift, to the card issuer. PayPal recommends to
+    /// continue with the payment in these cases only.
+    pub fn is_liability_shifted(&self) -> bool {
+        matches!(
+            self.liability_shift,
+            Some(LiabilityShift::Yes | LiabilityShift::Possible)
+        )
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ThreeDSecureAuthenticationResponse {
+    /// The outcome of the issuer's authentication.
+    pub authentication_status: Option<AuthenticationStatus>,
+
+    /// Whether the card is enrolled in 3D Secure.
+    pub enrollment_status: Option<EnrollmentStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuthenticationResult;
+    use crate::resources::enums::authentication_status::AuthenticationStatus;
+
+    #[test]
+    fn authentication_result_liability_shift() {
+        let result: AuthenticationResult = serde_json::from_str(
+            r#"{
+                "liability_shift": "POSSIBLE",
+                "three_d_secure": {"enrollment_status": "Y", "authentication_status": "Y"}
+            }"#,
+        )
+        .unwrap();
+
+        assert!(result.is_liability_shifted());
+        assert_eq!(
+            result.three_d_secure.unwrap().authentication_status,
+            Some(AuthenticationStatus::Successful)
+        );
+
+        let result: AuthenticationResult =
+            serde_json::from_str(r#"{"liability_shift": "NO"}"#).unwrap();
+        assert!(!result.is_liability_shifted());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::resources::card_address_portable::CardAddressPortable;
+use crate::resources::card_authentication::AuthenticationResult;
 use crate::resources::enums::card_type::CardType;
 use crate::resources::enums::network::Network;
 use crate::resources::enums::payment_card_type::PaymentCardType;
@@ -14,10 +15,13 @@ pub struct CardResponse {
     /// The card holder's name as it appears on the card.
     pub name: Option<String>,
 
-    /// The primary account number (PAN) for the payment card.
+    /// The primary account number (PAN) for the payment card. Not returned in order responses,
+    /// which only contain the last digits.
+    #[serde(default)]
     pub number: String,
 
     /// The card expiration year and month, in Internet date format.
+    #[serde(default)]
     pub expiry: String,
 
     /// The three- or four-digit security code of the card. Also known as the CVV, CVC, CVN, CVE, or CID.
@@ -40,4 +44,8 @@ pub struct CardResponse {
     /// The billing address for this card. Supports only the address_line_1, address_line_2, admin_area_1, admin_area_2, postal_code,
     /// and country_code properties.
     pub billing_address: Option<CardAddressPortable>,
+
+    /// The results of the 3D Secure authentication, if the card was verified. Use it to decide
+    /// whether to continue with the payment.
+    pub authentication_result: Option<AuthenticationResult>,
 }
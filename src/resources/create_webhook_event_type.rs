@@ -1,7 +1,35 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+use crate::resources::show_webhook_event_type::ShowWebhookEventType;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct CreateWebhookEventType {
     /// The unique event name.
     pub name: String,
 }
+
+impl CreateWebhookEventType {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+
+    /// Subscribes to all events, including events as they are added.
+    #[must_use]
+    pub fn wildcard() -> Self {
+        Self::new("*")
+    }
+
+    /// Whether this is the `*` wildcard that subscribes to all events.
+    pub fn is_wildcard(&self) -> bool {
+        self.name == "*"
+    }
+
+    /// Creates an event type to subscribe to from an event type listed by PayPal.
+    #[must_use]
+    pub fn from_show_type(show: &ShowWebhookEventType) -> Self {
+        Self::new(&show.name)
+    }
+}
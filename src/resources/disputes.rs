@@ -0,0 +1,1092 @@
+use std::borrow::Cow;
+
+use chrono::{DateTime, Duration, Utc};
+use reqwest::multipart::{Form, Part};
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::Endpoint;
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::address_portable::AddressPortable;
+use crate::resources::enums::accept_claim_reason::AcceptClaimReason;
+use crate::resources::enums::accept_claim_type::AcceptClaimType;
+use crate::resources::enums::dispute_life_cycle_stage::DisputeLifeCycleStage;
+use crate::resources::enums::dispute_outcome_code::DisputeOutcomeCode;
+use crate::resources::enums::dispute_reason::DisputeReason;
+use crate::resources::enums::dispute_state::DisputeState;
+use crate::resources::enums::dispute_status::DisputeStatus;
+use crate::resources::enums::link_rel::LinkRel;
+use crate::resources::enums::offer_type::OfferType;
+use crate::resources::evidence::Evidence;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+use crate::resources::money::Money;
+use crate::resources::page::Page;
+
+pub struct Dispute;
+
+impl Dispute {
+    /// Lists disputes with a summary set of details, which shows the `dispute_id`, `reason`,
+    /// `status`, `dispute_amount`, `create_time`, and `update_time` fields. The disputes are
+    /// paginated with a cursor, see [`ListDisputesResponse::next_page_token`].
+    pub async fn list(
+        client: &Client,
+        query: ListDisputesQuery,
+    ) -> Result<ListDisputesResponse, PayPalError> {
+        client.get(&ListDisputes::new(query)).await
+    }
+
+    /// Shows details for a dispute, by ID.
+    pub async fn show(
+        client: &Client,
+        dispute_id: &str,
+    ) -> Result<ShowDisputeResponse, PayPalError> {
+        client.get(&ShowDispute::new(dispute_id.to_string())).await
+    }
+
+    /// Provides evidence for a dispute, by ID. The evidence is sent as `multipart/form-data`,
+    /// together with the documents in `files`, for example the PDF of a shipping receipt.
+    pub async fn provide_evidence(
+        client: &Client,
+        dispute_id: &str,
+        dto: ProvideEvidenceDto,
+        files: Vec<EvidenceFile>,
+    ) -> Result<ProvideEvidenceResponse, PayPalError> {
+        client
+            .post(&ProvideEvidence::new(dispute_id.to_string(), dto, files))
+            .await
+    }
+
+    /// Accepts liability for a claim, by ID. When you accept liability for a claim, the dispute
+    /// closes in the customer's favor and PayPal automatically refunds money to the customer from
+    /// the merchant's account.
+    pub async fn accept_claim(
+        client: &Client,
+        dispute_id: &str,
+        dto: AcceptClaimDto,
+    ) -> Result<DisputeActionResponse, PayPalError> {
+        client
+            .post(&AcceptClaim::new(dispute_id.to_string(), dto))
+            .await
+    }
+
+    /// Makes an offer to the other party to resolve a dispute, by ID. To make this call, the stage
+    /// in the dispute lifecycle must be `INQUIRY`. The customer can accept or deny the offer.
+    pub async fn make_offer(
+        client: &Client,
+        dispute_id: &str,
+        dto: MakeOfferDto,
+    ) -> Result<DisputeActionResponse, PayPalError> {
+        client
+            .post(&MakeOffer::new(dispute_id.to_string(), dto))
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ShowDisputeResponse {
+    /// The ID of the dispute.
+    pub dispute_id: Option<String>,
+
+    /// The date and time when the dispute was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the dispute was last updated, in Internet date and time format.
+    pub update_time: Option<String>,
+
+    /// The reason for the item-level dispute.
+    pub reason: Option<DisputeReason>,
+
+    /// The status of the dispute.
+    pub status: Option<DisputeStatus>,
+
+    /// The amount in the transaction that the customer originally disputed.
+    pub dispute_amount: Option<Money>,
+
+    /// The stage in the dispute lifecycle.
+    pub dispute_life_cycle_stage: Option<DisputeLifeCycleStage>,
+
+    /// The outcome of a resolved dispute.
+    pub dispute_outcome: Option<DisputeOutcome>,
+
+    /// The merchant-proposed offer for a dispute.
+    pub offer: Option<DisputeOffer>,
+
+    /// The allowed response options for the merchant.
+    pub allowed_response_options: Option<AllowedResponseOptions>,
+
+    /// The deadline until which the merchant can submit evidence for the dispute.
+    #[serde(flatten)]
+    pub submit_evidence_deadline: SubmitEvidenceDeadline,
+
+    /// An array of evidence documents.
+    pub evidences: Option<Vec<Evidence>>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ShowDisputeResponse {
+    /// Whether the merchant can accept the claim of this dispute.
+    pub fn can_accept_claim(&self) -> bool {
+        self.allowed_response_options
+            .as_ref()
+            .map_or(false, |options| options.accept_claim.is_some())
+    }
+
+    /// The offer types that the merchant can make to resolve this dispute. Empty if the merchant
+    /// cannot make an offer.
+    pub fn allowed_offer_types(&self) -> &[OfferType] {
+        self.allowed_response_options
+            .as_ref()
+            .and_then(|options| options.make_offer.as_ref())
+            .map_or(&[], |make_offer| &make_offer.offer_types)
+    }
+
+    /// Whether the evidence for this dispute has to be submitted within the next three days.
+    pub fn is_evidence_due_soon(&self) -> bool {
+        self.is_evidence_due_soon_at(Utc::now())
+    }
+
+    /// Whether the evidence for this dispute has to be submitted within three days after `now`.
+    pub fn is_evidence_due_soon_at(&self, now: DateTime<Utc>) -> bool {
+        self.submit_evidence_deadline
+            .time_remaining_at(now)
+            .map_or(false, |remaining| {
+                remaining >= Duration::zero() && remaining <= Duration::days(3)
+            })
+    }
+}
+
+impl HateoasLinks for ShowDisputeResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct DisputeOutcome {
+    /// The outcome of a resolved dispute.
+    pub outcome_code: Option<DisputeOutcomeCode>,
+
+    /// The amount that either the merchant or PayPal refunds the customer.
+    pub amount_refunded: Option<Money>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct DisputeOffer {
+    /// The customer-requested refund for this dispute.
+    pub buyer_requested_amount: Option<Money>,
+
+    /// The merchant-offered refund for this dispute.
+    pub seller_offered_amount: Option<Money>,
+
+    /// The type of offer that the merchant proposes for the dispute.
+    pub offer_type: Option<OfferType>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AllowedResponseOptions {
+    /// The types of refunds that the merchant can offer when accepting the claim.
+    pub accept_claim: Option<AcceptClaimOptions>,
+
+    /// The types of offers that the merchant can make to resolve the dispute.
+    pub make_offer: Option<MakeOfferOptions>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AcceptClaimOptions {
+    /// The refund types that the merchant can choose from when accepting the claim.
+    #[serde(default = "Vec::new")]
+    pub accept_claim_types: Vec<AcceptClaimType>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MakeOfferOptions {
+    /// The offer types that the merchant can choose from.
+    #[serde(default = "Vec::new")]
+    pub offer_types: Vec<OfferType>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct SubmitEvidenceDeadline {
+    /// The date and time by when the seller must respond to the dispute, in Internet date and time
+    /// format. If the seller does not respond by this time, the dispute is resolved in the buyer's
+    /// favor.
+    #[serde(rename = "seller_response_due_date")]
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+impl SubmitEvidenceDeadline {
+    /// The number of whole days left until the deadline. Negative if the deadline has passed.
+    pub fn days_remaining(&self) -> Option<i64> {
+        self.time_remaining().map(|remaining| remaining.num_days())
+    }
+
+    fn time_remaining(&self) -> Option<Duration> {
+        self.time_remaining_at(Utc::now())
+    }
+
+    fn time_remaining_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.due_date.map(|due_date| due_date - now)
+    }
+}
+
+/// A dispute in its short form, as returned when listing disputes.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct DisputeSummary {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+
+    /// The date and time when the dispute was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the dispute was last updated, in Internet date and time format.
+    pub update_time: Option<String>,
+
+    /// The status of the dispute.
+    pub status: DisputeStatus,
+
+    /// The reason for the item-level dispute.
+    pub reason: DisputeReason,
+
+    /// The amount in the transaction that the customer originally disputed.
+    pub dispute_amount: Money,
+
+    /// The ID of the disputed transaction.
+    pub disputed_transaction_id: Option<String>,
+
+    /// The date and time by when the merchant must respond to the dispute.
+    #[serde(alias = "seller_response_due_date")]
+    pub merchant_response_due_date: Option<DateTime<Utc>>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListDisputesQuery {
+    /// Filters the disputes in the response by a creation date and time. The start time must be
+    /// within the last 180 days. Cannot be combined with `disputed_transaction_id`.
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// Filters the disputes in the response by a transaction, by ID.
+    pub disputed_transaction_id: Option<String>,
+
+    /// Filters the disputes in the response by a comma-separated list of states.
+    pub dispute_state: Option<String>,
+
+    /// Limits the number of disputes in the response to this value. Value is from 1 to 50.
+    pub page_size: Option<u32>,
+
+    /// The token that describes the next page of results to fetch.
+    pub next_page_token: Option<String>,
+}
+
+impl ListDisputesQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    #[must_use]
+    pub fn disputed_transaction_id(mut self, disputed_transaction_id: String) -> Self {
+        self.disputed_transaction_id = Some(disputed_transaction_id);
+        self
+    }
+
+    /// Adds a state to filter the disputes by. Disputes in any of the added states are returned.
+    #[must_use]
+    pub fn state(mut self, state: DisputeState) -> Self {
+        self.dispute_state = Some(match self.dispute_state {
+            Some(states) => format!("{states},{state}"),
+            None => state.to_string(),
+        });
+        self
+    }
+
+    #[must_use]
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    #[must_use]
+    pub fn next_page_token(mut self, next_page_token: String) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListDisputesResponse {
+    /// An array of disputes, in their short form.
+    #[serde(default = "Vec::new")]
+    pub items: Vec<DisputeSummary>,
+
+    /// An array of request-related HATEOAS links, including the link to the next page.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ListDisputesResponse {
+    /// The token of the next page, taken from the `next` link. Pass it to
+    /// [`ListDisputesQuery::next_page_token`] to fetch the next page. `None` on the last page.
+    pub fn next_page_token(&self) -> Option<String> {
+        let url = Url::parse(&self.link(LinkRel::Next)?.href).ok()?;
+        url.query_pairs()
+            .find(|(key, _)| key == "next_page_token")
+            .map(|(_, value)| value.into_owned())
+    }
+
+    /// Converts the response into a generic [`Page`] of disputes.
+    pub fn into_page(self) -> Page<DisputeSummary> {
+        Page {
+            items: self.items,
+            total_items: None,
+            total_pages: None,
+            links: self.links,
+        }
+    }
+}
+
+impl HateoasLinks for ListDisputesResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct ListDisputes {
+    query: ListDisputesQuery,
+}
+
+impl ListDisputes {
+    pub const fn new(query: ListDisputesQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListDisputes {
+    type QueryParams = ListDisputesQuery;
+    type RequestBody = ();
+    type ResponseBody = ListDisputesResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/customer/disputes")
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ProvideEvidenceDto {
+    /// An array of evidences for the dispute.
+    pub evidences: Vec<Evidence>,
+}
+
+impl ProvideEvidenceDto {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn evidence(mut self, evidence: Evidence) -> Self {
+        self.evidences.push(evidence);
+        self
+    }
+}
+
+/// A document that is uploaded as proof for a dispute. PayPal accepts PDF, JPEG, GIF and PNG
+/// files of up to 10 MB each, and 50 MB in total.
+#[derive(Clone, Debug)]
+pub struct EvidenceFile {
+    /// The file name, including its extension.
+    pub name: String,
+
+    /// The MIME type of the file.
+    pub content_type: &'static str,
+
+    /// The content of the file.
+    pub content: Vec<u8>,
+}
+
+impl EvidenceFile {
+    #[must_use]
+    pub const fn pdf(name: String, content: Vec<u8>) -> Self {
+        Self {
+            name,
+            content_type: "application/pdf",
+            content,
+        }
+    }
+
+    #[must_use]
+    pub const fn jpeg(name: String, content: Vec<u8>) -> Self {
+        Self {
+            name,
+            content_type: "image/jpeg",
+            content,
+        }
+    }
+
+    #[must_use]
+    pub const fn png(name: String, content: Vec<u8>) -> Self {
+        Self {
+            name,
+            content_type: "image/png",
+            content,
+        }
+    }
+
+    #[must_use]
+    pub const fn gif(name: String, content: Vec<u8>) -> Self {
+        Self {
+            name,
+            content_type: "image/gif",
+            content,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProvideEvidenceResponse {
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl HateoasLinks for ProvideEvidenceResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct ProvideEvidence {
+    dispute_id: String,
+    body: ProvideEvidenceDto,
+    files: Vec<EvidenceFile>,
+}
+
+impl ProvideEvidence {
+    pub const fn new(
+        dispute_id: String,
+        body: ProvideEvidenceDto,
+        files: Vec<EvidenceFile>,
+    ) -> Self {
+        Self {
+            dispute_id,
+            body,
+            files,
+        }
+    }
+}
+
+impl Endpoint for ProvideEvidence {
+    type QueryParams = ();
+    type RequestBody = ProvideEvidenceDto;
+    type ResponseBody = ProvideEvidenceResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/customer/disputes/{}/provide-evidence",
+            self.dispute_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+
+    /// The evidences are sent as JSON in the `input` part, each document in an `evidence_file`
+    /// part.
+    fn multipart_body(&self) -> Result<Option<Form>, PayPalError> {
+        let input = Part::text(serde_json::to_string(&self.body)?).mime_str("application/json")?;
+        let mut form = Form::new().part("input", input);
+
+        for file in &self.files {
+            let part = Part::bytes(file.content.clone())
+                .file_name(file.name.clone())
+                .mime_str(file.content_type)?;
+            form = form.part("evidence_file", part);
+        }
+
+        Ok(Some(form))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AcceptClaimDto {
+    /// The merchant's notes about the claim. PayPal can, but the customer cannot, view these
+    /// notes.
+    pub note: String,
+
+    /// The merchant's reason for acceptance of the customer's claim.
+    pub accept_claim_reason: Option<AcceptClaimReason>,
+
+    /// The type of refund the merchant proposes to the customer.
+    pub accept_claim_type: Option<AcceptClaimType>,
+
+    /// The refund amount. If not set, the full disputed amount is refunded.
+    pub refund_amount: Option<Money>,
+
+    /// The address to which the customer has to return the item.
+    pub return_shipping_address: Option<AddressPortable>,
+
+    /// The merchant-provided ID of the invoice for the refund.
+    pub invoice_id: Option<String>,
+}
+
+impl AcceptClaimDto {
+    #[must_use]
+    pub fn new(note: String) -> Self {
+        Self {
+            note,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub const fn reason(mut self, accept_claim_reason: AcceptClaimReason) -> Self {
+        self.accept_claim_reason = Some(accept_claim_reason);
+        self
+    }
+
+    /// Refunds the given amount instead of the full disputed amount.
+    #[must_use]
+    pub fn partial_refund(mut self, refund_amount: Money) -> Self {
+        self.accept_claim_type = Some(AcceptClaimType::PartialRefund);
+        self.refund_amount = Some(refund_amount);
+        self
+    }
+
+    /// Refunds the customer once the item has been returned to the given address.
+    #[must_use]
+    pub fn refund_with_return(mut self, return_shipping_address: AddressPortable) -> Self {
+        self.accept_claim_type = Some(AcceptClaimType::RefundWithReturn);
+        self.return_shipping_address = Some(return_shipping_address);
+        self
+    }
+
+    #[must_use]
+    pub fn invoice_id(mut self, invoice_id: String) -> Self {
+        self.invoice_id = Some(invoice_id);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct MakeOfferDto {
+    /// The merchant's notes about the offer. PayPal can, but the customer cannot, view these
+    /// notes.
+    pub note: String,
+
+    /// The amount proposed to resolve the dispute.
+    pub offer_amount: Option<Money>,
+
+    /// The return address for the item.
+    pub return_shipping_address: Option<AddressPortable>,
+
+    /// The merchant-provided ID of the invoice for the refund.
+    pub invoice_id: Option<String>,
+
+    /// The type of offer that the merchant proposes for the dispute.
+    pub offer_type: OfferType,
+}
+
+impl MakeOfferDto {
+    /// Offers to refund the given amount without an item return.
+    #[must_use]
+    pub const fn refund(note: String, offer_amount: Money) -> Self {
+        Self::new(note, OfferType::Refund, Some(offer_amount))
+    }
+
+    /// Offers to refund the given amount once the item has been returned to the given address.
+    #[must_use]
+    pub fn refund_with_return(
+        note: String,
+        offer_amount: Money,
+        return_shipping_address: AddressPortable,
+    ) -> Self {
+        let mut dto = Self::new(note, OfferType::RefundWithReturn, Some(offer_amount));
+        dto.return_shipping_address = Some(return_shipping_address);
+        dto
+    }
+
+    /// Offers to refund the given amount and to send a replacement item.
+    #[must_use]
+    pub const fn refund_with_replacement(note: String, offer_amount: Money) -> Self {
+        Self::new(note, OfferType::RefundWithReplacement, Some(offer_amount))
+    }
+
+    /// Offers to send a replacement item without a refund.
+    #[must_use]
+    pub const fn replacement_without_refund(note: String) -> Self {
+        Self::new(note, OfferType::ReplacementWithoutRefund, None)
+    }
+
+    const fn new(note: String, offer_type: OfferType, offer_amount: Option<Money>) -> Self {
+        Self {
+            note,
+            offer_amount,
+            return_shipping_address: None,
+            invoice_id: None,
+            offer_type,
+        }
+    }
+
+    #[must_use]
+    pub fn invoice_id(mut self, invoice_id: String) -> Self {
+        self.invoice_id = Some(invoice_id);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct MakeOffer {
+    dispute_id: String,
+    body: MakeOfferDto,
+}
+
+impl MakeOffer {
+    pub const fn new(dispute_id: String, body: MakeOfferDto) -> Self {
+        Self { dispute_id, body }
+    }
+}
+
+impl Endpoint for MakeOffer {
+    type QueryParams = ();
+    type RequestBody = MakeOfferDto;
+    type ResponseBody = DisputeActionResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/customer/disputes/{}/make-offer",
+            self.dispute_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// The response to an action on a dispute, like accepting a claim.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct DisputeActionResponse {
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl HateoasLinks for DisputeActionResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct AcceptClaim {
+    dispute_id: String,
+    body: AcceptClaimDto,
+}
+
+impl AcceptClaim {
+    pub const fn new(dispute_id: String, body: AcceptClaimDto) -> Self {
+        Self { dispute_id, body }
+    }
+}
+
+impl Endpoint for AcceptClaim {
+    type QueryParams = ();
+    type RequestBody = AcceptClaimDto;
+    type ResponseBody = DisputeActionResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/customer/disputes/{}/accept-claim",
+            self.dispute_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ShowDispute {
+    dispute_id: String,
+}
+
+impl ShowDispute {
+    pub const fn new(dispute_id: String) -> Self {
+        Self { dispute_id }
+    }
+}
+
+impl Endpoint for ShowDispute {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ShowDisputeResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/customer/disputes/{}", self.dispute_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::*;
+    use crate::resources::enums::currency_code::CurrencyCode;
+    use crate::resources::enums::evidence_type::EvidenceType;
+
+    #[test]
+    fn evidence_types_requiring_documents() {
+        assert!(EvidenceType::ProofOfDeliverySignature.is_document());
+        assert!(EvidenceType::PoliceReport.is_document());
+        assert!(EvidenceType::ScreenShot.is_document());
+        assert!(!EvidenceType::ProofOfFulfillment.is_document());
+        assert!(!EvidenceType::ProofOfRefund.is_document());
+        assert!(!EvidenceType::ItemDescription.is_document());
+        assert!(!EvidenceType::Other.is_document());
+    }
+
+    #[test]
+    fn evidence_with_unknown_type() {
+        let evidence: Evidence = serde_json::from_str(
+            r#"{
+                "evidence_type": "PROOF_OF_AUTHENTICITY",
+                "notes": "Certificate of authenticity attached.",
+                "date": "2019-04-10T09:00:00.000Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(evidence.evidence_type, EvidenceType::Unknown);
+        assert!(!evidence.evidence_type.is_document());
+    }
+
+    #[test]
+    fn evidence_deadline_days_remaining() {
+        let deadline = SubmitEvidenceDeadline {
+            due_date: Some(Utc::now() + Duration::days(2) + Duration::hours(1)),
+        };
+        assert_eq!(deadline.days_remaining(), Some(2));
+
+        let deadline = SubmitEvidenceDeadline { due_date: None };
+        assert_eq!(deadline.days_remaining(), None);
+    }
+
+    #[test]
+    fn evidence_due_soon_threshold() {
+        let response: super::ShowDisputeResponse = serde_json::from_str(
+            r#"{"dispute_id": "PP-D-1", "seller_response_due_date": "2019-04-29T07:00:00.000Z"}"#,
+        )
+        .unwrap();
+        let due_date = Utc.with_ymd_and_hms(2019, 4, 29, 7, 0, 0).unwrap();
+
+        assert!(!response.is_evidence_due_soon_at(due_date - Duration::days(4)));
+        assert!(
+            !response.is_evidence_due_soon_at(due_date - Duration::days(3) - Duration::seconds(1))
+        );
+        assert!(response.is_evidence_due_soon_at(due_date - Duration::days(3)));
+        assert!(response.is_evidence_due_soon_at(due_date - Duration::hours(1)));
+        assert!(response.is_evidence_due_soon_at(due_date));
+        assert!(!response.is_evidence_due_soon_at(due_date + Duration::seconds(1)));
+
+        let response: super::ShowDisputeResponse =
+            serde_json::from_str(r#"{"dispute_id": "PP-D-1"}"#).unwrap();
+        assert!(!response.is_evidence_due_soon_at(due_date));
+    }
+
+    #[test]
+    fn evidence_deadline_deserializes_from_seller_response_due_date() {
+        let response: super::ShowDisputeResponse = serde_json::from_str(
+            r#"{"dispute_id": "PP-D-1", "seller_response_due_date": "2019-04-29T07:00:00.000Z"}"#,
+        )
+        .unwrap();
+
+        assert!(response.submit_evidence_deadline.due_date.is_some());
+        assert!(!response.is_evidence_due_soon());
+    }
+
+    #[test]
+    fn list_disputes_query_and_next_page() {
+        let endpoint = ListDisputes::new(
+            ListDisputesQuery::new()
+                .state(DisputeState::RequiredAction)
+                .state(DisputeState::UnderPayPalReview)
+                .page_size(10),
+        );
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "dispute_state=REQUIRED_ACTION%2CUNDER_PAYPAL_REVIEW&page_size=10"
+        );
+
+        let response: ListDisputesResponse = serde_json::from_str(
+            r#"{
+                "items": [
+                    {
+                        "dispute_id": "PP-D-4012",
+                        "create_time": "2019-04-11T04:18:00.000Z",
+                        "update_time": "2019-04-21T04:19:08.000Z",
+                        "reason": "MERCHANDISE_OR_SERVICE_NOT_RECEIVED",
+                        "status": "UNDER_REVIEW",
+                        "dispute_amount": {"currency_code": "USD", "value": "3.00"}
+                    }
+                ],
+                "links": [
+                    {
+                        "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes?page_size=10&next_page_token=DQ8eNnRcGEEqDVoQ",
+                        "rel": "next",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.next_page_token().as_deref(),
+            Some("DQ8eNnRcGEEqDVoQ")
+        );
+        assert_eq!(response.into_page().items[0].dispute_id, "PP-D-4012");
+    }
+
+    #[test]
+    fn dispute_summary_with_unknown_status_and_reason() {
+        let summary: DisputeSummary = serde_json::from_str(
+            r#"{
+                "dispute_id": "PP-D-4013",
+                "reason": "SOME_FUTURE_REASON",
+                "status": "SOME_FUTURE_STATUS",
+                "dispute_amount": {"currency_code": "USD", "value": "3.00"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(summary.reason, DisputeReason::Unknown);
+        assert_eq!(summary.status, DisputeStatus::Unknown);
+    }
+
+    #[test]
+    fn show_dispute_lifecycle() {
+        let response: ShowDisputeResponse = serde_json::from_str(
+            r#"{
+                "dispute_id": "PP-D-27803",
+                "reason": "MERCHANDISE_OR_SERVICE_NOT_AS_DESCRIBED",
+                "status": "RESOLVED",
+                "dispute_amount": {"currency_code": "USD", "value": "50.00"},
+                "dispute_life_cycle_stage": "CHARGEBACK",
+                "dispute_outcome": {
+                    "outcome_code": "RESOLVED_BUYER_FAVOUR",
+                    "amount_refunded": {"currency_code": "USD", "value": "50.00"}
+                },
+                "offer": {
+                    "buyer_requested_amount": {"currency_code": "USD", "value": "50.00"},
+                    "seller_offered_amount": {"currency_code": "USD", "value": "20.00"},
+                    "offer_type": "REFUND"
+                },
+                "allowed_response_options": {
+                    "make_offer": {"offer_types": ["REFUND", "REFUND_WITH_RETURN"]},
+                    "accept_claim": {"accept_claim_types": ["REFUND"]}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.reason,
+            Some(DisputeReason::MerchandiseOrServiceNotAsDescribed)
+        );
+        assert_eq!(response.status, Some(DisputeStatus::Resolved));
+        assert_eq!(
+            response.dispute_outcome.as_ref().unwrap().outcome_code,
+            Some(DisputeOutcomeCode::ResolvedBuyerFavour)
+        );
+        assert_eq!(
+            response.offer.as_ref().unwrap().offer_type,
+            Some(OfferType::Refund)
+        );
+        assert!(response.can_accept_claim());
+        assert_eq!(
+            response.allowed_offer_types(),
+            [OfferType::Refund, OfferType::RefundWithReturn]
+        );
+    }
+
+    #[test]
+    fn show_dispute_with_unknown_stage_outcome_and_offer_types() {
+        let response: ShowDisputeResponse = serde_json::from_str(
+            r#"{
+                "dispute_id": "PP-D-27803",
+                "dispute_life_cycle_stage": "SECOND_ARBITRATION",
+                "dispute_outcome": {"outcome_code": "RESOLVED_BY_MEDIATOR"},
+                "offer": {"offer_type": "STORE_CREDIT"},
+                "allowed_response_options": {
+                    "accept_claim": {"accept_claim_types": ["REFUND", "STORE_CREDIT"]},
+                    "make_offer": {"offer_types": ["REFUND", "STORE_CREDIT"]}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.dispute_life_cycle_stage,
+            Some(DisputeLifeCycleStage::Unknown)
+        );
+        assert_eq!(
+            response.dispute_outcome.as_ref().unwrap().outcome_code,
+            Some(DisputeOutcomeCode::Unknown)
+        );
+        assert_eq!(
+            response.offer.as_ref().unwrap().offer_type,
+            Some(OfferType::Unknown)
+        );
+        assert_eq!(
+            response
+                .allowed_response_options
+                .as_ref()
+                .unwrap()
+                .accept_claim
+                .as_ref()
+                .unwrap()
+                .accept_claim_types,
+            [AcceptClaimType::Refund, AcceptClaimType::Unknown]
+        );
+        assert_eq!(
+            response.allowed_offer_types(),
+            [OfferType::Refund, OfferType::Unknown]
+        );
+    }
+
+    #[test]
+    fn provide_evidence_multipart_body() {
+        let endpoint = ProvideEvidence::new(
+            "PP-D-27803".to_string(),
+            ProvideEvidenceDto::new().evidence(
+                Evidence::new(EvidenceType::ProofOfFulfillment)
+                    .tracking_info("FEDEX".to_string(), "122533485".to_string())
+                    .notes("Shipped on time".to_string()),
+            ),
+            vec![EvidenceFile::pdf(
+                "receipt.pdf".to_string(),
+                b"%PDF-1.4".to_vec(),
+            )],
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "evidences": [
+                    {
+                        "evidence_type": "PROOF_OF_FULFILLMENT",
+                        "evidence_info": {
+                            "tracking_info": [
+                                {"carrier_name": "FEDEX", "tracking_number": "122533485"}
+                            ]
+                        },
+                        "notes": "Shipped on time"
+                    }
+                ]
+            })
+        );
+        assert!(endpoint.multipart_body().unwrap().is_some());
+    }
+
+    #[test]
+    fn accept_claim_request_body() {
+        let endpoint = AcceptClaim::new(
+            "PP-D-27803".to_string(),
+            AcceptClaimDto::new("Partial refund for the damaged item.".to_string())
+                .reason(AcceptClaimReason::DidNotShipItem)
+                .partial_refund(Money::new(
+                    CurrencyCode::UnitedStatesDollar,
+                    "20.00".to_string(),
+                )),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v1/customer/disputes/PP-D-27803/accept-claim"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "note": "Partial refund for the damaged item.",
+                "accept_claim_reason": "DID_NOT_SHIP_ITEM",
+                "accept_claim_type": "PARTIAL_REFUND",
+                "refund_amount": {"currency_code": "USD", "value": "20.00"}
+            })
+        );
+    }
+
+    #[test]
+    fn make_offer_request_body() {
+        let refund = MakeOffer::new(
+            "PP-D-27803".to_string(),
+            MakeOfferDto::refund(
+                "Offer refund with replacement item.".to_string(),
+                Money::new(CurrencyCode::UnitedStatesDollar, "23.00".to_string()),
+            )
+            .invoice_id("Invoice-1".to_string()),
+        );
+        assert_eq!(refund.path(), "v1/customer/disputes/PP-D-27803/make-offer");
+        assert_eq!(
+            serde_json::to_value(refund.request_body()).unwrap(),
+            serde_json::json!({
+                "note": "Offer refund with replacement item.",
+                "offer_amount": {"currency_code": "USD", "value": "23.00"},
+                "invoice_id": "Invoice-1",
+                "offer_type": "REFUND"
+            })
+        );
+
+        let replacement = MakeOffer::new(
+            "PP-D-27803".to_string(),
+            MakeOfferDto::replacement_without_refund("Sending a new item.".to_string()),
+        );
+        assert_eq!(
+            serde_json::to_value(replacement.request_body()).unwrap(),
+            serde_json::json!({
+                "note": "Sending a new item.",
+                "offer_type": "REPLACEMENT_WITHOUT_REFUND"
+            })
+        );
+    }
+}
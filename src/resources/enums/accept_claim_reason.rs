@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// The merchant's reason for accepting a claim.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AcceptClaimReason {
+    /// The merchant did not ship the item.
+    #[serde(rename = "DID_NOT_SHIP_ITEM")]
+    DidNotShipItem,
+    /// Responding to the dispute is too time-consuming.
+    #[serde(rename = "TOO_TIME_CONSUMING")]
+    TooTimeConsuming,
+    /// The item was lost in the mail.
+    #[serde(rename = "LOST_IN_MAIL")]
+    LostInMail,
+    /// The merchant is not able to win the dispute.
+    #[serde(rename = "NOT_ABLE_TO_WIN")]
+    NotAbleToWin,
+    /// The merchant accepts the claim to comply with company policy.
+    #[serde(rename = "COMPANY_POLICY")]
+    CompanyPolicy,
+    /// The merchant did not provide a reason.
+    #[serde(rename = "REASON_NOT_SET")]
+    ReasonNotSet,
+}
+
+impl AcceptClaimReason {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::DidNotShipItem => "DID_NOT_SHIP_ITEM",
+            Self::TooTimeConsuming => "TOO_TIME_CONSUMING",
+            Self::LostInMail => "LOST_IN_MAIL",
+            Self::NotAbleToWin => "NOT_ABLE_TO_WIN",
+            Self::CompanyPolicy => "COMPANY_POLICY",
+            Self::ReasonNotSet => "REASON_NOT_SET",
+        }
+    }
+}
+
+impl AsRef<str> for AcceptClaimReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for AcceptClaimReason {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of refund the merchant offers when accepting a claim.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AcceptClaimType {
+    /// The merchant refunds the customer without an item return.
+    #[serde(rename = "REFUND")]
+    Refund,
+    /// The customer must return the item to the merchant before the merchant refunds the money.
+    #[serde(rename = "REFUND_WITH_RETURN")]
+    RefundWithReturn,
+    /// The merchant refunds a part of the disputed amount.
+    #[serde(rename = "PARTIAL_REFUND")]
+    PartialRefund,
+    /// The customer must return the item with a shipment label provided by the merchant.
+    #[serde(rename = "REFUND_WITH_RETURN_SHIPMENT_LABEL")]
+    RefundWithReturnShipmentLabel,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl AcceptClaimType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Refund => "REFUND",
+            Self::RefundWithReturn => "REFUND_WITH_RETURN",
+            Self::PartialRefund => "PARTIAL_REFUND",
+            Self::RefundWithReturnShipmentLabel => "REFUND_WITH_RETURN_SHIPMENT_LABEL",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for AcceptClaimType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for AcceptClaimType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
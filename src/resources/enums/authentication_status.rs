@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// The outcome of the 3D Secure authentication.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AuthenticationStatus {
+    /// The authentication was successful.
+    #[serde(rename = "Y")]
+    Successful,
+    /// The authentication failed.
+    #[serde(rename = "N")]
+    Failed,
+    /// The authentication was rejected by the issuer.
+    #[serde(rename = "R")]
+    Rejected,
+    /// The authentication was attempted but not completed.
+    #[serde(rename = "A")]
+    Attempted,
+    /// The authentication could not be performed.
+    #[serde(rename = "U")]
+    Unable,
+    /// A challenge is required to complete the authentication.
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    /// The authentication was for information only.
+    #[serde(rename = "I")]
+    InfoOnly,
+    /// The authentication is decoupled and completes out of band.
+    #[serde(rename = "D")]
+    Decoupled,
+}
+
+impl AuthenticationStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Successful => "Y",
+            Self::Failed => "N",
+            Self::Rejected => "R",
+            Self::Attempted => "A",
+            Self::Unable => "U",
+            Self::ChallengeRequired => "C",
+            Self::InfoOnly => "I",
+            Self::Decoupled => "D",
+        }
+    }
+}
+
+impl AsRef<str> for AuthenticationStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for AuthenticationStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
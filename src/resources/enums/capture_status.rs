@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -40,3 +42,33 @@ impl std::fmt::Display for CaptureStatus {
         self.as_str().fmt(formatter)
     }
 }
+
+impl FromStr for CaptureStatus {
+    type Err = CaptureStatusError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "COMPLETED" => Ok(Self::Completed),
+            "DECLINED" => Ok(Self::Declined),
+            "PARTIALLY_REFUNDED" => Ok(Self::PartiallyRefunded),
+            "PENDING" => Ok(Self::Pending),
+            "REFUNDED" => Ok(Self::Refunded),
+            "FAILED" => Ok(Self::Failed),
+            _ => Err(CaptureStatusError(())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CaptureStatusError(/* private */ ());
+
+impl std::fmt::Display for CaptureStatusError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        "invalid capture status".fmt(formatter)
+    }
+}
+
+impl std::error::Error for CaptureStatusError {
+    fn description(&self) -> &str {
+        "invalid capture status"
+    }
+}
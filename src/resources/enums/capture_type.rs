@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of capture for a subscription.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CaptureType {
+    /// The outstanding balance that the subscriber must clear.
+    #[serde(rename = "OUTSTANDING_BALANCE")]
+    OutstandingBalance,
+}
+
+impl CaptureType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OutstandingBalance => "OUTSTANDING_BALANCE",
+        }
+    }
+}
+
+impl AsRef<str> for CaptureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CaptureType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// The method used to verify a card, for example with 3D Secure.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum CardVerificationMethod {
+    /// Always triggers 3D Secure authentication.
+    #[serde(rename = "SCA_ALWAYS")]
+    ScaAlways,
+    /// Triggers 3D Secure authentication only when mandated, for example by PSD2 in Europe.
+    #[serde(rename = "SCA_WHEN_REQUIRED")]
+    ScaWhenRequired,
+}
+
+impl CardVerificationMethod {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ScaAlways => "SCA_ALWAYS",
+            Self::ScaWhenRequired => "SCA_WHEN_REQUIRED",
+        }
+    }
+}
+
+impl AsRef<str> for CardVerificationMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for CardVerificationMethod {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// The stage in the dispute lifecycle.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DisputeLifeCycleStage {
+    /// The customer and merchant interact in an attempt to resolve the dispute without escalation to PayPal.
+    #[serde(rename = "INQUIRY")]
+    Inquiry,
+    /// The customer disputed the transaction with their card issuer.
+    #[serde(rename = "CHARGEBACK")]
+    Chargeback,
+    /// The first appeal stage for merchants who do not agree with the outcome of a chargeback.
+    #[serde(rename = "PRE_ARBITRATION")]
+    PreArbitration,
+    /// The second appeal stage for merchants who do not agree with the outcome of a chargeback.
+    #[serde(rename = "ARBITRATION")]
+    Arbitration,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl DisputeLifeCycleStage {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Inquiry => "INQUIRY",
+            Self::Chargeback => "CHARGEBACK",
+            Self::PreArbitration => "PRE_ARBITRATION",
+            Self::Arbitration => "ARBITRATION",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for DisputeLifeCycleStage {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DisputeLifeCycleStage {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
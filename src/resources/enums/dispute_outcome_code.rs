@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a resolved dispute.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DisputeOutcomeCode {
+    /// The dispute was resolved in the customer's favor.
+    #[serde(rename = "RESOLVED_BUYER_FAVOUR")]
+    ResolvedBuyerFavour,
+    /// The dispute was resolved in the merchant's favor.
+    #[serde(rename = "RESOLVED_SELLER_FAVOUR")]
+    ResolvedSellerFavour,
+    /// PayPal provided the merchant or customer with protection and the case is resolved.
+    #[serde(rename = "RESOLVED_WITH_PAYOUT")]
+    ResolvedWithPayout,
+    /// The customer canceled the dispute.
+    #[serde(rename = "CANCELED_BY_BUYER")]
+    CanceledByBuyer,
+    /// The dispute was accepted.
+    #[serde(rename = "ACCEPTED")]
+    Accepted,
+    /// The dispute was denied.
+    #[serde(rename = "DENIED")]
+    Denied,
+    /// A dispute was not created.
+    #[serde(rename = "NONE")]
+    None,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl DisputeOutcomeCode {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ResolvedBuyerFavour => "RESOLVED_BUYER_FAVOUR",
+            Self::ResolvedSellerFavour => "RESOLVED_SELLER_FAVOUR",
+            Self::ResolvedWithPayout => "RESOLVED_WITH_PAYOUT",
+            Self::CanceledByBuyer => "CANCELED_BY_BUYER",
+            Self::Accepted => "ACCEPTED",
+            Self::Denied => "DENIED",
+            Self::None => "NONE",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for DisputeOutcomeCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DisputeOutcomeCode {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
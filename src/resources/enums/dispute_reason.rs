@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+/// The reason for a dispute.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DisputeReason {
+    /// The customer did not receive the merchandise or service.
+    #[serde(rename = "MERCHANDISE_OR_SERVICE_NOT_RECEIVED")]
+    MerchandiseOrServiceNotReceived,
+    /// The customer reports that the merchandise or service is not as described.
+    #[serde(rename = "MERCHANDISE_OR_SERVICE_NOT_AS_DESCRIBED")]
+    MerchandiseOrServiceNotAsDescribed,
+    /// The customer did not authorize purchase of the merchandise or service.
+    #[serde(rename = "UNAUTHORISED")]
+    Unauthorised,
+    /// The refund or credit was not processed for the customer.
+    #[serde(rename = "CREDIT_NOT_PROCESSED")]
+    CreditNotProcessed,
+    /// The transaction was a duplicate.
+    #[serde(rename = "DUPLICATE_TRANSACTION")]
+    DuplicateTransaction,
+    /// The customer was charged an incorrect amount.
+    #[serde(rename = "INCORRECT_AMOUNT")]
+    IncorrectAmount,
+    /// The customer paid for the transaction through other means.
+    #[serde(rename = "PAYMENT_BY_OTHER_MEANS")]
+    PaymentByOtherMeans,
+    /// The customer was being charged for a subscription or a recurring transaction that was canceled.
+    #[serde(rename = "CANCELED_RECURRING_BILLING")]
+    CanceledRecurringBilling,
+    /// A problem occurred with the remittance.
+    #[serde(rename = "PROBLEM_WITH_REMITTANCE")]
+    ProblemWithRemittance,
+    /// Other.
+    #[serde(rename = "OTHER")]
+    Other,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl DisputeReason {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MerchandiseOrServiceNotReceived => "MERCHANDISE_OR_SERVICE_NOT_RECEIVED",
+            Self::MerchandiseOrServiceNotAsDescribed => "MERCHANDISE_OR_SERVICE_NOT_AS_DESCRIBED",
+            Self::Unauthorised => "UNAUTHORISED",
+            Self::CreditNotProcessed => "CREDIT_NOT_PROCESSED",
+            Self::DuplicateTransaction => "DUPLICATE_TRANSACTION",
+            Self::IncorrectAmount => "INCORRECT_AMOUNT",
+            Self::PaymentByOtherMeans => "PAYMENT_BY_OTHER_MEANS",
+            Self::CanceledRecurringBilling => "CANCELED_RECURRING_BILLING",
+            Self::ProblemWithRemittance => "PROBLEM_WITH_REMITTANCE",
+            Self::Other => "OTHER",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for DisputeReason {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DisputeReason {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// The state of a dispute, used to filter disputes when listing them.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DisputeState {
+    /// Disputes that require an action from the merchant.
+    #[serde(rename = "REQUIRED_ACTION")]
+    RequiredAction,
+    /// Disputes that wait for an action from the customer.
+    #[serde(rename = "REQUIRED_OTHER_PARTY_ACTION")]
+    RequiredOtherPartyAction,
+    /// Disputes that are under review with PayPal.
+    #[serde(rename = "UNDER_PAYPAL_REVIEW")]
+    UnderPayPalReview,
+    /// Disputes that are resolved.
+    #[serde(rename = "RESOLVED")]
+    Resolved,
+    /// Disputes that are still inquiries, before they are escalated to claims.
+    #[serde(rename = "OPEN_INQUIRIES")]
+    OpenInquiries,
+    /// Resolved disputes that can be appealed.
+    #[serde(rename = "APPEALABLE")]
+    Appealable,
+}
+
+impl DisputeState {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::RequiredAction => "REQUIRED_ACTION",
+            Self::RequiredOtherPartyAction => "REQUIRED_OTHER_PARTY_ACTION",
+            Self::UnderPayPalReview => "UNDER_PAYPAL_REVIEW",
+            Self::Resolved => "RESOLVED",
+            Self::OpenInquiries => "OPEN_INQUIRIES",
+            Self::Appealable => "APPEALABLE",
+        }
+    }
+}
+
+impl AsRef<str> for DisputeState {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DisputeState {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a dispute.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DisputeStatus {
+    /// The dispute is open.
+    #[serde(rename = "OPEN")]
+    Open,
+    /// The dispute is waiting for a response from the customer.
+    #[serde(rename = "WAITING_FOR_BUYER_RESPONSE")]
+    WaitingForBuyerResponse,
+    /// The dispute is waiting for a response from the merchant.
+    #[serde(rename = "WAITING_FOR_SELLER_RESPONSE")]
+    WaitingForSellerResponse,
+    /// The dispute is under review with PayPal.
+    #[serde(rename = "UNDER_REVIEW")]
+    UnderReview,
+    /// The dispute is resolved.
+    #[serde(rename = "RESOLVED")]
+    Resolved,
+    /// The default status if the dispute does not have one of the other statuses.
+    #[serde(rename = "OTHER")]
+    Other,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl DisputeStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "OPEN",
+            Self::WaitingForBuyerResponse => "WAITING_FOR_BUYER_RESPONSE",
+            Self::WaitingForSellerResponse => "WAITING_FOR_SELLER_RESPONSE",
+            Self::UnderReview => "UNDER_REVIEW",
+            Self::Resolved => "RESOLVED",
+            Self::Other => "OTHER",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for DisputeStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for DisputeStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
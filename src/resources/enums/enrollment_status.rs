@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether the card is enrolled in 3D Secure.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EnrollmentStatus {
+    /// The card is enrolled and ready to complete 3D Secure authentication.
+    #[serde(rename = "Y")]
+    Ready,
+    /// The card is not enrolled in 3D Secure.
+    #[serde(rename = "N")]
+    NotReady,
+    /// The issuer is unavailable to complete 3D Secure authentication.
+    #[serde(rename = "U")]
+    Unavailable,
+    /// 3D Secure authentication was bypassed.
+    #[serde(rename = "B")]
+    Bypassed,
+}
+
+impl EnrollmentStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ready => "Y",
+            Self::NotReady => "N",
+            Self::Unavailable => "U",
+            Self::Bypassed => "B",
+        }
+    }
+}
+
+impl AsRef<str> for EnrollmentStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EnrollmentStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
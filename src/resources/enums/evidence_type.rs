@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of evidence that is submitted for a dispute.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum EvidenceType {
+    /// Proof that the order was fulfilled, usually through tracking information.
+    #[serde(rename = "PROOF_OF_FULFILLMENT")]
+    ProofOfFulfillment,
+    /// Proof that the payment was refunded, usually through the refund ID.
+    #[serde(rename = "PROOF_OF_REFUND")]
+    ProofOfRefund,
+    /// Proof of delivery signature.
+    #[serde(rename = "PROOF_OF_DELIVERY_SIGNATURE")]
+    ProofOfDeliverySignature,
+    /// Proof of receipt copy.
+    #[serde(rename = "PROOF_OF_RECEIPT_COPY")]
+    ProofOfReceiptCopy,
+    /// The merchant's return policy.
+    #[serde(rename = "RETURN_POLICY")]
+    ReturnPolicy,
+    /// The billing agreement between merchant and customer.
+    #[serde(rename = "BILLING_AGREEMENT")]
+    BillingAgreement,
+    /// Proof that the item was reshipped, usually through tracking information.
+    #[serde(rename = "PROOF_OF_RESHIPMENT")]
+    ProofOfReshipment,
+    /// A description of the item.
+    #[serde(rename = "ITEM_DESCRIPTION")]
+    ItemDescription,
+    /// A police report.
+    #[serde(rename = "POLICE_REPORT")]
+    PoliceReport,
+    /// An affidavit.
+    #[serde(rename = "AFFIDAVIT")]
+    Affidavit,
+    /// A screenshot.
+    #[serde(rename = "SCREEN_SHOT")]
+    ScreenShot,
+    /// Any other type of evidence.
+    #[serde(rename = "OTHER")]
+    Other,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl EvidenceType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ProofOfFulfillment => "PROOF_OF_FULFILLMENT",
+            Self::ProofOfRefund => "PROOF_OF_REFUND",
+            Self::ProofOfDeliverySignature => "PROOF_OF_DELIVERY_SIGNATURE",
+            Self::ProofOfReceiptCopy => "PROOF_OF_RECEIPT_COPY",
+            Self::ReturnPolicy => "RETURN_POLICY",
+            Self::BillingAgreement => "BILLING_AGREEMENT",
+            Self::ProofOfReshipment => "PROOF_OF_RESHIPMENT",
+            Self::ItemDescription => "ITEM_DESCRIPTION",
+            Self::PoliceReport => "POLICE_REPORT",
+            Self::Affidavit => "AFFIDAVIT",
+            Self::ScreenShot => "SCREEN_SHOT",
+            Self::Other => "OTHER",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+
+    /// Whether this type of evidence has to be submitted as an uploaded document. Tracking
+    /// information, refund IDs, item descriptions and other notes are submitted as text.
+    pub const fn is_document(self) -> bool {
+        matches!(
+            self,
+            Self::ProofOfDeliverySignature
+                | Self::ProofOfReceiptCopy
+                | Self::ReturnPolicy
+                | Self::BillingAgreement
+                | Self::PoliceReport
+                | Self::Affidavit
+                | Self::ScreenShot
+        )
+    }
+}
+
+impl AsRef<str> for EvidenceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for EvidenceType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
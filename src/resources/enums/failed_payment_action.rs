@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// The action to take on the subscription if a payment fails.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum FailedPaymentAction {
+    /// Continues the subscription if the payment fails.
+    #[serde(rename = "CONTINUE")]
+    Continue,
+    /// Cancels the subscription if the payment fails.
+    #[serde(rename = "CANCEL")]
+    Cancel,
+}
+
+impl FailedPaymentAction {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Continue => "CONTINUE",
+            Self::Cancel => "CANCEL",
+        }
+    }
+}
+
+impl AsRef<str> for FailedPaymentAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for FailedPaymentAction {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// The interval at which a subscription is charged or billed.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum IntervalUnit {
+    /// A daily billing cycle.
+    #[serde(rename = "DAY")]
+    Day,
+    /// A weekly billing cycle.
+    #[serde(rename = "WEEK")]
+    Week,
+    /// A monthly billing cycle.
+    #[serde(rename = "MONTH")]
+    Month,
+    /// A yearly billing cycle.
+    #[serde(rename = "YEAR")]
+    Year,
+}
+
+impl IntervalUnit {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Day => "DAY",
+            Self::Week => "WEEK",
+            Self::Month => "MONTH",
+            Self::Year => "YEAR",
+        }
+    }
+}
+
+impl AsRef<str> for IntervalUnit {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for IntervalUnit {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
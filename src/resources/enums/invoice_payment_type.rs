@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of an invoice payment or refund.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum InvoicePaymentType {
+    /// The payment or refund was made through PayPal.
+    #[serde(rename = "PAYPAL")]
+    PayPal,
+    /// The payment or refund was made outside of PayPal and recorded by the invoicer.
+    #[serde(rename = "EXTERNAL")]
+    External,
+}
+
+impl InvoicePaymentType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PayPal => "PAYPAL",
+            Self::External => "EXTERNAL",
+        }
+    }
+}
+
+impl AsRef<str> for InvoicePaymentType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoicePaymentType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of an invoice.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum InvoiceStatus {
+    /// The invoice is in draft state. It is not yet sent to the payer.
+    #[serde(rename = "DRAFT")]
+    Draft,
+    /// The invoice has been sent to the payer. The payment is awaited from the payer.
+    #[serde(rename = "SENT")]
+    Sent,
+    /// The invoice is scheduled on a future date. It is not yet sent to the payer.
+    #[serde(rename = "SCHEDULED")]
+    Scheduled,
+    /// The payer has paid for the invoice.
+    #[serde(rename = "PAID")]
+    Paid,
+    /// The invoice is marked as paid by the invoicer.
+    #[serde(rename = "MARKED_AS_PAID")]
+    MarkedAsPaid,
+    /// The invoice has been cancelled by the invoicer.
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    /// The invoice has been refunded by the invoicer.
+    #[serde(rename = "REFUNDED")]
+    Refunded,
+    /// The payer has partially paid for the invoice.
+    #[serde(rename = "PARTIALLY_PAID")]
+    PartiallyPaid,
+    /// The invoice has been partially refunded by the invoicer.
+    #[serde(rename = "PARTIALLY_REFUNDED")]
+    PartiallyRefunded,
+    /// The invoice is marked as refunded by the invoicer.
+    #[serde(rename = "MARKED_AS_REFUNDED")]
+    MarkedAsRefunded,
+    /// The invoicer is yet to receive the payment from the payer for the invoice.
+    #[serde(rename = "UNPAID")]
+    Unpaid,
+    /// The invoicer is yet to receive the payment for the invoice. It is under pending review.
+    #[serde(rename = "PAYMENT_PENDING")]
+    PaymentPending,
+}
+
+impl InvoiceStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "DRAFT",
+            Self::Sent => "SENT",
+            Self::Scheduled => "SCHEDULED",
+            Self::Paid => "PAID",
+            Self::MarkedAsPaid => "MARKED_AS_PAID",
+            Self::Cancelled => "CANCELLED",
+            Self::Refunded => "REFUNDED",
+            Self::PartiallyPaid => "PARTIALLY_PAID",
+            Self::PartiallyRefunded => "PARTIALLY_REFUNDED",
+            Self::MarkedAsRefunded => "MARKED_AS_REFUNDED",
+            Self::Unpaid => "UNPAID",
+            Self::PaymentPending => "PAYMENT_PENDING",
+        }
+    }
+}
+
+impl AsRef<str> for InvoiceStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for InvoiceStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
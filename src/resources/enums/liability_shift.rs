@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether the liability for a fraudulent card payment shifted to the card issuer.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum LiabilityShift {
+    /// Liability might shift to the card issuer.
+    #[serde(rename = "POSSIBLE")]
+    Possible,
+    /// Liability does not shift; the merchant is liable.
+    #[serde(rename = "NO")]
+    No,
+    /// The authentication system is not available.
+    #[serde(rename = "UNKNOWN")]
+    Unknown,
+    /// Liability has shifted to the card issuer.
+    #[serde(rename = "YES")]
+    Yes,
+}
+
+impl LiabilityShift {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Possible => "POSSIBLE",
+            Self::No => "NO",
+            Self::Unknown => "UNKNOWN",
+            Self::Yes => "YES",
+        }
+    }
+}
+
+impl AsRef<str> for LiabilityShift {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for LiabilityShift {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// The relation type of a HATEOAS link, used to look up links in a response.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum LinkRel {
+    /// The resource itself.
+    #[serde(rename = "self")]
+    SelfLink,
+    /// Redirect the buyer here to approve the payment.
+    #[serde(rename = "approve")]
+    Approve,
+    /// Redirect the buyer here to complete the required payer action.
+    #[serde(rename = "payer-action")]
+    PayerAction,
+    /// Captures the payment.
+    #[serde(rename = "capture")]
+    Capture,
+    /// Authorizes the payment.
+    #[serde(rename = "authorize")]
+    Authorize,
+    /// Reauthorizes the authorized payment.
+    #[serde(rename = "reauthorize")]
+    Reauthorize,
+    /// Voids the authorized payment.
+    #[serde(rename = "void")]
+    Void,
+    /// Refunds the captured payment.
+    #[serde(rename = "refund")]
+    Refund,
+    /// The parent resource.
+    #[serde(rename = "up")]
+    Up,
+    /// Updates the resource.
+    #[serde(rename = "update")]
+    Update,
+    /// Edits the resource.
+    #[serde(rename = "edit")]
+    Edit,
+    /// Deletes the resource.
+    #[serde(rename = "delete")]
+    Delete,
+    /// The next page of a list.
+    #[serde(rename = "next")]
+    Next,
+    /// The previous page of a list.
+    #[serde(rename = "prev")]
+    Previous,
+    /// Redirect the merchant here to sign up and grant permissions to the partner.
+    #[serde(rename = "action_url")]
+    ActionUrl,
+}
+
+impl LinkRel {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::SelfLink => "self",
+            Self::Approve => "approve",
+            Self::PayerAction => "payer-action",
+            Self::Capture => "capture",
+            Self::Authorize => "authorize",
+            Self::Reauthorize => "reauthorize",
+            Self::Void => "void",
+            Self::Refund => "refund",
+            Self::Up => "up",
+            Self::Update => "update",
+            Self::Edit => "edit",
+            Self::Delete => "delete",
+            Self::Next => "next",
+            Self::Previous => "prev",
+            Self::ActionUrl => "action_url",
+        }
+    }
+}
+
+impl AsRef<str> for LinkRel {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for LinkRel {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -1,28 +1,58 @@
+pub mod accept_claim_reason;
+pub mod accept_claim_type;
 pub mod anchor_type;
+pub mod authentication_status;
 pub mod authorization_status_reason;
 pub mod avs_code;
 pub mod capture_status;
 pub mod capture_status_reason;
+pub mod capture_type;
 pub mod card_type;
+pub mod card_verification_method;
 pub mod category;
 pub mod country_codes;
 pub mod currency_code;
 pub mod cvv_code;
 pub mod disembursement_mode;
 pub mod dispute_category;
+pub mod dispute_life_cycle_stage;
+pub mod dispute_outcome_code;
+pub mod dispute_reason;
+pub mod dispute_state;
+pub mod dispute_status;
+pub mod enrollment_status;
+pub mod evidence_type;
+pub mod failed_payment_action;
 pub mod http_method;
+pub mod interval_unit;
+pub mod invoice_payment_type;
+pub mod invoice_status;
 pub mod landing_page;
+pub mod liability_shift;
+pub mod link_rel;
 pub mod network;
+pub mod offer_type;
 pub mod op;
 pub mod order_intent;
 pub mod order_status;
 pub mod payee_preferred;
 pub mod payment_card_type;
 pub mod payment_initiator;
+pub mod payment_method;
 pub mod payment_status;
+pub mod payment_term_type;
 pub mod payment_type;
+pub mod payout_batch_status;
+pub mod payout_error_name;
+pub mod payout_recipient_type;
+pub mod payout_transaction_status;
 pub mod phone_type;
+pub mod plan_status;
 pub mod processing_instruction;
+pub mod product_category;
+pub mod product_type;
+pub mod qr_code_action;
+pub mod referenced_payout_item_status;
 pub mod refund_status;
 pub mod refund_status_reason;
 pub mod response_code;
@@ -30,8 +60,16 @@ pub mod seller_protection_status;
 pub mod shipping_preference;
 pub mod shipping_type;
 pub mod standard_entry_class_code;
+pub mod subscription_status;
+pub mod subscription_transaction_status;
+pub mod subscription_user_action;
 pub mod tax_id_type;
+pub mod tenure_type;
+pub mod tier_mode;
 pub mod token_type;
+pub mod tracker_carrier;
+pub mod tracker_status;
+pub mod unit_of_measure;
 pub mod usage;
 pub mod user_action;
 pub mod verification_status;
@@ -39,30 +77,60 @@ pub mod verification_status;
 #[rustfmt::skip]
 pub use {
     self::{
+        accept_claim_reason::*,
+        accept_claim_type::*,
+        authentication_status::*,
         authorization_status_reason::*,
         avs_code::*,
         capture_status::*,
         capture_status_reason::*,
+        capture_type::*,
         card_type::*,
+        card_verification_method::*,
         category::*,    
         country_codes::*,
         currency_code::*,
         cvv_code::*,
         disembursement_mode::*,
         dispute_category::*,
+        dispute_life_cycle_stage::*,
+        dispute_outcome_code::*,
+        dispute_reason::*,
+        dispute_state::*,
+        dispute_status::*,
+        enrollment_status::*,
+        evidence_type::*,
+        failed_payment_action::*,
         http_method::*,
+        interval_unit::*,
+        invoice_payment_type::*,
+        invoice_status::*,
         landing_page::*,
+        liability_shift::*,
+        link_rel::*,
         network::*,
+        offer_type::*,
         op::*,
         order_intent::*,
         order_status::*,
         payee_preferred::*,
         payment_card_type::*,
         payment_initiator::*,
+        payment_method::*,
         payment_status::*,
+        payment_term_type::*,
         payment_type::*,
+        payout_batch_status::*,
+        payout_error_name::*,
+        payout_recipient_type::*,
+        payout_transaction_status::*,
         phone_type::*,
+        plan_status::*,
         processing_instruction::*,
+        product_category::*,
+        product_type::*,
+        qr_code_action::*,
+        referenced_payout_item_status::*,
         refund_status::*,
         refund_status_reason::*,
         response_code::*,
@@ -70,8 +138,16 @@ pub use {
         shipping_preference::*,
         shipping_type::*,
         standard_entry_class_code::*,
+        subscription_status::*,
+        subscription_transaction_status::*,
+        subscription_user_action::*,
         tax_id_type::*,
+        tenure_type::*,
+        tier_mode::*,
         token_type::*,
+        tracker_carrier::*,
+        tracker_status::*,
+        unit_of_measure::*,
         usage::*,
         user_action::*,
         verification_status::*,
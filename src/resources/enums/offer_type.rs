@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of offer that the merchant makes to resolve a dispute.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum OfferType {
+    /// The merchant must refund the customer without any item replacement or return. This offer type is valid in the inquiry phase.
+    #[serde(rename = "REFUND")]
+    Refund,
+    /// The customer must return the item to the merchant and then the merchant will refund the money.
+    #[serde(rename = "REFUND_WITH_RETURN")]
+    RefundWithReturn,
+    /// The merchant must do a refund and then send a replacement item to the customer.
+    #[serde(rename = "REFUND_WITH_REPLACEMENT")]
+    RefundWithReplacement,
+    /// The merchant must send a replacement item to the customer with no additional refund.
+    #[serde(rename = "REPLACEMENT_WITHOUT_REFUND")]
+    ReplacementWithoutRefund,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl OfferType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Refund => "REFUND",
+            Self::RefundWithReturn => "REFUND_WITH_RETURN",
+            Self::RefundWithReplacement => "REFUND_WITH_REPLACEMENT",
+            Self::ReplacementWithoutRefund => "REPLACEMENT_WITHOUT_REFUND",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for OfferType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for OfferType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// The method of an invoice payment or refund that was recorded outside of PayPal.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PaymentMethod {
+    /// Payments can be received through bank transfers.
+    #[serde(rename = "BANK_TRANSFER")]
+    BankTransfer,
+    /// Payments can be received as cash.
+    #[serde(rename = "CASH")]
+    Cash,
+    /// Payments can be received as check.
+    #[serde(rename = "CHECK")]
+    Check,
+    /// Payments can be received through credit card payments.
+    #[serde(rename = "CREDIT_CARD")]
+    CreditCard,
+    /// Payments can be received through debit card payments.
+    #[serde(rename = "DEBIT_CARD")]
+    DebitCard,
+    /// Payments can be received through PayPal payments.
+    #[serde(rename = "PAYPAL")]
+    PayPal,
+    /// Payments can be received through wire transfer.
+    #[serde(rename = "WIRE_TRANSFER")]
+    WireTransfer,
+    /// Payments can be received through other modes.
+    #[serde(rename = "OTHER")]
+    Other,
+}
+
+impl PaymentMethod {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::BankTransfer => "BANK_TRANSFER",
+            Self::Cash => "CASH",
+            Self::Check => "CHECK",
+            Self::CreditCard => "CREDIT_CARD",
+            Self::DebitCard => "DEBIT_CARD",
+            Self::PayPal => "PAYPAL",
+            Self::WireTransfer => "WIRE_TRANSFER",
+            Self::Other => "OTHER",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentMethod {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentMethod {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
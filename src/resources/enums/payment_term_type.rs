@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// The payment due date for the invoice.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PaymentTermType {
+    /// The payment for the invoice is due upon receipt of the invoice.
+    #[serde(rename = "DUE_ON_RECEIPT")]
+    DueOnReceipt,
+    /// The payment for the invoice is due on the date specified in the invoice.
+    #[serde(rename = "DUE_ON_DATE_SPECIFIED")]
+    DueOnDateSpecified,
+    /// The payment for the invoice is due in 10 days.
+    #[serde(rename = "NET_10")]
+    Net10,
+    /// The payment for the invoice is due in 15 days.
+    #[serde(rename = "NET_15")]
+    Net15,
+    /// The payment for the invoice is due in 30 days.
+    #[serde(rename = "NET_30")]
+    Net30,
+    /// The payment for the invoice is due in 45 days.
+    #[serde(rename = "NET_45")]
+    Net45,
+    /// The payment for the invoice is due in 60 days.
+    #[serde(rename = "NET_60")]
+    Net60,
+    /// The payment for the invoice is due in 90 days.
+    #[serde(rename = "NET_90")]
+    Net90,
+    /// The invoice has no payment due date.
+    #[serde(rename = "NO_DUE_DATE")]
+    NoDueDate,
+}
+
+impl PaymentTermType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::DueOnReceipt => "DUE_ON_RECEIPT",
+            Self::DueOnDateSpecified => "DUE_ON_DATE_SPECIFIED",
+            Self::Net10 => "NET_10",
+            Self::Net15 => "NET_15",
+            Self::Net30 => "NET_30",
+            Self::Net45 => "NET_45",
+            Self::Net60 => "NET_60",
+            Self::Net90 => "NET_90",
+            Self::NoDueDate => "NO_DUE_DATE",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentTermType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentTermType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
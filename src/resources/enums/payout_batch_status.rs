@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a batch payout.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayoutBatchStatus {
+    /// The batch payout was denied. No payouts were made.
+    #[serde(rename = "DENIED")]
+    Denied,
+    /// The batch payout is waiting to be processed.
+    #[serde(rename = "PENDING")]
+    Pending,
+    /// The batch payout is being processed.
+    #[serde(rename = "PROCESSING")]
+    Processing,
+    /// The batch payout has been processed. The items can still have individual failures.
+    #[serde(rename = "SUCCESS")]
+    Success,
+    /// The batch payout was canceled.
+    #[serde(rename = "CANCELED")]
+    Canceled,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl PayoutBatchStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Denied => "DENIED",
+            Self::Pending => "PENDING",
+            Self::Processing => "PROCESSING",
+            Self::Success => "SUCCESS",
+            Self::Canceled => "CANCELED",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for PayoutBatchStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PayoutBatchStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// The name of the error that made a payout item fail.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayoutErrorName {
+    /// The sender has already submitted an item with the same sender item ID.
+    #[serde(rename = "DUPLICATE_ITEM")]
+    DuplicateItem,
+    /// The sender does not have enough funds to make the payout.
+    #[serde(rename = "INSUFFICIENT_FUNDS")]
+    InsufficientFunds,
+    /// PayPal failed to process the item. Retry the payout.
+    #[serde(rename = "INTERNAL_ERROR")]
+    InternalError,
+    /// The receiver cannot hold the currency of the payout.
+    #[serde(rename = "NON_HOLDING_CURRENCY")]
+    NonHoldingCurrency,
+    /// The receiver has to decide whether to accept payments in the currency of the payout.
+    #[serde(rename = "PENDING_RECIPIENT_NON_HOLDING_CURRENCY_PAYMENT_PREFERENCE")]
+    PendingRecipientNonHoldingCurrencyPaymentPreference,
+    /// The receiver's account is locked or inactive.
+    #[serde(rename = "RECEIVER_ACCOUNT_LOCKED")]
+    ReceiverAccountLocked,
+    /// The receiver's country is not allowed to receive payouts.
+    #[serde(rename = "RECEIVER_COUNTRY_NOT_ALLOWED")]
+    ReceiverCountryNotAllowed,
+    /// The receiver's state or province is restricted.
+    #[serde(rename = "RECEIVER_STATE_RESTRICTED")]
+    ReceiverStateRestricted,
+    /// The receiver has not confirmed their email address or phone number.
+    #[serde(rename = "RECEIVER_UNCONFIRMED")]
+    ReceiverUnconfirmed,
+    /// The receiver does not have a PayPal account.
+    #[serde(rename = "RECEIVER_UNREGISTERED")]
+    ReceiverUnregistered,
+    /// The receiver has a youth account, which cannot receive payouts.
+    #[serde(rename = "RECEIVER_YOUTH_ACCOUNT")]
+    ReceiverYouthAccount,
+    /// The payout exceeds the receiving limit of the receiver.
+    #[serde(rename = "RECEIVING_LIMIT_EXCEEDED")]
+    ReceivingLimitExceeded,
+    /// The payout was blocked for regulatory reasons.
+    #[serde(rename = "REGULATORY_BLOCKED")]
+    RegulatoryBlocked,
+    /// The payout is held for a regulatory review.
+    #[serde(rename = "REGULATORY_PENDING")]
+    RegulatoryPending,
+    /// The payout was declined by PayPal's risk checks.
+    #[serde(rename = "RISK_DECLINE")]
+    RiskDecline,
+    /// The payout exceeds the transaction limit of the sender.
+    #[serde(rename = "TRANSACTION_LIMIT_EXCEEDED")]
+    TransactionLimitExceeded,
+    /// The amount of the payout item is zero.
+    #[serde(rename = "ZERO_AMOUNT")]
+    ZeroAmount,
+    /// An error that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl PayoutErrorName {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::DuplicateItem => "DUPLICATE_ITEM",
+            Self::InsufficientFunds => "INSUFFICIENT_FUNDS",
+            Self::InternalError => "INTERNAL_ERROR",
+            Self::NonHoldingCurrency => "NON_HOLDING_CURRENCY",
+            Self::PendingRecipientNonHoldingCurrencyPaymentPreference => {
+                "PENDING_RECIPIENT_NON_HOLDING_CURRENCY_PAYMENT_PREFERENCE"
+            }
+            Self::ReceiverAccountLocked => "RECEIVER_ACCOUNT_LOCKED",
+            Self::ReceiverCountryNotAllowed => "RECEIVER_COUNTRY_NOT_ALLOWED",
+            Self::ReceiverStateRestricted => "RECEIVER_STATE_RESTRICTED",
+            Self::ReceiverUnconfirmed => "RECEIVER_UNCONFIRMED",
+            Self::ReceiverUnregistered => "RECEIVER_UNREGISTERED",
+            Self::ReceiverYouthAccount => "RECEIVER_YOUTH_ACCOUNT",
+            Self::ReceivingLimitExceeded => "RECEIVING_LIMIT_EXCEEDED",
+            Self::RegulatoryBlocked => "REGULATORY_BLOCKED",
+            Self::RegulatoryPending => "REGULATORY_PENDING",
+            Self::RiskDecline => "RISK_DECLINE",
+            Self::TransactionLimitExceeded => "TRANSACTION_LIMIT_EXCEEDED",
+            Self::ZeroAmount => "ZERO_AMOUNT",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for PayoutErrorName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PayoutErrorName {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
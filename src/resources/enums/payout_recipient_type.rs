@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of ID that identifies the payment receiver.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayoutRecipientType {
+    /// The receiver is identified by their email address.
+    #[serde(rename = "EMAIL")]
+    Email,
+    /// The receiver is identified by their unencrypted phone number.
+    #[serde(rename = "PHONE")]
+    Phone,
+    /// The receiver is identified by their encrypted PayPal account number.
+    #[serde(rename = "PAYPAL_ID")]
+    PayPalId,
+}
+
+impl PayoutRecipientType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "EMAIL",
+            Self::Phone => "PHONE",
+            Self::PayPalId => "PAYPAL_ID",
+        }
+    }
+}
+
+impl AsRef<str> for PayoutRecipientType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PayoutRecipientType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
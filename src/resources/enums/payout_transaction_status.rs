@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+/// The transaction status of a payout item.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PayoutTransactionStatus {
+    /// The funds have been credited to the recipient's account.
+    #[serde(rename = "SUCCESS")]
+    Success,
+    /// The payout request has failed, so the funds were not credited to the recipient's account.
+    #[serde(rename = "FAILED")]
+    Failed,
+    /// The payout request is awaiting processing.
+    #[serde(rename = "PENDING")]
+    Pending,
+    /// The recipient for this payout does not have a PayPal account. The funds are returned to your account after 30 days if the recipient does not claim them.
+    #[serde(rename = "UNCLAIMED")]
+    Unclaimed,
+    /// The recipient has not claimed the payout, so the funds have been returned to your account.
+    #[serde(rename = "RETURNED")]
+    Returned,
+    /// The payout request is being reviewed and is on hold.
+    #[serde(rename = "ONHOLD")]
+    OnHold,
+    /// The payout request has been blocked.
+    #[serde(rename = "BLOCKED")]
+    Blocked,
+    /// The payout request has been refunded.
+    #[serde(rename = "REFUNDED")]
+    Refunded,
+    /// The payout request has been reversed.
+    #[serde(rename = "REVERSED")]
+    Reversed,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl PayoutTransactionStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "SUCCESS",
+            Self::Failed => "FAILED",
+            Self::Pending => "PENDING",
+            Self::Unclaimed => "UNCLAIMED",
+            Self::Returned => "RETURNED",
+            Self::OnHold => "ONHOLD",
+            Self::Blocked => "BLOCKED",
+            Self::Refunded => "REFUNDED",
+            Self::Reversed => "REVERSED",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for PayoutTransactionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PayoutTransactionStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a billing plan.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PlanStatus {
+    /// The plan was created. You cannot create subscriptions for a plan in this state.
+    #[serde(rename = "CREATED")]
+    Created,
+    /// The plan is inactive.
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /// The plan is active. You can only create subscriptions for a plan in this state.
+    #[serde(rename = "ACTIVE")]
+    Active,
+}
+
+impl PlanStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "CREATED",
+            Self::Inactive => "INACTIVE",
+            Self::Active => "ACTIVE",
+        }
+    }
+}
+
+impl AsRef<str> for PlanStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PlanStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+/// The category of a catalog product. Covers the top-level categories of the PayPal product catalog.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ProductCategory {
+    #[serde(rename = "AUTOMOTIVE")]
+    Automotive,
+    #[serde(rename = "BABY")]
+    Baby,
+    #[serde(rename = "BEAUTY_AND_FRAGRANCES")]
+    BeautyAndFragrances,
+    #[serde(rename = "BOOKS_AND_MAGAZINES")]
+    BooksAndMagazines,
+    #[serde(rename = "BUSINESS_TO_BUSINESS")]
+    BusinessToBusiness,
+    #[serde(rename = "CLOTHING_ACCESSORIES_AND_SHOES")]
+    ClothingAccessoriesAndShoes,
+    #[serde(rename = "COMPUTER_ACCESSORIES_AND_SERVICES")]
+    ComputerAccessoriesAndServices,
+    #[serde(rename = "EDUCATION")]
+    Education,
+    #[serde(rename = "ELECTRONICS_AND_COMPUTERS")]
+    ElectronicsAndComputers,
+    #[serde(rename = "ENTERTAINMENT_AND_MEDIA")]
+    EntertainmentAndMedia,
+    #[serde(rename = "FINANCIAL_SERVICES_AND_PRODUCTS")]
+    FinancialServicesAndProducts,
+    #[serde(rename = "FOOD_RETAIL_AND_SERVICE")]
+    FoodRetailAndService,
+    #[serde(rename = "GIFTS_AND_FLOWERS")]
+    GiftsAndFlowers,
+    #[serde(rename = "GOVERNMENT")]
+    Government,
+    #[serde(rename = "HEALTH_AND_PERSONAL_CARE")]
+    HealthAndPersonalCare,
+    #[serde(rename = "HOME_AND_GARDEN")]
+    HomeAndGarden,
+    #[serde(rename = "NONPROFIT")]
+    Nonprofit,
+    #[serde(rename = "PETS_AND_ANIMALS")]
+    PetsAndAnimals,
+    #[serde(rename = "RELIGION_AND_SPIRITUALITY")]
+    ReligionAndSpirituality,
+    #[serde(rename = "RETAIL")]
+    Retail,
+    #[serde(rename = "SERVICES")]
+    Services,
+    #[serde(rename = "SOFTWARE")]
+    Software,
+    #[serde(rename = "SPORTS_AND_OUTDOORS")]
+    SportsAndOutdoors,
+    #[serde(rename = "TOYS_AND_HOBBIES")]
+    ToysAndHobbies,
+    #[serde(rename = "TRAVEL")]
+    Travel,
+    #[serde(rename = "VEHICLE_SALES")]
+    VehicleSales,
+    #[serde(rename = "VEHICLE_SERVICE_AND_ACCESSORIES")]
+    VehicleServiceAndAccessories,
+    #[serde(rename = "OTHER")]
+    Other,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl ProductCategory {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Automotive => "AUTOMOTIVE",
+            Self::Baby => "BABY",
+            Self::BeautyAndFragrances => "BEAUTY_AND_FRAGRANCES",
+            Self::BooksAndMagazines => "BOOKS_AND_MAGAZINES",
+            Self::BusinessToBusiness => "BUSINESS_TO_BUSINESS",
+            Self::ClothingAccessoriesAndShoes => "CLOTHING_ACCESSORIES_AND_SHOES",
+            Self::ComputerAccessoriesAndServices => "COMPUTER_ACCESSORIES_AND_SERVICES",
+            Self::Education => "EDUCATION",
+            Self::ElectronicsAndComputers => "ELECTRONICS_AND_COMPUTERS",
+            Self::EntertainmentAndMedia => "ENTERTAINMENT_AND_MEDIA",
+            Self::FinancialServicesAndProducts => "FINANCIAL_SERVICES_AND_PRODUCTS",
+            Self::FoodRetailAndService => "FOOD_RETAIL_AND_SERVICE",
+            Self::GiftsAndFlowers => "GIFTS_AND_FLOWERS",
+            Self::Government => "GOVERNMENT",
+            Self::HealthAndPersonalCare => "HEALTH_AND_PERSONAL_CARE",
+            Self::HomeAndGarden => "HOME_AND_GARDEN",
+            Self::Nonprofit => "NONPROFIT",
+            Self::PetsAndAnimals => "PETS_AND_ANIMALS",
+            Self::ReligionAndSpirituality => "RELIGION_AND_SPIRITUALITY",
+            Self::Retail => "RETAIL",
+            Self::Services => "SERVICES",
+            Self::Software => "SOFTWARE",
+            Self::SportsAndOutdoors => "SPORTS_AND_OUTDOORS",
+            Self::ToysAndHobbies => "TOYS_AND_HOBBIES",
+            Self::Travel => "TRAVEL",
+            Self::VehicleSales => "VEHICLE_SALES",
+            Self::VehicleServiceAndAccessories => "VEHICLE_SERVICE_AND_ACCESSORIES",
+            Self::Other => "OTHER",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for ProductCategory {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ProductCategory {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
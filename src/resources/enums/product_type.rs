@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of a catalog product.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ProductType {
+    /// Physical goods.
+    #[serde(rename = "PHYSICAL")]
+    Physical,
+    /// Digital goods.
+    #[serde(rename = "DIGITAL")]
+    Digital,
+    /// A service. For example, technical support.
+    #[serde(rename = "SERVICE")]
+    Service,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl ProductType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Physical => "PHYSICAL",
+            Self::Digital => "DIGITAL",
+            Self::Service => "SERVICE",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for ProductType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for ProductType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of URL for which to generate an invoice QR code.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum QrCodeAction {
+    /// The QR code links to the page where the payer pays the invoice.
+    #[serde(rename = "pay")]
+    Pay,
+    /// The QR code links to the invoice details page.
+    #[serde(rename = "details")]
+    Details,
+}
+
+impl QrCodeAction {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pay => "pay",
+            Self::Details => "details",
+        }
+    }
+}
+
+impl AsRef<str> for QrCodeAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for QrCodeAction {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 pub enum RefundStatus {
     #[serde(rename = "CANCELLED")]
     Cancelled,
+    #[serde(rename = "FAILED")]
+    Failed,
     #[serde(rename = "PENDING")]
     Pending,
     #[serde(rename = "COMPLETED")]
@@ -14,6 +16,7 @@ impl RefundStatus {
     pub const fn as_str(self) -> &'static str {
         match self {
             Self::Cancelled => "CANCELLED",
+            Self::Failed => "FAILED",
             Self::Pending => "PENDING",
             Self::Completed => "COMPLETED",
         }
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a subscription.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SubscriptionStatus {
+    /// The subscription is created but not yet approved by the buyer.
+    #[serde(rename = "APPROVAL_PENDING")]
+    ApprovalPending,
+    /// The buyer has approved the subscription.
+    #[serde(rename = "APPROVED")]
+    Approved,
+    /// The subscription is active.
+    #[serde(rename = "ACTIVE")]
+    Active,
+    /// The subscription is suspended.
+    #[serde(rename = "SUSPENDED")]
+    Suspended,
+    /// The subscription is cancelled.
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    /// The subscription is expired.
+    #[serde(rename = "EXPIRED")]
+    Expired,
+}
+
+impl SubscriptionStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ApprovalPending => "APPROVAL_PENDING",
+            Self::Approved => "APPROVED",
+            Self::Active => "ACTIVE",
+            Self::Suspended => "SUSPENDED",
+            Self::Cancelled => "CANCELLED",
+            Self::Expired => "EXPIRED",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a subscription transaction.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SubscriptionTransactionStatus {
+    /// The funds for this captured payment were credited to the payee's PayPal account.
+    #[serde(rename = "COMPLETED")]
+    Completed,
+    /// The funds could not be captured.
+    #[serde(rename = "DECLINED")]
+    Declined,
+    /// An amount less than this captured payment's amount was partially refunded to the payer.
+    #[serde(rename = "PARTIALLY_REFUNDED")]
+    PartiallyRefunded,
+    /// The funds for this captured payment was not yet credited to the payee's PayPal account.
+    #[serde(rename = "PENDING")]
+    Pending,
+    /// An amount greater than or equal to this captured payment's amount was refunded to the payer.
+    #[serde(rename = "REFUNDED")]
+    Refunded,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl SubscriptionTransactionStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Completed => "COMPLETED",
+            Self::Declined => "DECLINED",
+            Self::PartiallyRefunded => "PARTIALLY_REFUNDED",
+            Self::Pending => "PENDING",
+            Self::Refunded => "REFUNDED",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionTransactionStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionTransactionStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
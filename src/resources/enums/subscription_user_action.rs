@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Configures the label name of the button on the PayPal approval page.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SubscriptionUserAction {
+    /// After you redirect the customer to the PayPal subscription consent page, a Continue button appears. Activate the subscription on your site after the customer returns.
+    #[serde(rename = "CONTINUE")]
+    Continue,
+    /// After you redirect the customer to the PayPal subscription consent page, a Subscribe Now button appears. The subscription is activated immediately when the customer clicks it.
+    #[serde(rename = "SUBSCRIBE_NOW")]
+    SubscribeNow,
+}
+
+impl SubscriptionUserAction {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Continue => "CONTINUE",
+            Self::SubscribeNow => "SUBSCRIBE_NOW",
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionUserAction {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for SubscriptionUserAction {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
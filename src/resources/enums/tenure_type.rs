@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// The tenure type of a billing cycle.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TenureType {
+    /// A regular billing cycle.
+    #[serde(rename = "REGULAR")]
+    Regular,
+    /// A trial billing cycle.
+    #[serde(rename = "TRIAL")]
+    Trial,
+}
+
+impl TenureType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Regular => "REGULAR",
+            Self::Trial => "TRIAL",
+        }
+    }
+}
+
+impl AsRef<str> for TenureType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TenureType {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// The pricing model for tiered plans.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TierMode {
+    /// The price of all units is determined by the tier the total quantity falls into.
+    #[serde(rename = "VOLUME")]
+    Volume,
+    /// Each unit is priced at the tier it falls into. PayPal calls this model `TIERED`.
+    #[serde(rename = "TIERED")]
+    Graduated,
+}
+
+impl TierMode {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Volume => "VOLUME",
+            Self::Graduated => "TIERED",
+        }
+    }
+}
+
+impl AsRef<str> for TierMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TierMode {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// The carrier that ships the items of an order.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TrackerCarrier {
+    /// United Parcel Service.
+    #[serde(rename = "UPS")]
+    Ups,
+    /// United States Postal Service.
+    #[serde(rename = "USPS")]
+    Usps,
+    /// FedEx.
+    #[serde(rename = "FEDEX")]
+    FedEx,
+    /// DHL Express.
+    #[serde(rename = "DHL")]
+    Dhl,
+    /// DPD.
+    #[serde(rename = "DPD")]
+    Dpd,
+    /// GLS.
+    #[serde(rename = "GLS")]
+    Gls,
+    /// TNT.
+    #[serde(rename = "TNT")]
+    Tnt,
+    /// Hermes.
+    #[serde(rename = "HERMES")]
+    Hermes,
+    /// Royal Mail.
+    #[serde(rename = "ROYAL_MAIL")]
+    RoyalMail,
+    /// Canada Post.
+    #[serde(rename = "CANADA_POST")]
+    CanadaPost,
+    /// Any other carrier. Set `carrier_name_other` to the name of the carrier.
+    #[serde(rename = "OTHER")]
+    Other,
+    /// A value that this version of the library does not know yet.
+    #[serde(other, rename = "UNKNOWN")]
+    Unknown,
+}
+
+impl TrackerCarrier {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ups => "UPS",
+            Self::Usps => "USPS",
+            Self::FedEx => "FEDEX",
+            Self::Dhl => "DHL",
+            Self::Dpd => "DPD",
+            Self::Gls => "GLS",
+            Self::Tnt => "TNT",
+            Self::Hermes => "HERMES",
+            Self::RoyalMail => "ROYAL_MAIL",
+            Self::CanadaPost => "CANADA_POST",
+            Self::Other => "OTHER",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for TrackerCarrier {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TrackerCarrier {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
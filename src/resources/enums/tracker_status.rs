@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// The status of a shipment tracker.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum TrackerStatus {
+    /// The items were shipped.
+    #[serde(rename = "SHIPPED")]
+    Shipped,
+    /// The shipment is on hold.
+    #[serde(rename = "ON_HOLD")]
+    OnHold,
+    /// The items were delivered.
+    #[serde(rename = "DELIVERED")]
+    Delivered,
+    /// The shipment was cancelled.
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+}
+
+impl TrackerStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Shipped => "SHIPPED",
+            Self::OnHold => "ON_HOLD",
+            Self::Delivered => "DELIVERED",
+            Self::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+impl AsRef<str> for TrackerStatus {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TrackerStatus {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
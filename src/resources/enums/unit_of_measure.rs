@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// The unit of measure for the invoiced items.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum UnitOfMeasure {
+    /// The unit of measure is quantity. This invoice template is typically used for physical goods.
+    #[serde(rename = "QUANTITY")]
+    Quantity,
+    /// The unit of measure is hours. This invoice template is typically used for services.
+    #[serde(rename = "HOURS")]
+    Hours,
+    /// The unit of measure is amount. This invoice template is typically used when only amount is
+    /// required.
+    #[serde(rename = "AMOUNT")]
+    Amount,
+}
+
+impl UnitOfMeasure {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Quantity => "QUANTITY",
+            Self::Hours => "HOURS",
+            Self::Amount => "AMOUNT",
+        }
+    }
+}
+
+impl AsRef<str> for UnitOfMeasure {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for UnitOfMeasure {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::evidence_type::EvidenceType;
+
+/// Evidence that is submitted for a dispute.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Evidence {
+    /// The evidence type.
+    pub evidence_type: EvidenceType,
+
+    /// The evidence-related information.
+    pub evidence_info: Option<EvidenceInfo>,
+
+    /// An array of evidence documents.
+    pub documents: Option<Vec<EvidenceDocument>>,
+
+    /// Any evidence-related notes.
+    pub notes: Option<String>,
+
+    /// The date and time when the evidence was received, in Internet date and time format.
+    pub date: Option<String>,
+
+    /// The item ID. If the merchant provides multiple pieces of evidence and the transaction has
+    /// multiple item IDs, the merchant can use this value to associate a piece of evidence with an
+    /// item ID.
+    pub item_id: Option<String>,
+}
+
+impl Evidence {
+    #[must_use]
+    pub const fn new(evidence_type: EvidenceType) -> Self {
+        Self {
+            evidence_type,
+            evidence_info: None,
+            documents: None,
+            notes: None,
+            date: None,
+            item_id: None,
+        }
+    }
+
+    /// Adds the tracking information of a shipment for the disputed transaction.
+    #[must_use]
+    pub fn tracking_info(mut self, carrier_name: String, tracking_number: String) -> Self {
+        let tracking_info = EvidenceTrackingInfo {
+            carrier_name: Some(carrier_name),
+            tracking_number,
+            ..Default::default()
+        };
+        self.evidence_info
+            .get_or_insert_with(Default::default)
+            .tracking_info
+            .get_or_insert_with(Vec::new)
+            .push(tracking_info);
+        self
+    }
+
+    /// Adds the ID of a refund for the disputed transaction.
+    #[must_use]
+    pub fn refund_id(mut self, refund_id: String) -> Self {
+        self.evidence_info
+            .get_or_insert_with(Default::default)
+            .refund_ids
+            .get_or_insert_with(Vec::new)
+            .push(EvidenceRefundId { refund_id });
+        self
+    }
+
+    #[must_use]
+    pub fn notes(mut self, notes: String) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EvidenceInfo {
+    /// An array of relevant tracking information for the transaction involved in this dispute.
+    pub tracking_info: Option<Vec<EvidenceTrackingInfo>>,
+
+    /// An array of refund IDs for the transaction involved in this dispute.
+    pub refund_ids: Option<Vec<EvidenceRefundId>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EvidenceTrackingInfo {
+    /// The name of the shipment carrier for the transaction for this dispute.
+    pub carrier_name: Option<String>,
+
+    /// The name of the carrier if the `carrier_name` is `OTHER`.
+    pub carrier_name_other: Option<String>,
+
+    /// The URL to track the dispute-related transaction shipment.
+    pub tracking_url: Option<String>,
+
+    /// The number to track the dispute-related transaction shipment.
+    pub tracking_number: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EvidenceRefundId {
+    /// The ID of the refunded payment.
+    pub refund_id: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EvidenceDocument {
+    /// The document name.
+    pub name: Option<String>,
+
+    /// The downloadable URL for the document for which the client has access.
+    pub url: Option<String>,
+}
@@ -10,7 +10,7 @@ pub struct ExchangeRate {
     /// The target currency to which to convert an amount.
     pub target_currency: Option<String>,
 
-    // The target currency amount. Equivalent to one unit of the source currency. Formatted as integer or decimal value with one to
-    // 15 digits to the right of the decimal point.
+    /// The target currency amount. Equivalent to one unit of the source currency. Formatted as integer or decimal value with one to
+    /// 15 digits to the right of the decimal point.
     pub value: Option<String>,
 }
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::landing_page::LandingPage;
+use crate::resources::enums::payee_preferred::PayeePreferred;
+use crate::resources::enums::shipping_preference::ShippingPreference;
+use crate::resources::enums::user_action::UserAction;
+
+/// Customizes the payer experience during the approval process with PayPal. Replaces the
+/// deprecated `application_context` of orders.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+
+    /// The BCP 47-formatted locale of pages that the PayPal payment experience shows.
+    pub locale: Option<String>,
+
+    /// The type of landing page to show on the PayPal site for customer checkout.
+    pub landing_page: Option<LandingPage>,
+
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+
+    /// Configures a Continue or Pay Now checkout flow.
+    pub user_action: Option<UserAction>,
+
+    /// The merchant-preferred payment methods.
+    pub payment_method_preference: Option<PayeePreferred>,
+
+    /// The URL where the customer is redirected after the customer approves the payment.
+    pub return_url: Option<String>,
+
+    /// The URL where the customer is redirected after the customer cancels the payment.
+    pub cancel_url: Option<String>,
+}
+
+impl ExperienceContext {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn brand_name(mut self, brand_name: String) -> Self {
+        self.brand_name = Some(brand_name);
+        self
+    }
+
+    #[must_use]
+    pub fn locale(mut self, locale: String) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    #[must_use]
+    pub const fn landing_page(mut self, landing_page: LandingPage) -> Self {
+        self.landing_page = Some(landing_page);
+        self
+    }
+
+    #[must_use]
+    pub const fn shipping_preference(mut self, shipping_preference: ShippingPreference) -> Self {
+        self.shipping_preference = Some(shipping_preference);
+        self
+    }
+
+    #[must_use]
+    pub const fn user_action(mut self, user_action: UserAction) -> Self {
+        self.user_action = Some(user_action);
+        self
+    }
+
+    #[must_use]
+    pub const fn payment_method_preference(
+        mut self,
+        payment_method_preference: PayeePreferred,
+    ) -> Self {
+        self.payment_method_preference = Some(payment_method_preference);
+        self
+    }
+
+    #[must_use]
+    pub fn return_url(mut self, return_url: String) -> Self {
+        self.return_url = Some(return_url);
+        self
+    }
+
+    #[must_use]
+    pub fn cancel_url(mut self, cancel_url: String) -> Self {
+        self.cancel_url = Some(cancel_url);
+        self
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::unit_of_measure::UnitOfMeasure;
+use crate::resources::item::Item;
+use crate::resources::money::Money;
+
+/// An item that appears on an invoice or invoice template.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceItem {
+    /// The ID of the invoice line item.
+    pub id: Option<String>,
+
+    /// The item name for the invoice line item.
+    pub name: String,
+
+    /// The item description for the invoice line item.
+    pub description: Option<String>,
+
+    /// The quantity of the item that the invoicer provides to the payer. Value is from -1000000 to
+    /// 1000000. Supports up to five decimal places.
+    pub quantity: String,
+
+    /// The unit price of the item. This does not include tax and discount. Value is from
+    /// -1000000 to 1000000. Supports up to two decimal places.
+    pub unit_amount: Money,
+
+    /// The tax associated with the item. The tax amount is added to the item total.
+    pub tax: Option<InvoiceTax>,
+
+    /// The date when the item or service was provided, in Internet date format.
+    pub item_date: Option<String>,
+
+    /// Discount as a percent or amount at invoice level. The invoice discount amount is subtracted
+    /// from the item total.
+    pub discount: Option<InvoiceDiscount>,
+
+    /// The unit of measure for the invoiced item.
+    pub unit_of_measure: Option<UnitOfMeasure>,
+}
+
+impl InvoiceItem {
+    #[must_use]
+    pub fn new(name: String, quantity: String, unit_amount: Money) -> Self {
+        Self {
+            name,
+            quantity,
+            unit_amount,
+            ..Default::default()
+        }
+    }
+}
+
+/// Converts an order line item into an invoice line item. The per-unit tax of an order item is an
+/// amount while invoices expect a tax rate, so the tax is not carried over.
+impl From<Item> for InvoiceItem {
+    fn from(item: Item) -> Self {
+        Self {
+            name: item.name,
+            description: item.description,
+            quantity: item.quantity,
+            unit_amount: item.unit_amount,
+            ..Default::default()
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceTax {
+    /// The name of the tax applied on the invoice items.
+    pub name: String,
+
+    /// The tax rate. Value is from 0 to 100. Supports up to five decimal places.
+    pub percent: String,
+
+    /// The calculated tax amount. The tax amount is added to the item total.
+    pub amount: Option<Money>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceDiscount {
+    /// The discount as a percentage value. Value is from 0 to 100. Supports up to five decimal
+    /// places.
+    pub percent: Option<String>,
+
+    /// The invoice level discount amount. Value is from 0 to 1000000. Supports up to two decimal
+    /// places.
+    pub amount: Option<Money>,
+}
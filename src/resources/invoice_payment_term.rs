@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::payment_term_type::PaymentTermType;
+
+/// The payment due date for the invoice.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoicePaymentTerm {
+    /// The payment term. Payment can be due upon receipt, a specified date, or in a set number of
+    /// days.
+    pub term_type: Option<PaymentTermType>,
+
+    /// The date when the invoice payment is due, in Internet date format.
+    /// For example, yyyy-MM-dd.
+    pub due_date: Option<String>,
+}
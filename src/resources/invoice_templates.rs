@@ -0,0 +1,697 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::amount_summary_detail::AmountSummaryDetail;
+use crate::resources::enums::currency_code::CurrencyCode;
+use crate::resources::enums::unit_of_measure::UnitOfMeasure;
+use crate::resources::invoice_item::InvoiceItem;
+use crate::resources::invoice_payment_term::InvoicePaymentTerm;
+use crate::resources::invoicer_info::InvoicerInfo;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+use crate::resources::money::Money;
+use crate::resources::partial_payment::PartialPayment;
+use crate::resources::recipient_info::RecipientInfo;
+
+/// An invoice template. Templates share most of their fields with invoices and are used to
+/// pre-fill invoices with commonly used information.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceTemplate {
+    /// The ID of the template.
+    pub id: Option<String>,
+
+    /// The template name.
+    /// **Note**: The template name must be unique.
+    pub name: String,
+
+    /// Indicates whether this template is the default template. A invoicer can have one default
+    /// template.
+    pub default_template: bool,
+
+    /// The template details. Includes invoicer business information, invoice recipients, items,
+    /// and configuration.
+    pub template_info: Option<TemplateInfo>,
+
+    /// The template settings. Describes which fields to show or hide when you create an invoice.
+    pub settings: Option<TemplateSettings>,
+
+    /// The unit of measure for the template. Value is quantity, hours, or amount.
+    pub unit_of_measure: Option<UnitOfMeasure>,
+
+    /// Indicates whether this template is a invoicer-created custom template. The system generates
+    /// non-custom templates.
+    pub standard_template: Option<bool>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl InvoiceTemplate {
+    /// Lists merchant-created templates with associated details. The associated details include the
+    /// emails, addresses, and phone numbers from the user's PayPal profile.
+    pub async fn list(
+        client: &Client,
+        query: ListTemplatesQuery,
+    ) -> Result<ListTemplatesResponse, PayPalError> {
+        client.get(&ListTemplates::new(query)).await
+    }
+
+    /// Shows details for a template, by ID.
+    pub async fn show(
+        client: &Client,
+        template_id: &str,
+    ) -> Result<ShowTemplateResponse, PayPalError> {
+        client
+            .get(&ShowTemplate::new(template_id.to_string()))
+            .await
+    }
+
+    /// Creates an invoice template. You can use this newly created template to create an invoice.
+    pub async fn create(
+        client: &Client,
+        dto: CreateTemplateDto,
+    ) -> Result<CreateTemplateResponse, PayPalError> {
+        client.post(&CreateTemplate::new(dto)).await
+    }
+
+    /// Fully updates a template, by ID. In the JSON request body, include a complete template
+    /// object. This call does not support partial updates.
+    pub async fn update(
+        client: &Client,
+        template_id: &str,
+        dto: UpdateTemplateDto,
+    ) -> Result<UpdateTemplateResponse, PayPalError> {
+        client
+            .put(&UpdateTemplate::new(template_id.to_string(), dto))
+            .await
+    }
+
+    /// Deletes a template, by ID.
+    pub async fn delete(client: &Client, template_id: &str) -> Result<(), PayPalError> {
+        client
+            .delete(&DeleteTemplate::new(template_id.to_string()))
+            .await?;
+        Ok(())
+    }
+}
+
+impl HateoasLinks for InvoiceTemplate {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateInfo {
+    /// The template-related details. Includes notes, terms and conditions, memo, and attachments.
+    pub detail: Option<TemplateDetail>,
+
+    /// The invoicer information. Includes business name, email, address, phone, fax, tax ID,
+    /// additional notes, and logo URL.
+    pub invoicer: Option<InvoicerInfo>,
+
+    /// The billing and shipping information. Includes name, email, address, phone, and language.
+    pub primary_recipients: Option<Vec<RecipientInfo>>,
+
+    /// An array of one or more CC: emails to which notifications are sent. If you omit this
+    /// parameter, a notification is sent to all CC: email addresses that are part of the invoice.
+    pub additional_recipients: Option<Vec<String>>,
+
+    /// An array of invoice line-item information.
+    pub items: Option<Vec<InvoiceItem>>,
+
+    /// The template configuration details. Includes partial payment, tip, and tax calculated after
+    /// discount.
+    pub configuration: Option<TemplateConfiguration>,
+
+    /// The invoice amount summary of item total, discount, tax total, and shipping.
+    pub amount: Option<AmountSummaryDetail>,
+
+    /// The amount due for the invoice.
+    pub due_amount: Option<Money>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateDetail {
+    /// The three-character ISO-4217 currency code that identifies the currency.
+    pub currency_code: CurrencyCode,
+
+    /// A note to the invoice recipient. Also appears on the invoice notification email.
+    pub note: Option<String>,
+
+    /// The general terms of the invoice. Can include return or cancellation policy and other terms
+    /// and conditions.
+    pub terms_and_conditions: Option<String>,
+
+    /// A private bookkeeping memo for the user.
+    pub memo: Option<String>,
+
+    /// The payment due date for the invoice.
+    pub payment_term: Option<InvoicePaymentTerm>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateConfiguration {
+    /// Indicates whether the tax is calculated before or after a discount.
+    pub tax_calculated_after_discount: Option<bool>,
+
+    /// Indicates whether the unit price includes tax.
+    pub tax_inclusive: Option<bool>,
+
+    /// Indicates whether the invoice enables the customer to enter a tip amount during payment.
+    pub allow_tip: Option<bool>,
+
+    /// The partial payment details. Includes the minimum amount that the invoicer wants the payer
+    /// to pay.
+    pub partial_payment: Option<PartialPayment>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateSettings {
+    /// The template item headers display preference.
+    pub template_item_settings: Option<Vec<TemplateItemSetting>>,
+
+    /// The template subtotal headers display preference.
+    pub template_subtotal_settings: Option<Vec<TemplateSubtotalSetting>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateItemSetting {
+    /// The field name in `template_items` for which to map the display preferences.
+    pub field_name: String,
+
+    /// The display preference.
+    pub display_preference: Option<TemplateDisplayPreference>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateSubtotalSetting {
+    /// The field name in `template_subtotals` for which to map the display preferences.
+    pub field_name: String,
+
+    /// The display preference.
+    pub display_preference: Option<TemplateDisplayPreference>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TemplateDisplayPreference {
+    /// Indicates whether to show or hide this field.
+    pub hidden: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListTemplatesResponse {
+    /// An array of email addresses from the user's PayPal profile.
+    pub emails: Option<Vec<String>>,
+
+    /// An array of details for each template. If `fields` is `none`, returns only the template
+    /// name, ID, and default status.
+    pub templates: Option<Vec<InvoiceTemplate>>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListTemplatesQuery {
+    /// The fields to return in the response. Value is `all` or `none`. To return only the template
+    /// name, ID, and default attributes, specify `none`.
+    pub fields: Option<String>,
+
+    /// The page number to be retrieved, for the list of templates.
+    pub page: Option<u32>,
+
+    /// The maximum number of templates to return in the response.
+    pub page_size: Option<u32>,
+}
+
+impl ListTemplatesQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns only the name, ID, and default status of the templates.
+    #[must_use]
+    pub fn without_details(mut self) -> Self {
+        self.fields = Some("none".to_string());
+        self
+    }
+
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    #[must_use]
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct ListTemplates {
+    query: ListTemplatesQuery,
+}
+
+impl ListTemplates {
+    pub const fn new(query: ListTemplatesQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListTemplates {
+    type QueryParams = ListTemplatesQuery;
+    type RequestBody = ();
+    type ResponseBody = ListTemplatesResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v2/invoicing/templates")
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+pub type ShowTemplateResponse = InvoiceTemplate;
+
+#[derive(Debug)]
+struct ShowTemplate {
+    template_id: String,
+}
+
+impl ShowTemplate {
+    pub const fn new(template_id: String) -> Self {
+        Self { template_id }
+    }
+}
+
+impl Endpoint for ShowTemplate {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ShowTemplateResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/templates/{}", self.template_id))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTemplateDto {
+    /// The template name. The template name must be unique.
+    pub name: String,
+
+    /// Indicates whether this template is the default template.
+    pub default_template: bool,
+
+    /// The template details. Includes invoicer business information, invoice recipients, items,
+    /// and configuration.
+    pub template_info: Option<TemplateInfo>,
+
+    /// The template settings. Describes which fields to show or hide when you create an invoice.
+    pub settings: Option<TemplateSettings>,
+
+    /// The unit of measure for the template. Value is quantity, hours, or amount.
+    pub unit_of_measure: Option<UnitOfMeasure>,
+}
+
+impl CreateTemplateDto {
+    #[must_use]
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub const fn default_template(mut self, default_template: bool) -> Self {
+        self.default_template = default_template;
+        self
+    }
+
+    #[must_use]
+    pub fn template_info(mut self, template_info: TemplateInfo) -> Self {
+        self.template_info = Some(template_info);
+        self
+    }
+
+    #[must_use]
+    pub fn settings(mut self, settings: TemplateSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    #[must_use]
+    pub const fn unit_of_measure(mut self, unit_of_measure: UnitOfMeasure) -> Self {
+        self.unit_of_measure = Some(unit_of_measure);
+        self
+    }
+}
+
+pub type CreateTemplateResponse = InvoiceTemplate;
+
+#[derive(Debug)]
+struct CreateTemplate {
+    body: CreateTemplateDto,
+}
+
+impl CreateTemplate {
+    pub const fn new(body: CreateTemplateDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateTemplate {
+    type QueryParams = ();
+    type RequestBody = CreateTemplateDto;
+    type ResponseBody = CreateTemplateResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v2/invoicing/templates")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+pub type UpdateTemplateDto = CreateTemplateDto;
+
+pub type UpdateTemplateResponse = InvoiceTemplate;
+
+#[derive(Debug)]
+struct UpdateTemplate {
+    template_id: String,
+    body: UpdateTemplateDto,
+}
+
+impl UpdateTemplate {
+    pub const fn new(template_id: String, body: UpdateTemplateDto) -> Self {
+        Self { template_id, body }
+    }
+}
+
+impl Endpoint for UpdateTemplate {
+    type QueryParams = ();
+    type RequestBody = UpdateTemplateDto;
+    type ResponseBody = UpdateTemplateResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/templates/{}", self.template_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::PUT
+    }
+}
+
+#[derive(Debug)]
+struct DeleteTemplate {
+    template_id: String,
+}
+
+impl DeleteTemplate {
+    pub const fn new(template_id: String) -> Self {
+        Self { template_id }
+    }
+}
+
+impl Endpoint for DeleteTemplate {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/templates/{}", self.template_id))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::DELETE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::enums::link_rel::LinkRel;
+    use crate::resources::enums::payment_term_type::PaymentTermType;
+
+    #[test]
+    fn list_templates_query() {
+        let endpoint =
+            ListTemplates::new(ListTemplatesQuery::new().without_details().page_size(50));
+
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "fields=none&page_size=50"
+        );
+    }
+
+    #[test]
+    fn create_template_request_body() {
+        let endpoint = CreateTemplate::new(
+            CreateTemplateDto::new("Hours Template".to_string())
+                .default_template(true)
+                .template_info(TemplateInfo {
+                    detail: Some(TemplateDetail {
+                        currency_code: CurrencyCode::UnitedStatesDollar,
+                        note: Some("Thank you for your business.".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .unit_of_measure(UnitOfMeasure::Hours),
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "name": "Hours Template",
+                "default_template": true,
+                "template_info": {
+                    "detail": {
+                        "currency_code": "USD",
+                        "note": "Thank you for your business."
+                    }
+                },
+                "unit_of_measure": "HOURS"
+            })
+        );
+    }
+
+    #[test]
+    fn show_template_response() {
+        let endpoint = ShowTemplate::new("TEMP-19V05281TU309413B".to_string());
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/templates/TEMP-19V05281TU309413B"
+        );
+        assert_eq!(endpoint.request_method(), Method::GET);
+
+        let template = endpoint
+            .parse_response(
+                r#"{
+                    "id": "TEMP-19V05281TU309413B",
+                    "name": "Template 1",
+                    "default_template": true,
+                    "template_info": {
+                        "detail": {
+                            "currency_code": "USD",
+                            "note": "Thank you for your business.",
+                            "terms_and_conditions": "No refunds after 30 days.",
+                            "memo": "This is a long contract",
+                            "payment_term": {"term_type": "NET_10"}
+                        },
+                        "invoicer": {
+                            "name": {"given_name": "David", "surname": "Larusso"},
+                            "email_address": "merchant@example.com",
+                            "website": "www.test.com",
+                            "logo_url": "https://example.com/logo.PNG",
+                            "additional_notes": "2-4"
+                        },
+                        "items": [
+                            {
+                                "name": "Yoga Mat",
+                                "description": "new watch",
+                                "quantity": "1",
+                                "unit_amount": {"currency_code": "USD", "value": "50.00"},
+                                "tax": {"name": "Sales Tax", "percent": "7.25"},
+                                "discount": {"percent": "5"},
+                                "unit_of_measure": "QUANTITY"
+                            }
+                        ],
+                        "configuration": {
+                            "tax_calculated_after_discount": true,
+                            "tax_inclusive": false,
+                            "allow_tip": true,
+                            "partial_payment": {
+                                "allow_partial_payment": true,
+                                "minimum_amount_due": {"currency_code": "USD", "value": "20.00"}
+                            }
+                        }
+                    },
+                    "settings": {
+                        "template_item_settings": [
+                            {"field_name": "items.date", "display_preference": {"hidden": true}},
+                            {"field_name": "items.discount", "display_preference": {"hidden": false}}
+                        ],
+                        "template_subtotal_settings": [
+                            {"field_name": "custom", "display_preference": {"hidden": false}}
+                        ]
+                    },
+                    "unit_of_measure": "QUANTITY",
+                    "standard_template": false,
+                    "links": [
+                        {
+                            "href": "https://api-m.paypal.com/v2/invoicing/templates/TEMP-19V05281TU309413B",
+                            "rel": "self",
+                            "method": "GET"
+                        },
+                        {
+                            "href": "https://api-m.paypal.com/v2/invoicing/templates/TEMP-19V05281TU309413B",
+                            "rel": "delete",
+                            "method": "DELETE"
+                        }
+                    ]
+                }"#,
+            )
+            .unwrap();
+
+        assert_eq!(template.id.as_deref(), Some("TEMP-19V05281TU309413B"));
+        assert_eq!(template.name, "Template 1");
+        assert!(template.default_template);
+        assert_eq!(template.standard_template, Some(false));
+        let info = template.template_info.as_ref().unwrap();
+        let detail = info.detail.as_ref().unwrap();
+        assert_eq!(detail.currency_code, CurrencyCode::UnitedStatesDollar);
+        assert_eq!(
+            detail.payment_term.as_ref().unwrap().term_type,
+            Some(PaymentTermType::Net10)
+        );
+        assert_eq!(info.items.as_ref().unwrap()[0].unit_amount.value, "50.00");
+        assert_eq!(info.configuration.as_ref().unwrap().allow_tip, Some(true));
+        let item_settings = template
+            .settings
+            .as_ref()
+            .unwrap()
+            .template_item_settings
+            .as_ref()
+            .unwrap();
+        assert_eq!(item_settings[0].field_name, "items.date");
+        assert!(item_settings[0].display_preference.as_ref().unwrap().hidden);
+        assert!(template.link(LinkRel::Delete).is_some());
+    }
+
+    #[test]
+    fn update_template_request_body() {
+        let item_setting = |field_name: &str, hidden| TemplateItemSetting {
+            field_name: field_name.to_string(),
+            display_preference: Some(TemplateDisplayPreference { hidden }),
+        };
+        let endpoint = UpdateTemplate::new(
+            "TEMP-19V05281TU309413B".to_string(),
+            UpdateTemplateDto::new("Template 1".to_string())
+                .default_template(true)
+                .template_info(TemplateInfo {
+                    detail: Some(TemplateDetail {
+                        currency_code: CurrencyCode::UnitedStatesDollar,
+                        note: Some("Thank you for your business.".to_string()),
+                        terms_and_conditions: Some("No refunds after 30 days.".to_string()),
+                        memo: Some("This is a long contract".to_string()),
+                        payment_term: Some(InvoicePaymentTerm {
+                            term_type: Some(PaymentTermType::Net10),
+                            ..Default::default()
+                        }),
+                    }),
+                    configuration: Some(TemplateConfiguration {
+                        tax_calculated_after_discount: Some(true),
+                        tax_inclusive: Some(false),
+                        allow_tip: Some(true),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })
+                .settings(TemplateSettings {
+                    template_item_settings: Some(vec![
+                        item_setting("items.date", true),
+                        item_setting("items.discount", false),
+                    ]),
+                    ..Default::default()
+                })
+                .unit_of_measure(UnitOfMeasure::Quantity),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/templates/TEMP-19V05281TU309413B"
+        );
+        assert_eq!(endpoint.request_method(), Method::PUT);
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "name": "Template 1",
+                "default_template": true,
+                "template_info": {
+                    "detail": {
+                        "currency_code": "USD",
+                        "note": "Thank you for your business.",
+                        "terms_and_conditions": "No refunds after 30 days.",
+                        "memo": "This is a long contract",
+                        "payment_term": {"term_type": "NET_10"}
+                    },
+                    "configuration": {
+                        "tax_calculated_after_discount": true,
+                        "tax_inclusive": false,
+                        "allow_tip": true
+                    }
+                },
+                "settings": {
+                    "template_item_settings": [
+                        {"field_name": "items.date", "display_preference": {"hidden": true}},
+                        {"field_name": "items.discount", "display_preference": {"hidden": false}}
+                    ]
+                },
+                "unit_of_measure": "QUANTITY"
+            })
+        );
+    }
+
+    #[test]
+    fn delete_template_request() {
+        let endpoint = DeleteTemplate::new("TEMP-19V05281TU309413B".to_string());
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/templates/TEMP-19V05281TU309413B"
+        );
+        assert_eq!(endpoint.request_method(), Method::DELETE);
+        assert!(endpoint.request_body().is_none());
+    }
+}
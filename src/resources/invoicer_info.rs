@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::name::Name;
+use crate::resources::phone_detail::PhoneDetail;
+use crate::resources::shipping_detail_address_portable::ShippingDetailAddressPortable;
+
+/// The invoicer business information that appears on the invoice.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoicerInfo {
+    /// Required. The business name of the party.
+    pub business_name: Option<String>,
+
+    /// The first and last name of the invoicer.
+    pub name: Option<Name>,
+
+    /// The portable international postal address.
+    pub address: Option<ShippingDetailAddressPortable>,
+
+    /// The invoicer email address, which must be listed in the user's PayPal profile.
+    /// If you omit this value, notifications are sent from and to the primary email address but
+    /// do not appear on the invoice.
+    pub email_address: Option<String>,
+
+    /// An array of invoicer's phone numbers. The invoicer can choose to hide the phone number on
+    /// the invoice.
+    pub phones: Option<Vec<PhoneDetail>>,
+
+    /// The invoicer's website.
+    pub website: Option<String>,
+
+    /// The invoicer's tax ID.
+    pub tax_id: Option<String>,
+
+    /// Any additional information. Includes business hours.
+    pub additional_notes: Option<String>,
+
+    /// The full URL to an external logo image. The logo image must not be larger than 250 pixels
+    /// wide by 90 pixels high.
+    pub logo_url: Option<String>,
+}
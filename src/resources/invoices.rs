@@ -0,0 +1,1543 @@
+use std::borrow::Cow;
+
+use base64::{engine::general_purpose, Engine as _};
+use chrono::NaiveDate;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::client::request::HttpRequestHeaders;
+use crate::resources::amount_summary_detail::AmountSummaryDetail;
+use crate::resources::enums::currency_code::CurrencyCode;
+use crate::resources::enums::invoice_payment_type::InvoicePaymentType;
+use crate::resources::enums::invoice_status::InvoiceStatus;
+use crate::resources::enums::payment_method::PaymentMethod;
+use crate::resources::enums::qr_code_action::QrCodeAction;
+use crate::resources::invoice_item::InvoiceItem;
+use crate::resources::invoice_payment_term::InvoicePaymentTerm;
+use crate::resources::invoicer_info::InvoicerInfo;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+use crate::resources::money::Money;
+use crate::resources::partial_payment::PartialPayment;
+use crate::resources::recipient_info::{ContactInformation, RecipientInfo};
+
+pub struct Invoice;
+
+impl Invoice {
+    /// Creates a draft invoice. To move the invoice from a draft to payable state, you must send
+    /// the invoice.
+    pub async fn create(
+        client: &Client,
+        dto: CreateInvoiceDto,
+    ) -> Result<InvoiceDetails, PayPalError> {
+        client.post(&CreateInvoice::new(dto)).await
+    }
+
+    /// Shows details for an invoice, by ID.
+    pub async fn show(client: &Client, invoice_id: &str) -> Result<InvoiceDetails, PayPalError> {
+        client.get(&ShowInvoice::new(invoice_id.to_string())).await
+    }
+
+    /// Fully updates an invoice, by ID. In the JSON request body, include a complete invoice
+    /// object. This call does not support partial updates.
+    ///
+    /// # Arguments
+    /// * `send_to_recipient` - Whether to send the invoice update notification to the recipient.
+    /// * `send_to_invoicer` - Whether to send the invoice update notification to the merchant.
+    pub async fn update(
+        client: &Client,
+        invoice_id: &str,
+        invoice: InvoiceDetails,
+        send_to_recipient: bool,
+        send_to_invoicer: bool,
+    ) -> Result<InvoiceDetails, PayPalError> {
+        let query = UpdateInvoiceQuery {
+            send_to_recipient,
+            send_to_invoicer,
+        };
+
+        client
+            .put(&UpdateInvoice::new(invoice_id.to_string(), invoice, query))
+            .await
+    }
+
+    /// Deletes a draft or scheduled invoice, by ID. Deletes invoices in the draft or scheduled
+    /// state only. For invoices that have already been sent, you can cancel the invoice.
+    pub async fn delete(client: &Client, invoice_id: &str) -> Result<(), PayPalError> {
+        client
+            .delete(&DeleteInvoice::new(invoice_id.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    /// Sends a reminder to the payer about an invoice, by ID. In the JSON request body, include a
+    /// notification object that defines the subject of the reminder and other details.
+    pub async fn remind(
+        client: &Client,
+        invoice_id: &str,
+        dto: InvoiceNotificationDto,
+    ) -> Result<(), PayPalError> {
+        client
+            .post(&RemindInvoice::new(invoice_id.to_string(), dto))
+            .await?;
+        Ok(())
+    }
+
+    /// Cancels a sent invoice, by ID, and, optionally, sends a notification about the
+    /// cancellation to the payer, merchant, and CC: emails.
+    pub async fn cancel(
+        client: &Client,
+        invoice_id: &str,
+        dto: CancelInvoiceDto,
+    ) -> Result<(), PayPalError> {
+        client
+            .post(&CancelInvoice::new(invoice_id.to_string(), dto))
+            .await?;
+        Ok(())
+    }
+
+    /// Generates a QR code for an invoice, by ID. The QR code is a PNG image of the given width
+    /// and height in pixels, each between 150 and 500. Returns the decoded PNG bytes.
+    pub async fn generate_qr_code(
+        client: &Client,
+        invoice_id: &str,
+        width: u32,
+        height: u32,
+        action: QrCodeAction,
+    ) -> Result<Vec<u8>, PayPalError> {
+        let dto = GenerateQrCodeDto {
+            width,
+            height,
+            action,
+        };
+
+        client
+            .post(&GenerateQrCode::new(invoice_id.to_string(), dto))
+            .await
+    }
+
+    /// Generates the next invoice number that is available to the merchant. The next invoice
+    /// number uses the prefix and suffix from the last invoice number and increments the number
+    /// by one.
+    pub async fn generate_next_number(
+        client: &Client,
+    ) -> Result<GenerateNextInvoiceNumberResponse, PayPalError> {
+        client.post(&GenerateNextInvoiceNumber).await
+    }
+
+    /// Records a payment for the invoice. If no payment is due, the invoice is marked as `PAID`.
+    /// Otherwise, the invoice is marked as `PARTIALLY PAID`.
+    pub async fn record_payment(
+        client: &Client,
+        invoice_id: &str,
+        dto: RecordPaymentDto,
+    ) -> Result<RecordPaymentResponse, PayPalError> {
+        client
+            .post(&RecordPayment::new(invoice_id.to_string(), dto))
+            .await
+    }
+
+    /// Records a refund for the invoice. If all payments are refunded, the invoice is marked as
+    /// `REFUNDED`. Otherwise, the invoice is marked as `PARTIALLY REFUNDED`.
+    pub async fn record_refund(
+        client: &Client,
+        invoice_id: &str,
+        dto: RecordRefundDto,
+    ) -> Result<RecordRefundResponse, PayPalError> {
+        client
+            .post(&RecordRefund::new(invoice_id.to_string(), dto))
+            .await
+    }
+
+    /// Records a payment that was made outside of PayPal, for example by bank transfer or in cash.
+    /// Shortcut for [`Invoice::record_payment`].
+    pub async fn mark_as_paid(
+        client: &Client,
+        invoice_id: &str,
+        method: PaymentMethod,
+        amount: Money,
+        date: NaiveDate,
+        note: Option<&str>,
+    ) -> Result<RecordPaymentResponse, PayPalError> {
+        let dto = RecordPaymentDto::on_date(method, amount, date, note);
+
+        Self::record_payment(client, invoice_id, dto).await
+    }
+
+    /// Records a refund that was made outside of PayPal. Shortcut for [`Invoice::record_refund`].
+    pub async fn mark_as_refunded(
+        client: &Client,
+        invoice_id: &str,
+        method: PaymentMethod,
+        amount: Money,
+        date: NaiveDate,
+        note: Option<&str>,
+    ) -> Result<RecordRefundResponse, PayPalError> {
+        let dto = RecordRefundDto::on_date(method, amount, date, note);
+
+        Self::record_refund(client, invoice_id, dto).await
+    }
+
+    /// Deletes an external payment, by invoice ID and transaction ID.
+    pub async fn delete_external_payment(
+        client: &Client,
+        invoice_id: &str,
+        transaction_id: &str,
+    ) -> Result<(), PayPalError> {
+        client
+            .delete(&DeleteExternalPayment::new(
+                invoice_id.to_string(),
+                transaction_id.to_string(),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes an external refund, by invoice ID and transaction ID.
+    pub async fn delete_external_refund(
+        client: &Client,
+        invoice_id: &str,
+        transaction_id: &str,
+    ) -> Result<(), PayPalError> {
+        client
+            .delete(&DeleteExternalRefund::new(
+                invoice_id.to_string(),
+                transaction_id.to_string(),
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+/// An invoice, as returned by PayPal.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceDetails {
+    /// The ID of the invoice.
+    pub id: Option<String>,
+
+    /// The status of the invoice.
+    pub status: Option<InvoiceStatus>,
+
+    /// The details of the invoice. Includes the invoice number, date, payment terms, and audit
+    /// metadata.
+    pub detail: InvoiceDetail,
+
+    /// The invoicer information. Includes business name, email, address, phone, fax, tax ID,
+    /// additional notes, and logo URL.
+    pub invoicer: Option<InvoicerInfo>,
+
+    /// The billing and shipping information. Includes name, email, address, phone and language.
+    pub primary_recipients: Option<Vec<RecipientInfo>>,
+
+    /// An array of one or more CC: emails to which notifications are sent.
+    pub additional_recipients: Option<Vec<String>>,
+
+    /// An array of invoice line item information.
+    pub items: Option<Vec<InvoiceItem>>,
+
+    /// The invoice configuration details. Includes partial payment, tip, and tax calculated after
+    /// discount.
+    pub configuration: Option<InvoiceConfiguration>,
+
+    /// The invoice amount summary of item total, discount, tax total and shipping.
+    pub amount: Option<AmountSummaryDetail>,
+
+    /// The amount due for the invoice.
+    pub due_amount: Option<Money>,
+
+    /// The amount paid by the payer as gratuity to the invoicer.
+    pub gratuity: Option<Money>,
+
+    /// List of payments registered against the invoice.
+    pub payments: Option<InvoicePayments>,
+
+    /// List of refunds against this invoice. The invoicing refund details includes refund type,
+    /// date, amount, and method.
+    pub refunds: Option<InvoiceRefunds>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl InvoiceDetails {
+    /// Whether the invoice is still awaiting payment after its due date.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        let awaiting_payment = matches!(
+            self.status,
+            Some(InvoiceStatus::Sent | InvoiceStatus::Unpaid | InvoiceStatus::PartiallyPaid)
+        );
+
+        awaiting_payment
+            && self
+                .detail
+                .payment_term
+                .as_ref()
+                .and_then(|payment_term| payment_term.due_date.as_deref())
+                .and_then(|due_date| NaiveDate::parse_from_str(due_date, "%Y-%m-%d").ok())
+                .map_or(false, |due_date| due_date < today)
+    }
+}
+
+impl HateoasLinks for InvoiceDetails {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoicePayments {
+    /// The aggregated payment amounts against this invoice.
+    pub paid_amount: Option<Money>,
+
+    /// An array of payment details for the invoice. The payment details of the invoice like
+    /// payment type, method, date, discount and transaction type.
+    pub transactions: Option<Vec<InvoicePaymentDetail>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoicePaymentDetail {
+    /// The payment type in an invoicing flow which can be PayPal or an external cash or check
+    /// payment.
+    #[serde(rename = "type")]
+    pub payment_type: Option<InvoicePaymentType>,
+
+    /// The ID for a PayPal payment transaction. Required for the `PAYPAL` payment type.
+    pub payment_id: Option<String>,
+
+    /// The date when the invoice was paid, in Internet date format. For example, yyyy-MM-dd.
+    pub payment_date: Option<String>,
+
+    /// The payment mode or method through which the invoicer can accept the payment.
+    pub method: PaymentMethod,
+
+    /// A note associated with an external cash or check payment.
+    pub note: Option<String>,
+
+    /// The payment amount to record against the invoice.
+    pub amount: Option<Money>,
+
+    /// The recipient's shipping information. Includes the user's contact information, which
+    /// includes name and address.
+    pub shipping_info: Option<ContactInformation>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceRefunds {
+    /// The aggregated refund amounts.
+    pub refund_amount: Option<Money>,
+
+    /// An array of refund details for the invoice. Includes the refund type, date, amount, and
+    /// method.
+    pub transactions: Option<Vec<InvoiceRefundDetail>>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InvoiceRefundDetail {
+    /// The PayPal refund type. Indicates whether the refund was paid through PayPal or externally
+    /// in the invoicing flow.
+    #[serde(rename = "type")]
+    pub refund_type: Option<InvoicePaymentType>,
+
+    /// The ID for a PayPal refund transaction. Required for the `PAYPAL` refund type.
+    pub refund_id: Option<String>,
+
+    /// The date when the invoice was refunded, in Internet date format. For example, yyyy-MM-dd.
+    pub refund_date: Option<String>,
+
+    /// The amount to record as refunded. If you omit the amount, the total invoice paid amount is
+    /// recorded as refunded.
+    pub amount: Option<Money>,
+
+    /// The payment mode or method through which the invoicer can accept the payments.
+    pub method: PaymentMethod,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceDetail {
+    /// The reference data. Includes a post office (PO) number.
+    pub reference: Option<String>,
+
+    /// The three-character ISO-4217 currency code that identifies the currency.
+    pub currency_code: CurrencyCode,
+
+    /// A note to the invoice recipient. Also appears on the invoice notification email.
+    pub note: Option<String>,
+
+    /// The general terms of the invoice. Can include return or cancellation policy and other terms
+    /// and conditions.
+    pub terms_and_conditions: Option<String>,
+
+    /// A private bookkeeping memo for the user.
+    pub memo: Option<String>,
+
+    /// The invoice number. Default is the number that is auto-incremented from the last number.
+    pub invoice_number: Option<String>,
+
+    /// The invoice date as specified by the sender, in Internet date format. For example,
+    /// yyyy-MM-dd.
+    pub invoice_date: Option<String>,
+
+    /// The payment due date for the invoice.
+    pub payment_term: Option<InvoicePaymentTerm>,
+}
+
+impl InvoiceDetail {
+    #[must_use]
+    pub fn new(currency_code: CurrencyCode) -> Self {
+        Self {
+            currency_code,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn reference(mut self, reference: String) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+
+    #[must_use]
+    pub fn note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    #[must_use]
+    pub fn terms_and_conditions(mut self, terms_and_conditions: String) -> Self {
+        self.terms_and_conditions = Some(terms_and_conditions);
+        self
+    }
+
+    #[must_use]
+    pub fn memo(mut self, memo: String) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    #[must_use]
+    pub fn invoice_number(mut self, invoice_number: String) -> Self {
+        self.invoice_number = Some(invoice_number);
+        self
+    }
+
+    #[must_use]
+    pub fn invoice_date(mut self, invoice_date: NaiveDate) -> Self {
+        self.invoice_date = Some(invoice_date.format("%Y-%m-%d").to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn payment_term(mut self, payment_term: InvoicePaymentTerm) -> Self {
+        self.payment_term = Some(payment_term);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InvoiceConfiguration {
+    /// Indicates whether the tax is calculated before or after a discount.
+    pub tax_calculated_after_discount: Option<bool>,
+
+    /// Indicates whether the unit price includes tax.
+    pub tax_inclusive: Option<bool>,
+
+    /// Indicates whether the invoice enables the customer to enter a tip amount during payment.
+    pub allow_tip: Option<bool>,
+
+    /// The partial payment details. Includes the minimum amount that the invoicer wants the payer
+    /// to pay.
+    pub partial_payment: Option<PartialPayment>,
+
+    /// The template ID. The template determines the layout of the invoice. Includes which fields
+    /// to show and hide.
+    pub template_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateInvoiceDto {
+    /// The details of the invoice. Includes the invoice number, date, payment terms, and audit
+    /// metadata.
+    pub detail: InvoiceDetail,
+
+    /// The invoicer information. Includes business name, email, address, phone, fax, tax ID,
+    /// additional notes, and logo URL.
+    pub invoicer: Option<InvoicerInfo>,
+
+    /// The billing and shipping information. Includes name, email, address, phone and language.
+    pub primary_recipients: Option<Vec<RecipientInfo>>,
+
+    /// An array of one or more CC: emails to which notifications are sent.
+    pub additional_recipients: Option<Vec<String>>,
+
+    /// An array of invoice line item information.
+    pub items: Option<Vec<InvoiceItem>>,
+
+    /// The invoice configuration details. Includes partial payment, tip, and tax calculated after
+    /// discount.
+    pub configuration: Option<InvoiceConfiguration>,
+
+    /// The invoice amount summary of item total, discount, tax total and shipping.
+    pub amount: Option<AmountSummaryDetail>,
+}
+
+impl CreateInvoiceDto {
+    #[must_use]
+    pub fn new(detail: InvoiceDetail) -> Self {
+        Self {
+            detail,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn invoicer(mut self, invoicer: InvoicerInfo) -> Self {
+        self.invoicer = Some(invoicer);
+        self
+    }
+
+    /// Adds a primary recipient to the invoice.
+    #[must_use]
+    pub fn primary_recipient(mut self, recipient: RecipientInfo) -> Self {
+        self.primary_recipients
+            .get_or_insert_with(Vec::new)
+            .push(recipient);
+        self
+    }
+
+    /// Adds a CC: email address to which notifications are sent.
+    #[must_use]
+    pub fn additional_recipient(mut self, email_address: String) -> Self {
+        self.additional_recipients
+            .get_or_insert_with(Vec::new)
+            .push(email_address);
+        self
+    }
+
+    /// Adds a line item to the invoice.
+    #[must_use]
+    pub fn item(mut self, item: InvoiceItem) -> Self {
+        self.items.get_or_insert_with(Vec::new).push(item);
+        self
+    }
+
+    /// Adds line items to the invoice, for example the items of an order.
+    #[must_use]
+    pub fn items<I: Into<InvoiceItem>>(mut self, items: impl IntoIterator<Item = I>) -> Self {
+        self.items
+            .get_or_insert_with(Vec::new)
+            .extend(items.into_iter().map(Into::into));
+        self
+    }
+
+    #[must_use]
+    pub fn configuration(mut self, configuration: InvoiceConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    #[must_use]
+    pub fn amount(mut self, amount: AmountSummaryDetail) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct CreateInvoice {
+    body: CreateInvoiceDto,
+}
+
+impl CreateInvoice {
+    pub const fn new(body: CreateInvoiceDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateInvoice {
+    type QueryParams = ();
+    type RequestBody = CreateInvoiceDto;
+    type ResponseBody = InvoiceDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v2/invoicing/invoices")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+
+    fn headers(&self) -> HttpRequestHeaders {
+        HttpRequestHeaders {
+            prefer: Some("return=representation".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ShowInvoice {
+    invoice_id: String,
+}
+
+impl ShowInvoice {
+    pub const fn new(invoice_id: String) -> Self {
+        Self { invoice_id }
+    }
+}
+
+impl Endpoint for ShowInvoice {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = InvoiceDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/invoices/{}", self.invoice_id))
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct UpdateInvoiceQuery {
+    /// Indicates whether to send the invoice update notification to the recipient.
+    send_to_recipient: bool,
+
+    /// Indicates whether to send the invoice update notification to the merchant.
+    send_to_invoicer: bool,
+}
+
+#[derive(Debug)]
+struct UpdateInvoice {
+    invoice_id: String,
+    body: InvoiceDetails,
+    query: UpdateInvoiceQuery,
+}
+
+impl UpdateInvoice {
+    pub const fn new(invoice_id: String, body: InvoiceDetails, query: UpdateInvoiceQuery) -> Self {
+        Self {
+            invoice_id,
+            body,
+            query,
+        }
+    }
+}
+
+impl Endpoint for UpdateInvoice {
+    type QueryParams = UpdateInvoiceQuery;
+    type RequestBody = InvoiceDetails;
+    type ResponseBody = InvoiceDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/invoices/{}", self.invoice_id))
+    }
+
+    fn headers(&self) -> HttpRequestHeaders {
+        HttpRequestHeaders {
+            prefer: Some("return=representation".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::PUT
+    }
+}
+
+#[derive(Debug)]
+struct DeleteInvoice {
+    invoice_id: String,
+}
+
+impl DeleteInvoice {
+    pub const fn new(invoice_id: String) -> Self {
+        Self { invoice_id }
+    }
+}
+
+impl Endpoint for DeleteInvoice {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/invoices/{}", self.invoice_id))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::DELETE
+    }
+}
+
+/// The email or SMS notification to send to the invoicer or payer.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct InvoiceNotificationDto {
+    /// The subject of the email that is sent as a notification to the recipient.
+    pub subject: Option<String>,
+
+    /// A note to the payer.
+    pub note: Option<String>,
+
+    /// Indicates whether to send a copy of the email to the merchant.
+    pub send_to_invoicer: Option<bool>,
+
+    /// Indicates whether to send a copy of the email to the recipient.
+    pub send_to_recipient: Option<bool>,
+
+    /// An array of one or more CC: emails to which notifications are sent. If you omit this
+    /// parameter, a notification is sent to all CC: email addresses that are part of the invoice.
+    pub additional_recipients: Option<Vec<String>>,
+}
+
+impl InvoiceNotificationDto {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn subject(mut self, subject: String) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
+    #[must_use]
+    pub fn note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    #[must_use]
+    pub const fn send_to_invoicer(mut self, send_to_invoicer: bool) -> Self {
+        self.send_to_invoicer = Some(send_to_invoicer);
+        self
+    }
+
+    #[must_use]
+    pub const fn send_to_recipient(mut self, send_to_recipient: bool) -> Self {
+        self.send_to_recipient = Some(send_to_recipient);
+        self
+    }
+
+    /// Adds a CC: email address to which the notification is sent.
+    #[must_use]
+    pub fn additional_recipient(mut self, email_address: String) -> Self {
+        self.additional_recipients
+            .get_or_insert_with(Vec::new)
+            .push(email_address);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct RemindInvoice {
+    invoice_id: String,
+    body: InvoiceNotificationDto,
+}
+
+impl RemindInvoice {
+    pub const fn new(invoice_id: String, body: InvoiceNotificationDto) -> Self {
+        Self { invoice_id, body }
+    }
+}
+
+impl Endpoint for RemindInvoice {
+    type QueryParams = ();
+    type RequestBody = InvoiceNotificationDto;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/invoices/{}/remind", self.invoice_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+pub type CancelInvoiceDto = InvoiceNotificationDto;
+
+#[derive(Debug)]
+struct CancelInvoice {
+    invoice_id: String,
+    body: CancelInvoiceDto,
+}
+
+impl CancelInvoice {
+    pub const fn new(invoice_id: String, body: CancelInvoiceDto) -> Self {
+        Self { invoice_id, body }
+    }
+}
+
+impl Endpoint for CancelInvoice {
+    type QueryParams = ();
+    type RequestBody = CancelInvoiceDto;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/invoices/{}/cancel", self.invoice_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct GenerateQrCodeDto {
+    /// The width, in pixels, of the QR code image. Value is from 150 to 500.
+    pub width: u32,
+
+    /// The height, in pixels, of the QR code image. Value is from 150 to 500.
+    pub height: u32,
+
+    /// The type of URL for which to generate a QR code.
+    pub action: QrCodeAction,
+}
+
+#[derive(Debug)]
+struct GenerateQrCode {
+    invoice_id: String,
+    body: GenerateQrCodeDto,
+}
+
+impl GenerateQrCode {
+    pub const fn new(invoice_id: String, body: GenerateQrCodeDto) -> Self {
+        Self { invoice_id, body }
+    }
+}
+
+impl Endpoint for GenerateQrCode {
+    type QueryParams = ();
+    type RequestBody = GenerateQrCodeDto;
+    type ResponseBody = Vec<u8>;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/invoicing/invoices/{}/generate-qr-code",
+            self.invoice_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+
+    /// The QR code is returned as base64 encoded text instead of JSON, possibly wrapped in a
+    /// multipart body. Lines outside of the base64 alphabet are boundaries or part headers.
+    fn parse_response(&self, text: &str) -> Result<Self::ResponseBody, PayPalError> {
+        let is_base64 = |line: &&str| {
+            !line.is_empty()
+                && line
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || b"+/=".contains(&byte))
+        };
+        let encoded: String = text.lines().map(str::trim).filter(is_base64).collect();
+
+        if encoded.is_empty() {
+            return Err(PayPalError::LibraryError(
+                "The response does not contain a QR code image".to_string(),
+            ));
+        }
+
+        general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|error| PayPalError::LibraryError(format!("Invalid QR code image: {error}")))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct RecordPaymentDto {
+    /// The payment mode or method through which the invoicer can accept the payment.
+    pub method: PaymentMethod,
+
+    /// The amount paid for the invoice.
+    pub amount: Money,
+
+    /// The date when the invoice was paid, in Internet date format. For example, yyyy-MM-dd.
+    pub payment_date: String,
+
+    /// A note associated with an external cash or check payment.
+    pub note: Option<String>,
+
+    /// The ID for a PayPal payment transaction. Required for the `PAYPAL` payment type.
+    pub payment_id: Option<String>,
+
+    /// The recipient's shipping information. Includes the user's contact information, which
+    /// includes name and address.
+    pub shipping_info: Option<ContactInformation>,
+}
+
+impl RecordPaymentDto {
+    #[must_use]
+    pub const fn new(method: PaymentMethod, amount: Money, date: String) -> Self {
+        Self {
+            method,
+            amount,
+            payment_date: date,
+            note: None,
+            payment_id: None,
+            shipping_info: None,
+        }
+    }
+
+    /// A payment on the given date, formatted as PayPal expects it.
+    fn on_date(method: PaymentMethod, amount: Money, date: NaiveDate, note: Option<&str>) -> Self {
+        Self {
+            note: note.map(ToString::to_string),
+            ..Self::new(method, amount, date.format("%Y-%m-%d").to_string())
+        }
+    }
+
+    #[must_use]
+    pub fn note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    #[must_use]
+    pub fn payment_id(mut self, payment_id: String) -> Self {
+        self.payment_id = Some(payment_id);
+        self
+    }
+
+    #[must_use]
+    pub fn shipping_info(mut self, shipping_info: ContactInformation) -> Self {
+        self.shipping_info = Some(shipping_info);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RecordPaymentResponse {
+    /// The ID for the payment record.
+    pub payment_id: String,
+}
+
+#[derive(Debug)]
+struct RecordPayment {
+    invoice_id: String,
+    body: RecordPaymentDto,
+}
+
+impl RecordPayment {
+    pub const fn new(invoice_id: String, body: RecordPaymentDto) -> Self {
+        Self { invoice_id, body }
+    }
+}
+
+impl Endpoint for RecordPayment {
+    type QueryParams = ();
+    type RequestBody = RecordPaymentDto;
+    type ResponseBody = RecordPaymentResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/invoicing/invoices/{}/payments",
+            self.invoice_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct RecordRefundDto {
+    /// The payment mode or method through which the invoicer can accept the payment.
+    pub method: PaymentMethod,
+
+    /// The amount refunded for the invoice.
+    pub amount: Money,
+
+    /// The date when the invoice was refunded, in Internet date format. For example, yyyy-MM-dd.
+    pub refund_date: String,
+
+    /// A note associated with an external refund.
+    pub note: Option<String>,
+}
+
+impl RecordRefundDto {
+    #[must_use]
+    pub const fn new(method: PaymentMethod, amount: Money, date: String) -> Self {
+        Self {
+            method,
+            amount,
+            refund_date: date,
+            note: None,
+        }
+    }
+
+    /// A refund on the given date, formatted as PayPal expects it.
+    fn on_date(method: PaymentMethod, amount: Money, date: NaiveDate, note: Option<&str>) -> Self {
+        Self {
+            note: note.map(ToString::to_string),
+            ..Self::new(method, amount, date.format("%Y-%m-%d").to_string())
+        }
+    }
+
+    #[must_use]
+    pub fn note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RecordRefundResponse {
+    /// The ID for the refund record.
+    pub refund_id: String,
+}
+
+#[derive(Debug)]
+struct RecordRefund {
+    invoice_id: String,
+    body: RecordRefundDto,
+}
+
+impl RecordRefund {
+    pub const fn new(invoice_id: String, body: RecordRefundDto) -> Self {
+        Self { invoice_id, body }
+    }
+}
+
+impl Endpoint for RecordRefund {
+    type QueryParams = ();
+    type RequestBody = RecordRefundDto;
+    type ResponseBody = RecordRefundResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/invoicing/invoices/{}/refunds", self.invoice_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct DeleteExternalPayment {
+    invoice_id: String,
+    transaction_id: String,
+}
+
+impl DeleteExternalPayment {
+    pub const fn new(invoice_id: String, transaction_id: String) -> Self {
+        Self {
+            invoice_id,
+            transaction_id,
+        }
+    }
+}
+
+impl Endpoint for DeleteExternalPayment {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/invoicing/invoices/{}/payments/{}",
+            self.invoice_id, self.transaction_id
+        ))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::DELETE
+    }
+}
+
+#[derive(Debug)]
+struct DeleteExternalRefund {
+    invoice_id: String,
+    transaction_id: String,
+}
+
+impl DeleteExternalRefund {
+    pub const fn new(invoice_id: String, transaction_id: String) -> Self {
+        Self {
+            invoice_id,
+            transaction_id,
+        }
+    }
+}
+
+impl Endpoint for DeleteExternalRefund {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/invoicing/invoices/{}/refunds/{}",
+            self.invoice_id, self.transaction_id
+        ))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::DELETE
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GenerateNextInvoiceNumberResponse {
+    /// The invoice number. If you omit this value, the default is the auto-incremented number
+    /// from the last number.
+    pub invoice_number: String,
+}
+
+#[derive(Debug)]
+struct GenerateNextInvoiceNumber;
+
+impl Endpoint for GenerateNextInvoiceNumber {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = GenerateNextInvoiceNumberResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v2/invoicing/generate-next-invoice-number")
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::enums::payment_term_type::PaymentTermType;
+    use crate::resources::item::Item;
+    use crate::resources::recipient_info::BillingInfo;
+
+    #[test]
+    fn create_invoice_request_body() {
+        let usd = |value: &str| Money::new(CurrencyCode::UnitedStatesDollar, value.to_string());
+        let order_items = vec![
+            Item::new("T-Shirt".to_string(), usd("20.00"), "2".to_string())
+                .description("Blue, size M".to_string()),
+        ];
+
+        let dto = CreateInvoiceDto::new(
+            InvoiceDetail::new(CurrencyCode::UnitedStatesDollar)
+                .invoice_number("#123".to_string())
+                .invoice_date(NaiveDate::from_ymd_opt(2023, 4, 1).unwrap())
+                .payment_term(InvoicePaymentTerm {
+                    term_type: Some(PaymentTermType::Net10),
+                    due_date: None,
+                }),
+        )
+        .primary_recipient(RecipientInfo {
+            billing_info: Some(BillingInfo {
+                email_address: Some("bill-me@example.com".to_string()),
+                ..Default::default()
+            }),
+            shipping_info: None,
+        })
+        .items(order_items)
+        .item(InvoiceItem::new(
+            "Shipping".to_string(),
+            "1".to_string(),
+            usd("5.00"),
+        ));
+        let endpoint = CreateInvoice::new(dto);
+
+        assert_eq!(
+            endpoint.headers().prefer.as_deref(),
+            Some("return=representation")
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "detail": {
+                    "currency_code": "USD",
+                    "invoice_number": "#123",
+                    "invoice_date": "2023-04-01",
+                    "payment_term": {"term_type": "NET_10"}
+                },
+                "primary_recipients": [
+                    {"billing_info": {"email_address": "bill-me@example.com"}}
+                ],
+                "items": [
+                    {
+                        "name": "T-Shirt",
+                        "description": "Blue, size M",
+                        "quantity": "2",
+                        "unit_amount": {"currency_code": "USD", "value": "20.00"}
+                    },
+                    {
+                        "name": "Shipping",
+                        "quantity": "1",
+                        "unit_amount": {"currency_code": "USD", "value": "5.00"}
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn show_invoice_payments_and_refunds() {
+        let invoice: InvoiceDetails = serde_json::from_str(
+            r#"{
+                "id": "INV2-Z56S-5LLA-Q52L-CPZ5",
+                "status": "PARTIALLY_REFUNDED",
+                "detail": {"currency_code": "USD", "invoice_number": "0042"},
+                "amount": {"currency_code": "USD", "value": "74.21"},
+                "due_amount": {"currency_code": "USD", "value": "0.00"},
+                "gratuity": {"currency_code": "USD", "value": "5.00"},
+                "payments": {
+                    "paid_amount": {"currency_code": "USD", "value": "79.21"},
+                    "transactions": [
+                        {
+                            "payment_id": "EXTR-86F38350LX4353815",
+                            "type": "EXTERNAL",
+                            "method": "CASH",
+                            "payment_date": "2018-05-01",
+                            "amount": {"currency_code": "USD", "value": "79.21"}
+                        }
+                    ]
+                },
+                "refunds": {
+                    "refund_amount": {"currency_code": "USD", "value": "10.00"},
+                    "transactions": [
+                        {
+                            "refund_id": "EXTR-2LG703375E477444T",
+                            "type": "EXTERNAL",
+                            "method": "BANK_TRANSFER",
+                            "refund_date": "2018-05-21",
+                            "amount": {"currency_code": "USD", "value": "10.00"}
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(invoice.status, Some(InvoiceStatus::PartiallyRefunded));
+        assert_eq!(invoice.gratuity.unwrap().value, "5.00");
+
+        let payments = invoice.payments.unwrap();
+        let payment = &payments.transactions.as_ref().unwrap()[0];
+        assert_eq!(payment.payment_type, Some(InvoicePaymentType::External));
+        assert_eq!(payment.method, PaymentMethod::Cash);
+
+        let refunds = invoice.refunds.unwrap();
+        let refund = &refunds.transactions.as_ref().unwrap()[0];
+        assert_eq!(refund.refund_id.as_deref(), Some("EXTR-2LG703375E477444T"));
+        assert_eq!(refund.method, PaymentMethod::BankTransfer);
+    }
+
+    #[test]
+    fn update_invoice_query_and_body() {
+        let invoice = InvoiceDetails {
+            id: Some("INV2-Z56S-5LLA-Q52L-CPZ5".to_string()),
+            detail: InvoiceDetail::new(CurrencyCode::UnitedStatesDollar)
+                .memo("Paid late".to_string()),
+            ..Default::default()
+        };
+        let endpoint = UpdateInvoice::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            invoice,
+            UpdateInvoiceQuery {
+                send_to_recipient: false,
+                send_to_invoicer: true,
+            },
+        );
+
+        assert_eq!(endpoint.request_method(), Method::PUT);
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "send_to_recipient=false&send_to_invoicer=true"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "id": "INV2-Z56S-5LLA-Q52L-CPZ5",
+                "detail": {"currency_code": "USD", "memo": "Paid late"}
+            })
+        );
+    }
+
+    #[test]
+    fn remind_invoice_request_body() {
+        let endpoint = RemindInvoice::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            InvoiceNotificationDto::new()
+                .subject("Reminder: Payment due for the invoice #ABC-123".to_string())
+                .note("Please pay before the due date to avoid incurring late payment charges which will be adjusted in the next bill generated.".to_string())
+                .send_to_invoicer(true)
+                .additional_recipient("customer-a@example.com".to_string()),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/remind"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "subject": "Reminder: Payment due for the invoice #ABC-123",
+                "note": "Please pay before the due date to avoid incurring late payment charges which will be adjusted in the next bill generated.",
+                "send_to_invoicer": true,
+                "additional_recipients": ["customer-a@example.com"]
+            })
+        );
+    }
+
+    #[test]
+    fn invoice_is_overdue() {
+        let today = NaiveDate::from_ymd_opt(2023, 5, 2).unwrap();
+        let mut invoice = InvoiceDetails {
+            status: Some(InvoiceStatus::Sent),
+            detail: InvoiceDetail::new(CurrencyCode::UnitedStatesDollar).payment_term(
+                InvoicePaymentTerm {
+                    term_type: None,
+                    due_date: Some("2023-05-01".to_string()),
+                },
+            ),
+            ..Default::default()
+        };
+        assert!(invoice.is_overdue(today));
+        assert!(!invoice.is_overdue(NaiveDate::from_ymd_opt(2023, 5, 1).unwrap()));
+
+        invoice.status = Some(InvoiceStatus::Paid);
+        assert!(!invoice.is_overdue(today));
+    }
+
+    #[test]
+    fn cancel_invoice_request_body() {
+        let endpoint = CancelInvoice::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            CancelInvoiceDto::new()
+                .subject("Invoice Cancelled".to_string())
+                .note("Cancelling the invoice".to_string())
+                .send_to_invoicer(true)
+                .send_to_recipient(true),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/cancel"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "subject": "Invoice Cancelled",
+                "note": "Cancelling the invoice",
+                "send_to_invoicer": true,
+                "send_to_recipient": true
+            })
+        );
+    }
+
+    #[test]
+    fn record_payment_request_body() {
+        let endpoint = RecordPayment::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            RecordPaymentDto::new(
+                PaymentMethod::Check,
+                Money::new(CurrencyCode::UnitedStatesDollar, "10.00".to_string()),
+                "2018-05-01".to_string(),
+            )
+            .note("Check #1234".to_string()),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/payments"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "method": "CHECK",
+                "amount": {"currency_code": "USD", "value": "10.00"},
+                "payment_date": "2018-05-01",
+                "note": "Check #1234"
+            })
+        );
+    }
+
+    #[test]
+    fn record_refund_request_body() {
+        let endpoint = RecordRefund::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            RecordRefundDto::new(
+                PaymentMethod::BankTransfer,
+                Money::new(CurrencyCode::UnitedStatesDollar, "5.00".to_string()),
+                "2018-05-21".to_string(),
+            ),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/refunds"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "method": "BANK_TRANSFER",
+                "amount": {"currency_code": "USD", "value": "5.00"},
+                "refund_date": "2018-05-21"
+            })
+        );
+    }
+
+    #[test]
+    fn mark_as_paid_and_refunded_request_bodies() {
+        let date = NaiveDate::from_ymd_opt(2018, 5, 1).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(RecordPaymentDto::on_date(
+                PaymentMethod::Cash,
+                Money::new(CurrencyCode::UnitedStatesDollar, "10.00".to_string()),
+                date,
+                Some("Paid at the counter"),
+            ))
+            .unwrap(),
+            serde_json::json!({
+                "method": "CASH",
+                "amount": {"currency_code": "USD", "value": "10.00"},
+                "payment_date": "2018-05-01",
+                "note": "Paid at the counter"
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(RecordRefundDto::on_date(
+                PaymentMethod::BankTransfer,
+                Money::new(CurrencyCode::UnitedStatesDollar, "5.00".to_string()),
+                date,
+                None,
+            ))
+            .unwrap(),
+            serde_json::json!({
+                "method": "BANK_TRANSFER",
+                "amount": {"currency_code": "USD", "value": "5.00"},
+                "refund_date": "2018-05-01"
+            })
+        );
+    }
+
+    #[test]
+    fn delete_invoice_request() {
+        let endpoint = DeleteInvoice::new("INV2-Z56S-5LLA-Q52L-CPZ5".to_string());
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5"
+        );
+        assert_eq!(endpoint.request_method(), Method::DELETE);
+        assert!(endpoint.request_body().is_none());
+    }
+
+    #[test]
+    fn delete_external_transaction_paths() {
+        let payment = DeleteExternalPayment::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            "EXTR-86F38350LX4353815".to_string(),
+        );
+        let refund = DeleteExternalRefund::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            "EXTR-2LG703375E477444T".to_string(),
+        );
+
+        assert_eq!(
+            payment.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/payments/EXTR-86F38350LX4353815"
+        );
+        assert_eq!(
+            refund.path(),
+            "v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/refunds/EXTR-2LG703375E477444T"
+        );
+        assert_eq!(payment.request_method(), Method::DELETE);
+        assert_eq!(refund.request_method(), Method::DELETE);
+    }
+
+    #[test]
+    fn generate_next_invoice_number() {
+        let endpoint = GenerateNextInvoiceNumber;
+        assert_eq!(endpoint.path(), "v2/invoicing/generate-next-invoice-number");
+        assert_eq!(endpoint.request_method(), Method::POST);
+        assert!(endpoint.request_body().is_none());
+
+        let response = endpoint
+            .parse_response(r#"{"invoice_number": "ee0044"}"#)
+            .unwrap();
+        assert_eq!(response.invoice_number, "ee0044");
+    }
+
+    #[test]
+    fn generate_qr_code_decodes_image() {
+        let endpoint = GenerateQrCode::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5".to_string(),
+            GenerateQrCodeDto {
+                width: 400,
+                height: 400,
+                action: QrCodeAction::Pay,
+            },
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({"width": 400, "height": 400, "action": "pay"})
+        );
+
+        let png_signature = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        assert_eq!(
+            endpoint.parse_response("iVBORw0KGgo=").unwrap(),
+            png_signature
+        );
+        assert_eq!(
+            endpoint
+                .parse_response(
+                    "--b6f4e9c1\r\nContent-Type: text/plain\r\n\r\niVBORw0KGgo=\r\n--b6f4e9c1--\r\n"
+                )
+                .unwrap(),
+            png_signature
+        );
+        assert!(endpoint.parse_response("not an image").is_err());
+    }
+}
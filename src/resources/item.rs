@@ -29,6 +29,12 @@ pub struct Item {
 
     /// The item category type.
     pub category: Option<Category>,
+
+    /// The URL to the item being purchased. Visible to the buyer and used in buyer experiences.
+    pub url: Option<String>,
+
+    /// The URL of the item's image. File type and size restrictions apply.
+    pub image_url: Option<String>,
 }
 
 impl Item {
@@ -41,6 +47,44 @@ impl Item {
             description: None,
             sku: None,
             category: None,
+            url: None,
+            image_url: None,
         }
     }
+
+    #[must_use]
+    pub fn tax(mut self, tax: Money) -> Self {
+        self.tax = Some(tax);
+        self
+    }
+
+    #[must_use]
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    #[must_use]
+    pub fn sku(mut self, sku: String) -> Self {
+        self.sku = Some(sku);
+        self
+    }
+
+    #[must_use]
+    pub const fn category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    #[must_use]
+    pub fn url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    #[must_use]
+    pub fn image_url(mut self, image_url: String) -> Self {
+        self.image_url = Some(image_url);
+        self
+    }
 }
@@ -1,7 +1,9 @@
 use crate::resources::enums::http_method::HttpMethod;
+use crate::resources::enums::link_rel::LinkRel;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct LinkDescription {
     /// The complete target URL. To make the related call, combine the method with this URI Template-formatted link. For pre-processing,
     /// include the $, (, and ) characters. The href is the key HATEOAS component that links a completed call with a subsequent call.
@@ -13,3 +15,26 @@ pub struct LinkDescription {
     /// The HTTP method. If present, use this method to make a request to the target URL. If absent, the default method is GET.
     pub method: Option<HttpMethod>,
 }
+
+/// Looks up the HATEOAS links of a response, instead of iterating `links` and matching `rel`
+/// values by hand.
+pub trait HateoasLinks {
+    /// All HATEOAS links of the response. Empty if the response has none.
+    fn hateoas_links(&self) -> &[LinkDescription];
+
+    /// Finds the link with the given relation type.
+    fn link(&self, rel: LinkRel) -> Option<&LinkDescription> {
+        self.hateoas_links()
+            .iter()
+            .find(|link| link.rel == rel.as_str())
+    }
+
+    /// The URL to which the buyer has to be redirected to approve the payment. PayPal returns it
+    /// as `approve`, or as `payer-action` if the order was created with a payment source.
+    fn approve_url(&self) -> Option<Url> {
+        let link = self
+            .link(LinkRel::Approve)
+            .or_else(|| self.link(LinkRel::PayerAction))?;
+        Url::parse(&link.href).ok()
+    }
+}
@@ -4,19 +4,32 @@ pub use {
     address_details::*,
     address_portable::*,
     amount_breakdown::*,
+    amount_summary_detail::*,
     amount_with_breakdown::*,
     authorization_status_details::*,
     authorization_with_additional_data::*,
+    billing_agreements::*,
+    billing_cycle::*,
+    billing_plans::*,
     capture::*,
     capture_status_details::*,
     card_address_portable::*,
+    card_authentication::*,
     card_response::*,
     create_webhook_event_type::*,
     date_no_time::*,
+    disputes::*,
     email::*,
     enums::*,
     enums::*,
+    evidence::*,
     exchange_rate::*,
+    experience_context::*,
+    invoice_item::*,
+    invoice_payment_term::*,
+    invoice_templates::*,
+    invoicer_info::*,
+    invoices::*,
     item::*,
     link_description::*,
     money::*,
@@ -25,23 +38,36 @@ pub use {
     network_transaction_reference::*,
     order::*,
     order_application_context::*,
+    order_payment_source::*,
+    page::*,
+    partial_payment::*,
+    partner_referrals::*,
     patch::*,
+    pay_upon_invoice::*,
     payee::*,
     payee_base::*,
     payer::*,
     payment_collection::*,
     payment_instruction::*,
-    payment_method::*,
+    payment_preferences::*,
     payment_source::*,
     payment_source_response::*,
     payments::*,
+    payout_currency::*,
+    payout_item_error::*,
+    payouts::*,
     paypal_payment_source_response::*,
+    phone_detail::*,
     phone_with_type::*,
     phone_with_type_phone::*,
     platform_fee::*,
+    pricing_scheme::*,
     processor_response::*,
+    products::*,
     purchase_unit::*,
     purchase_unit_request::*,
+    recipient_info::*,
+    referenced_payouts::*,
     refund::*,
     refund_status_details::*,
     seller_payable_breakdown::*,
@@ -53,28 +79,55 @@ pub use {
     shipping_option::*,
     show_webhook_event_type::*,
     stored_payment_source::*,
+    subscriber::*,
+    subscription_application_context::*,
+    subscription_billing_info::*,
+    subscription_transaction::*,
+    subscriptions::*,
     tax_info::*,
+    taxes::*,
     token::*,
+    tracker::*,
     user_info::*,
+    vault::*,
+    wallet_payment_source::*,
+    webhook_event::*,
     webhooks::*,
 };
 
+// The invoicing `enums::PaymentMethod` shares its name with the order `PaymentMethod` object, which
+// keeps the crate-level name. The enum is available as `enums::PaymentMethod`.
+pub use payment_method::PaymentMethod;
+
 pub mod address;
 pub mod address_details;
 pub mod address_portable;
 pub mod amount_breakdown;
+pub mod amount_summary_detail;
 pub mod amount_with_breakdown;
 pub mod authorization_status_details;
 pub mod authorization_with_additional_data;
+pub mod billing_agreements;
+pub mod billing_cycle;
+pub mod billing_plans;
 pub mod capture;
 pub mod capture_status_details;
 pub mod card_address_portable;
+pub mod card_authentication;
 pub mod card_response;
 pub mod create_webhook_event_type;
 pub mod date_no_time;
+pub mod disputes;
 pub mod email;
 pub mod enums;
+pub mod evidence;
 pub mod exchange_rate;
+pub mod experience_context;
+pub mod invoice_item;
+pub mod invoice_payment_term;
+pub mod invoice_templates;
+pub mod invoicer_info;
+pub mod invoices;
 pub mod item;
 pub mod link_description;
 pub mod money;
@@ -83,23 +136,37 @@ pub mod net_amount_breakdown;
 pub mod network_transaction_reference;
 pub mod order;
 pub mod order_application_context;
+pub mod order_payment_source;
+pub mod page;
+pub mod partial_payment;
+pub mod partner_referrals;
 pub mod patch;
+pub mod pay_upon_invoice;
 pub mod payee;
 pub mod payee_base;
 pub mod payer;
 pub mod payment_collection;
 pub mod payment_instruction;
 pub mod payment_method;
+pub mod payment_preferences;
 pub mod payment_source;
 pub mod payment_source_response;
 pub mod payments;
+pub mod payout_currency;
+pub mod payout_item_error;
+pub mod payouts;
 pub mod paypal_payment_source_response;
+pub mod phone_detail;
 pub mod phone_with_type;
 pub mod phone_with_type_phone;
 pub mod platform_fee;
+pub mod pricing_scheme;
 pub mod processor_response;
+pub mod products;
 pub mod purchase_unit;
 pub mod purchase_unit_request;
+pub mod recipient_info;
+pub mod referenced_payouts;
 pub mod refund;
 pub mod refund_status_details;
 pub mod seller_payable_breakdown;
@@ -111,7 +178,17 @@ pub mod shipping_detail_name;
 pub mod shipping_option;
 pub mod show_webhook_event_type;
 pub mod stored_payment_source;
+pub mod subscriber;
+pub mod subscription_application_context;
+pub mod subscription_billing_info;
+pub mod subscription_transaction;
+pub mod subscriptions;
 pub mod tax_info;
+pub mod taxes;
 pub mod token;
+pub mod tracker;
 pub mod user_info;
+pub mod vault;
+pub mod wallet_payment_source;
+pub mod webhook_event;
 pub mod webhooks;
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,17 +8,30 @@ use serde_with::skip_serializing_none;
 use crate::client::endpoint::{EmptyResponseBody, Endpoint};
 use crate::client::error::PayPalError;
 use crate::client::paypal::Client;
+use crate::resources::amount_with_breakdown::AmountWithBreakdown;
+use crate::resources::authorization_with_additional_data::AuthorizationWithAdditionalData;
+use crate::resources::capture::Capture;
+use crate::resources::enums::capture_status::CaptureStatus;
+use crate::resources::enums::op::Op;
 use crate::resources::enums::order_intent::OrderIntent;
 use crate::resources::enums::order_status::OrderStatus;
+use crate::resources::enums::payment_status::PaymentStatus;
 use crate::resources::enums::processing_instruction::ProcessingInstruction;
-use crate::resources::link_description::LinkDescription;
+use crate::resources::enums::tracker_carrier::TrackerCarrier;
+use crate::resources::enums::tracker_status::TrackerStatus;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
 use crate::resources::order_application_context::OrderApplicationContext;
-use crate::resources::patch::Patch;
+use crate::resources::order_payment_source::OrderPaymentSource;
+use crate::resources::patch::{Patch, PatchValue};
 use crate::resources::payer::Payer;
 use crate::resources::payment_source::PaymentSource;
 use crate::resources::payment_source_response::PaymentSourceResponse;
+use crate::resources::payments::Payment;
+use crate::resources::platform_fee::PlatformFee;
 use crate::resources::purchase_unit::PurchaseUnit;
 use crate::resources::purchase_unit_request::PurchaseUnitRequest;
+use crate::resources::shipping_detail::ShippingDetail;
+use crate::resources::tracker::TrackerItem;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -67,9 +81,70 @@ impl Order {
         client.post(&CreateOrder::new(dto)).await
     }
 
+    /// Shows details for an order, by ID. Use this to poll the status of an order after the buyer
+    /// approved it.
+    pub async fn show(client: &Client, id: &str) -> Result<Order, PayPalError> {
+        client.get(&ShowOrderDetails::new(id.to_string())).await
+    }
+
     /// Shows details for an order, by ID.
+    #[deprecated(note = "Use `Order::show` instead")]
     pub async fn show_details(client: &Client, id: &str) -> Result<Order, PayPalError> {
-        client.get(&ShowOrderDetails::new(id.to_string())).await
+        Self::show(client, id).await
+    }
+
+    /// Shows only the status of an order, by ID.
+    pub async fn status(client: &Client, id: &str) -> Result<OrderStatus, PayPalError> {
+        Self::show(client, id).await?.status.ok_or_else(|| {
+            PayPalError::LibraryError(format!("Order {id} has no status in the response"))
+        })
+    }
+
+    /// Voids an authorized order. PayPal does not offer a void endpoint for orders, so this voids
+    /// every authorization of the order that has not been captured, voided, denied or expired yet.
+    pub async fn void(client: &Client, id: &str) -> Result<(), PayPalError> {
+        let order = Self::show(client, id).await?;
+
+        for authorization_id in order.voidable_authorization_ids() {
+            Payment::void_authorized(client, authorization_id.to_string()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The IDs of the authorizations of all purchase units that can still be voided, that is, that
+    /// have not been captured, voided, denied or expired yet.
+    pub fn voidable_authorization_ids(&self) -> Vec<&str> {
+        self.purchase_units
+            .iter()
+            .flatten()
+            .filter_map(|purchase_unit| purchase_unit.payments.as_ref()?.authorizations.as_ref())
+            .flatten()
+            .filter(|authorization| {
+                !matches!(
+                    authorization.status,
+                    Some(
+                        PaymentStatus::Captured
+                            | PaymentStatus::Denied
+                            | PaymentStatus::Expired
+                            | PaymentStatus::Voided
+                    )
+                )
+            })
+            .filter_map(|authorization| authorization.id.as_deref())
+            .collect()
+    }
+
+    /// The PayPal-assigned ID of the payer, available after the buyer approved the order. The ID
+    /// is needed to associate vaulted payment sources and billing agreements with the buyer.
+    ///
+    /// Taken from the PayPal payment source, falling back to the deprecated `payer` object.
+    #[allow(deprecated)]
+    pub fn payer_id(&self) -> Option<&str> {
+        self.payment_source
+            .as_ref()
+            .and_then(|payment_source| payment_source.paypal.as_ref()?.account_id.as_deref())
+            .or_else(|| self.payer.as_ref()?.payer_id.as_deref())
     }
 
     /// Updates an order with a CREATED or APPROVED status. You cannot update an order with the COMPLETED status.
@@ -82,6 +157,18 @@ impl Order {
         Ok(())
     }
 
+    /// Adds a platform fee to the purchase unit with the given reference ID of an order.
+    pub async fn add_platform_fee(
+        client: &Client,
+        id: &str,
+        purchase_unit_reference_id: &str,
+        fee: PlatformFee,
+    ) -> Result<(), PayPalError> {
+        let patch = OrderPatch::add_platform_fee(purchase_unit_reference_id, fee);
+
+        Self::patch(client, id, vec![patch].into()).await
+    }
+
     /// Authorizes payment for an order. To successfully authorize payment for an order, the buyer
     /// must first approve the order or a valid payment_source must be provided in the request.
     /// A buyer can approve the order upon being redirected to the rel:approve URL that was returned
@@ -90,11 +177,66 @@ impl Order {
         client: &Client,
         id: &str,
     ) -> Result<AuthorizePaymentForOrderResponse, PayPalError> {
+        Self::authorize(client, id, None).await
+    }
+
+    /// Authorizes payment for an order, optionally with a payment source for orders the buyer has
+    /// not approved. See [`Order::authorize_payment`].
+    pub async fn authorize(
+        client: &Client,
+        id: &str,
+        payment_source: Option<PaymentSource>,
+    ) -> Result<AuthorizePaymentForOrderResponse, PayPalError> {
+        client
+            .post(&AuthorizePaymentForOrder::new(
+                id.to_string(),
+                AuthorizeOrderDto { payment_source },
+            ))
+            .await
+    }
+
+    /// Confirms the payment source of an order on behalf of the buyer, for payment sources that do
+    /// not redirect the buyer through the JavaScript SDK, like cards and alternative payment
+    /// methods. If the buyer has to approve the payment, the response contains a `payer-action`
+    /// link.
+    pub async fn confirm_payment_source(
+        client: &Client,
+        id: &str,
+        dto: ConfirmPaymentSourceDto,
+    ) -> Result<Order, PayPalError> {
         client
-            .post(&AuthorizePaymentForOrder::new(id.to_string()))
+            .post(&ConfirmPaymentSource::new(id.to_string(), dto))
             .await
     }
 
+    /// Adds tracking information for a shipment to a captured order. Tracking information
+    /// qualifies the order for PayPal's seller protection.
+    pub async fn add_tracking(
+        client: &Client,
+        id: &str,
+        dto: AddTrackerDto,
+    ) -> Result<Order, PayPalError> {
+        client.post(&AddTracker::new(id.to_string(), dto)).await
+    }
+
+    /// Updates a shipment tracker of an order, for example to mark the shipment as delivered or
+    /// to correct the tracking number. Build the operations with [`TrackerPatch`].
+    pub async fn update_tracking(
+        client: &Client,
+        id: &str,
+        tracker_id: &str,
+        patch: Vec<Patch>,
+    ) -> Result<(), PayPalError> {
+        client
+            .patch(&UpdateTracker::new(
+                id.to_string(),
+                tracker_id.to_string(),
+                patch,
+            ))
+            .await?;
+        Ok(())
+    }
+
     /// Captures payment for an order. To successfully capture payment for an order,
     /// the buyer must first approve the order or a valid payment_source must be provided in the
     /// request. A buyer can approve the order upon being redirected to the rel:approve URL that
@@ -105,14 +247,20 @@ impl Order {
         payment_source: Option<PaymentSource>,
     ) -> Result<CapturePaymentForOrderResponse, PayPalError> {
         client
-            .post(&CapturePaymentForOrder {
-                order_id: id.to_string(),
-                payment_source,
-            })
+            .post(&CapturePaymentForOrder::new(
+                id.to_string(),
+                CaptureOrderDto { payment_source },
+            ))
             .await
     }
 }
 
+impl HateoasLinks for Order {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Debug, Serialize)]
 pub struct CreateOrderDto {
@@ -128,6 +276,7 @@ pub struct CreateOrderDto {
     pub intent: OrderIntent,
 
     /// The customer who approves and pays for the order. The customer is also known as the payer.
+    /// Known buyer information, such as the email address, pre-fills the PayPal checkout.
     pub payer: Option<Payer>,
 
     /// An array of purchase units. Each purchase unit establishes a contract between a payer and the payee. Each purchase unit represents
@@ -136,6 +285,41 @@ pub struct CreateOrderDto {
 
     /// Customize the payer experience during the approval process for the payment with PayPal.
     pub application_context: Option<OrderApplicationContext>,
+
+    /// The payment source of the order. Set `paypal.experience_context` to customize the payer
+    /// experience instead of the deprecated `application_context`.
+    pub payment_source: Option<OrderPaymentSource>,
+}
+
+impl CreateOrderDto {
+    #[must_use]
+    pub const fn new(intent: OrderIntent, purchase_units: Vec<PurchaseUnitRequest>) -> Self {
+        Self {
+            intent,
+            payer: None,
+            purchase_units,
+            application_context: None,
+            payment_source: None,
+        }
+    }
+
+    #[must_use]
+    pub fn payer(mut self, payer: Payer) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+
+    #[must_use]
+    pub fn application_context(mut self, application_context: OrderApplicationContext) -> Self {
+        self.application_context = Some(application_context);
+        self
+    }
+
+    #[must_use]
+    pub fn payment_source(mut self, payment_source: OrderPaymentSource) -> Self {
+        self.payment_source = Some(payment_source);
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -194,6 +378,74 @@ pub struct PatchOrderDto {
     pub patch: Vec<Patch>,
 }
 
+impl From<Vec<Patch>> for PatchOrderDto {
+    fn from(patch: Vec<Patch>) -> Self {
+        Self { patch }
+    }
+}
+
+/// Typed patch operations for [`Order::patch`]. Purchase units are addressed by their reference
+/// ID, which is `default` for orders with a single purchase unit created without one.
+pub struct OrderPatch;
+
+impl OrderPatch {
+    /// Replaces the amount of a purchase unit, for example after the shipping costs changed.
+    pub fn replace_amount(reference_id: &str, amount: AmountWithBreakdown) -> Patch {
+        Patch::new(Op::Replace)
+            .path(Self::purchase_unit_path(reference_id, "amount"))
+            .value(PatchValue::AmountWithBreakdown(amount))
+    }
+
+    /// Adds shipping details to a purchase unit that has none yet.
+    pub fn add_shipping(reference_id: &str, shipping: ShippingDetail) -> Patch {
+        Patch::new(Op::Add)
+            .path(Self::purchase_unit_path(reference_id, "shipping"))
+            .value(PatchValue::ShippingDetail(shipping))
+    }
+
+    /// Replaces the shipping details of a purchase unit.
+    pub fn replace_shipping(reference_id: &str, shipping: ShippingDetail) -> Patch {
+        Patch::new(Op::Replace)
+            .path(Self::purchase_unit_path(reference_id, "shipping"))
+            .value(PatchValue::ShippingDetail(shipping))
+    }
+
+    /// Replaces the description of a purchase unit.
+    pub fn replace_description(reference_id: &str, description: String) -> Patch {
+        Patch::new(Op::Replace)
+            .path(Self::purchase_unit_path(reference_id, "description"))
+            .value(PatchValue::String(description))
+    }
+
+    /// Replaces the custom ID of a purchase unit.
+    pub fn replace_custom_id(reference_id: &str, custom_id: String) -> Patch {
+        Patch::new(Op::Replace)
+            .path(Self::purchase_unit_path(reference_id, "custom_id"))
+            .value(PatchValue::String(custom_id))
+    }
+
+    /// Replaces the invoice ID of a purchase unit.
+    pub fn replace_invoice_id(reference_id: &str, invoice_id: String) -> Patch {
+        Patch::new(Op::Replace)
+            .path(Self::purchase_unit_path(reference_id, "invoice_id"))
+            .value(PatchValue::String(invoice_id))
+    }
+
+    /// Adds a platform fee to a purchase unit.
+    pub fn add_platform_fee(reference_id: &str, fee: PlatformFee) -> Patch {
+        Patch::new(Op::Add)
+            .path(Self::purchase_unit_path(
+                reference_id,
+                "payment_instruction/platform_fees/-",
+            ))
+            .value(PatchValue::PlatformFee(fee))
+    }
+
+    fn purchase_unit_path(reference_id: &str, attribute: &str) -> String {
+        format!("/purchase_units/@reference_id=='{reference_id}'/{attribute}")
+    }
+}
+
 type PatchOrderResponse = EmptyResponseBody;
 
 #[derive(Debug)]
@@ -226,6 +478,233 @@ impl Endpoint for PatchOrder {
     }
 }
 
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct ConfirmPaymentSourceDto {
+    /// The payment source to confirm.
+    pub payment_source: OrderPaymentSource,
+
+    /// The instruction to process an order.
+    pub processing_instruction: Option<ProcessingInstruction>,
+
+    /// Customize the payer experience during the approval process for the payment.
+    pub application_context: Option<OrderApplicationContext>,
+}
+
+impl ConfirmPaymentSourceDto {
+    #[must_use]
+    pub const fn new(payment_source: OrderPaymentSource) -> Self {
+        Self {
+            payment_source,
+            processing_instruction: None,
+            application_context: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn processing_instruction(
+        mut self,
+        processing_instruction: ProcessingInstruction,
+    ) -> Self {
+        self.processing_instruction = Some(processing_instruction);
+        self
+    }
+
+    #[must_use]
+    pub fn application_context(mut self, application_context: OrderApplicationContext) -> Self {
+        self.application_context = Some(application_context);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct ConfirmPaymentSource {
+    order_id: String,
+    body: ConfirmPaymentSourceDto,
+}
+
+impl ConfirmPaymentSource {
+    pub const fn new(order_id: String, body: ConfirmPaymentSourceDto) -> Self {
+        Self { order_id, body }
+    }
+}
+
+impl Endpoint for ConfirmPaymentSource {
+    type QueryParams = ();
+    type RequestBody = ConfirmPaymentSourceDto;
+    type ResponseBody = Order;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/checkout/orders/{}/confirm-payment-source",
+            self.order_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct AddTrackerDto {
+    /// The PayPal capture ID of the shipped items.
+    pub capture_id: String,
+
+    /// The tracking number for the shipment.
+    pub tracking_number: String,
+
+    /// The carrier for the shipment.
+    pub carrier: TrackerCarrier,
+
+    /// The name of the carrier if `carrier` is `OTHER`.
+    pub carrier_name_other: Option<String>,
+
+    /// Whether to send an email notification to the payer about the shipment.
+    pub notify_payer: Option<bool>,
+
+    /// The items that are part of the shipment.
+    pub items: Option<Vec<TrackerItem>>,
+}
+
+impl AddTrackerDto {
+    #[must_use]
+    pub const fn new(capture_id: String, tracking_number: String, carrier: TrackerCarrier) -> Self {
+        Self {
+            capture_id,
+            tracking_number,
+            carrier,
+            carrier_name_other: None,
+            notify_payer: None,
+            items: None,
+        }
+    }
+
+    #[must_use]
+    pub fn carrier_name_other(mut self, carrier_name_other: String) -> Self {
+        self.carrier_name_other = Some(carrier_name_other);
+        self
+    }
+
+    #[must_use]
+    pub const fn notify_payer(mut self, notify_payer: bool) -> Self {
+        self.notify_payer = Some(notify_payer);
+        self
+    }
+
+    #[must_use]
+    pub fn items(mut self, items: Vec<TrackerItem>) -> Self {
+        self.items = Some(items);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct AddTracker {
+    order_id: String,
+    body: AddTrackerDto,
+}
+
+impl AddTracker {
+    pub const fn new(order_id: String, body: AddTrackerDto) -> Self {
+        Self { order_id, body }
+    }
+}
+
+impl Endpoint for AddTracker {
+    type QueryParams = ();
+    type RequestBody = AddTrackerDto;
+    type ResponseBody = Order;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/checkout/orders/{}/track", self.order_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// Typed patch operations for [`Order::update_tracking`].
+pub struct TrackerPatch;
+
+impl TrackerPatch {
+    /// Replaces the status of the shipment.
+    pub fn replace_status(status: TrackerStatus) -> Patch {
+        Patch::new(Op::Replace)
+            .path("/status".to_string())
+            .value(PatchValue::String(status.as_str().to_string()))
+    }
+
+    /// Replaces the tracking number of the shipment.
+    pub fn replace_tracking_number(tracking_number: String) -> Patch {
+        Patch::new(Op::Replace)
+            .path("/tracking_number".to_string())
+            .value(PatchValue::String(tracking_number))
+    }
+
+    /// Replaces the carrier of the shipment.
+    pub fn replace_carrier(carrier: TrackerCarrier) -> Patch {
+        Patch::new(Op::Replace)
+            .path("/carrier".to_string())
+            .value(PatchValue::String(carrier.as_str().to_string()))
+    }
+
+    /// Replaces whether the payer is notified about the shipment.
+    pub fn replace_notify_payer(notify_payer: bool) -> Patch {
+        Patch::new(Op::Replace)
+            .path("/notify_payer".to_string())
+            .value(PatchValue::Boolean(notify_payer))
+    }
+}
+
+#[derive(Debug)]
+struct UpdateTracker {
+    order_id: String,
+    tracker_id: String,
+    body: Vec<Patch>,
+}
+
+impl UpdateTracker {
+    pub const fn new(order_id: String, tracker_id: String, body: Vec<Patch>) -> Self {
+        Self {
+            order_id,
+            tracker_id,
+            body,
+        }
+    }
+}
+
+impl Endpoint for UpdateTracker {
+    type QueryParams = ();
+    type RequestBody = Vec<Patch>;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/checkout/orders/{}/trackers/{}",
+            self.order_id, self.tracker_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::PATCH
+    }
+}
+
 /// Authorizes payment for an order. To successfully authorize payment for an order, the buyer must
 /// first approve the order or a valid payment_source must be provided in the request.
 /// A buyer can approve the order upon being redirected to the rel:approve URL that was returned in
@@ -234,14 +713,17 @@ impl Endpoint for PatchOrder {
 struct AuthorizePaymentForOrder {
     /// The ID of the order for which to authorize.
     order_id: String,
+    body: AuthorizeOrderDto,
 }
 
 impl AuthorizePaymentForOrder {
-    pub fn new(order_id: String) -> Self {
-        Self { order_id }
+    pub const fn new(order_id: String, body: AuthorizeOrderDto) -> Self {
+        Self { order_id, body }
     }
 }
 
+pub type AuthorizeOrderDto = CaptureOrderDto;
+
 #[skip_serializing_none]
 #[derive(Debug, Deserialize, Default)]
 pub struct AuthorizePaymentForOrderResponse {
@@ -280,26 +762,76 @@ pub struct AuthorizePaymentForOrderResponse {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl AuthorizePaymentForOrderResponse {
+    /// The ID of the first authorization of the first purchase unit.
+    pub fn first_authorization_id(&self) -> Option<&str> {
+        self.first_authorization()?.id.as_deref()
+    }
+
+    /// The expiry of the first authorization of the first purchase unit. An authorization is valid
+    /// for 29 days; delayed captures have to reauthorize the payment before it expires.
+    pub fn first_authorization_expiry(&self) -> Option<&DateTime<Utc>> {
+        self.first_authorization()?.expiration_time.as_ref()
+    }
+
+    fn first_authorization(&self) -> Option<&AuthorizationWithAdditionalData> {
+        self.purchase_units
+            .as_ref()?
+            .first()?
+            .payments
+            .as_ref()?
+            .authorizations
+            .as_ref()?
+            .first()
+    }
+}
+
+impl HateoasLinks for AuthorizePaymentForOrderResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
 impl Endpoint for AuthorizePaymentForOrder {
     type QueryParams = ();
-    type RequestBody = ();
+    type RequestBody = AuthorizeOrderDto;
     type ResponseBody = AuthorizePaymentForOrderResponse;
 
     fn path(&self) -> Cow<str> {
         Cow::Owned(format!("v2/checkout/orders/{}/authorize", self.order_id))
     }
 
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        // Approved orders are authorized without a request body.
+        self.body
+            .payment_source
+            .as_ref()
+            .map(|_payment_source| self.body.clone())
+    }
+
     fn request_method(&self) -> Method {
         Method::POST
     }
 }
 
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CaptureOrderDto {
+    /// The payment source definition. Only needed if the buyer has not approved the order.
+    pub payment_source: Option<PaymentSource>,
+}
+
+#[derive(Debug)]
 struct CapturePaymentForOrder {
     /// The ID of the order for which to capture.
     order_id: String,
+    body: CaptureOrderDto,
+}
 
-    /// The payment source definition
-    payment_source: Option<PaymentSource>,
+impl CapturePaymentForOrder {
+    pub const fn new(order_id: String, body: CaptureOrderDto) -> Self {
+        Self { order_id, body }
+    }
 }
 
 #[skip_serializing_none]
@@ -340,9 +872,49 @@ pub struct CapturePaymentForOrderResponse {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl CapturePaymentForOrderResponse {
+    /// The ID of the first capture of the first purchase unit.
+    pub fn first_capture_id(&self) -> Option<&str> {
+        Some(self.first_capture()?.id.as_str())
+    }
+
+    /// The status of the first capture of the first purchase unit. `None` if there is no capture
+    /// or its status is unknown.
+    pub fn first_capture_status(&self) -> Option<CaptureStatus> {
+        self.first_capture()?.status.parse().ok()
+    }
+
+    /// All captures of all purchase units, in order.
+    pub fn all_captures(&self) -> Vec<&Capture> {
+        self.purchase_units
+            .iter()
+            .flatten()
+            .filter_map(|purchase_unit| purchase_unit.payments.as_ref()?.captures.as_ref())
+            .flatten()
+            .collect()
+    }
+
+    fn first_capture(&self) -> Option<&Capture> {
+        self.purchase_units
+            .as_ref()?
+            .first()?
+            .payments
+            .as_ref()?
+            .captures
+            .as_ref()?
+            .first()
+    }
+}
+
+impl HateoasLinks for CapturePaymentForOrderResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
 impl Endpoint for CapturePaymentForOrder {
     type QueryParams = ();
-    type RequestBody = Option<PaymentSource>;
+    type RequestBody = CaptureOrderDto;
     type ResponseBody = CapturePaymentForOrderResponse;
 
     fn path(&self) -> Cow<str> {
@@ -350,10 +922,585 @@ impl Endpoint for CapturePaymentForOrder {
     }
 
     fn request_body(&self) -> Option<Self::RequestBody> {
-        Some(self.payment_source.clone())
+        // Approved orders are captured without a request body.
+        self.body
+            .payment_source
+            .as_ref()
+            .map(|_payment_source| self.body.clone())
     }
 
     fn request_method(&self) -> Method {
         Method::POST
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::resources::enums::currency_code::CurrencyCode;
+    use crate::resources::enums::landing_page::LandingPage;
+    use crate::resources::enums::link_rel::LinkRel;
+    use crate::resources::enums::payee_preferred::PayeePreferred;
+    use crate::resources::enums::shipping_preference::ShippingPreference;
+    use crate::resources::enums::token_type::TokenType;
+    use crate::resources::enums::user_action::UserAction;
+    use crate::resources::experience_context::ExperienceContext;
+    use crate::resources::money::Money;
+    use crate::resources::order_payment_source::PayPalWalletRequest;
+    use crate::resources::payee_base::PayeeBase;
+    use crate::resources::payment_collection::PaymentCollection;
+    use crate::resources::token::Token;
+
+    #[test]
+    fn capture_order_request_body() {
+        let endpoint = CapturePaymentForOrder::new(
+            "5O190127TN364715T".to_string(),
+            CaptureOrderDto {
+                payment_source: Some(PaymentSource {
+                    token: Token {
+                        id: "B-8MV64254WP9536944".to_string(),
+                        type_: TokenType::BillingAgreement,
+                    },
+                }),
+            },
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "payment_source": {
+                    "token": {"id": "B-8MV64254WP9536944", "type": "BILLING_AGREEMENT"}
+                }
+            })
+        );
+
+        let endpoint = CapturePaymentForOrder::new(
+            "5O190127TN364715T".to_string(),
+            CaptureOrderDto::default(),
+        );
+        assert!(endpoint.request_body().is_none());
+    }
+
+    #[test]
+    fn order_patch_operations() {
+        let patch = vec![
+            OrderPatch::replace_amount(
+                "default",
+                AmountWithBreakdown::new(CurrencyCode::Euro, "12.50".to_string()),
+            ),
+            OrderPatch::replace_invoice_id("default", "INV-1".to_string()),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(patch).unwrap(),
+            serde_json::json!([
+                {
+                    "op": "replace",
+                    "path": "/purchase_units/@reference_id=='default'/amount",
+                    "value": {"currency_code": "EUR", "value": "12.50"}
+                },
+                {
+                    "op": "replace",
+                    "path": "/purchase_units/@reference_id=='default'/invoice_id",
+                    "value": "INV-1"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn update_tracker_request() {
+        let endpoint = UpdateTracker::new(
+            "5O190127TN364715T".to_string(),
+            "8MC585209K746392H-443844607820".to_string(),
+            vec![
+                TrackerPatch::replace_status(TrackerStatus::Delivered),
+                TrackerPatch::replace_tracking_number("443844607821".to_string()),
+                TrackerPatch::replace_carrier(TrackerCarrier::Ups),
+                TrackerPatch::replace_notify_payer(true),
+            ],
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/checkout/orders/5O190127TN364715T/trackers/8MC585209K746392H-443844607820"
+        );
+        assert_eq!(endpoint.request_method(), Method::PATCH);
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!([
+                {"op": "replace", "path": "/status", "value": "DELIVERED"},
+                {"op": "replace", "path": "/tracking_number", "value": "443844607821"},
+                {"op": "replace", "path": "/carrier", "value": "UPS"},
+                {"op": "replace", "path": "/notify_payer", "value": true}
+            ])
+        );
+    }
+
+    #[test]
+    fn add_platform_fee_patch() {
+        let endpoint = PatchOrder::new(
+            "5O190127TN364715T".to_string(),
+            vec![OrderPatch::add_platform_fee(
+                "default",
+                PlatformFee::new(Money::new(
+                    CurrencyCode::UnitedStatesDollar,
+                    "2.00".to_string(),
+                ))
+                .payee(PayeeBase {
+                    merchant_id: Some("7MVKGTR4HGR6S".to_string()),
+                    ..Default::default()
+                }),
+            )]
+            .into(),
+        );
+
+        assert_eq!(endpoint.path(), "v2/checkout/orders/5O190127TN364715T");
+        assert_eq!(endpoint.request_method(), Method::PATCH);
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!([
+                {
+                    "op": "add",
+                    "path": "/purchase_units/@reference_id=='default'/payment_instruction/platform_fees/-",
+                    "value": {
+                        "amount": {"currency_code": "USD", "value": "2.00"},
+                        "payee": {"merchant_id": "7MVKGTR4HGR6S"}
+                    }
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn add_tracker_request() {
+        let endpoint = AddTracker::new(
+            "5O190127TN364715T".to_string(),
+            AddTrackerDto::new(
+                "8MC585209K746392H".to_string(),
+                "443844607820".to_string(),
+                TrackerCarrier::FedEx,
+            )
+            .notify_payer(false)
+            .items(vec![TrackerItem {
+                name: Some("T-Shirt".to_string()),
+                quantity: Some("1".to_string()),
+                sku: Some("sku02".to_string()),
+                url: Some("https://www.example.com/example".to_string()),
+                image_url: Some("https://www.example.com/example.jpg".to_string()),
+            }]),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/checkout/orders/5O190127TN364715T/track"
+        );
+        assert_eq!(endpoint.request_method(), Method::POST);
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "capture_id": "8MC585209K746392H",
+                "tracking_number": "443844607820",
+                "carrier": "FEDEX",
+                "notify_payer": false,
+                "items": [
+                    {
+                        "name": "T-Shirt",
+                        "sku": "sku02",
+                        "quantity": "1",
+                        "image_url": "https://www.example.com/example.jpg",
+                        "url": "https://www.example.com/example"
+                    }
+                ]
+            })
+        );
+
+        let dto = AddTrackerDto::new(
+            "8MC585209K746392H".to_string(),
+            "443844607820".to_string(),
+            TrackerCarrier::Other,
+        )
+        .carrier_name_other("Local Courier".to_string());
+        assert_eq!(
+            serde_json::to_value(dto).unwrap(),
+            serde_json::json!({
+                "capture_id": "8MC585209K746392H",
+                "tracking_number": "443844607820",
+                "carrier": "OTHER",
+                "carrier_name_other": "Local Courier"
+            })
+        );
+
+        assert_eq!(
+            serde_json::from_str::<TrackerCarrier>(r#""POSTNL""#).unwrap(),
+            TrackerCarrier::Unknown
+        );
+    }
+
+    #[test]
+    fn order_approve_url() {
+        let link = |rel: &str, href: &str| LinkDescription {
+            href: href.to_string(),
+            rel: rel.to_string(),
+            method: None,
+        };
+
+        let order = Order {
+            links: Some(vec![
+                link(
+                    "self",
+                    "https://api-m.paypal.com/v2/checkout/orders/5O190127TN364715T",
+                ),
+                link(
+                    "approve",
+                    "https://www.paypal.com/checkoutnow?token=5O190127TN364715T",
+                ),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            order.approve_url().unwrap().as_str(),
+            "https://www.paypal.com/checkoutnow?token=5O190127TN364715T"
+        );
+        assert!(order.link(LinkRel::SelfLink).is_some());
+        assert!(order.link(LinkRel::Capture).is_none());
+
+        let order = Order {
+            links: Some(vec![link(
+                "payer-action",
+                "https://www.paypal.com/checkoutnow?token=5O190127TN364715T",
+            )]),
+            ..Default::default()
+        };
+        assert!(order.approve_url().is_some());
+        assert!(Order::default().approve_url().is_none());
+    }
+
+    #[test]
+    fn create_order_request_body() {
+        let mut purchase_unit = PurchaseUnitRequest::new(AmountWithBreakdown::new(
+            CurrencyCode::Euro,
+            "10.00".to_string(),
+        ));
+        purchase_unit.reference_ids("default".to_string());
+
+        let dto = CreateOrderDto::new(OrderIntent::Capture, vec![purchase_unit]).payment_source(
+            OrderPaymentSource::new().paypal(
+                PayPalWalletRequest::new().experience_context(
+                    ExperienceContext::new()
+                        .user_action(UserAction::PayNow)
+                        .return_url("https://example.com/return".to_string()),
+                ),
+            ),
+        );
+
+        assert_eq!(
+            serde_json::to_value(dto).unwrap(),
+            serde_json::json!({
+                "intent": "CAPTURE",
+                "purchase_units": [{
+                    "reference_id": "default",
+                    "amount": {"currency_code": "EUR", "value": "10.00"},
+                    "items": []
+                }],
+                "payment_source": {
+                    "paypal": {
+                        "experience_context": {
+                            "user_action": "PAY_NOW",
+                            "return_url": "https://example.com/return"
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn confirm_payment_source_request() {
+        let endpoint = ConfirmPaymentSource::new(
+            "5O190127TN364715T".to_string(),
+            ConfirmPaymentSourceDto::new(
+                OrderPaymentSource::new().paypal(
+                    PayPalWalletRequest::new()
+                        .email_address("customer@example.com".to_string())
+                        .experience_context(
+                            ExperienceContext::new()
+                                .payment_method_preference(PayeePreferred::ImmediatePaymentRequired)
+                                .brand_name("EXAMPLE INC".to_string())
+                                .locale("en-US".to_string())
+                                .landing_page(LandingPage::Login)
+                                .shipping_preference(ShippingPreference::SetProvidedAddress)
+                                .user_action(UserAction::PayNow)
+                                .return_url("https://example.com/returnUrl".to_string())
+                                .cancel_url("https://example.com/cancelUrl".to_string()),
+                        ),
+                ),
+            )
+            .processing_instruction(ProcessingInstruction::NoInstruction),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/checkout/orders/5O190127TN364715T/confirm-payment-source"
+        );
+        assert_eq!(endpoint.request_method(), Method::POST);
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "payment_source": {
+                    "paypal": {
+                        "email_address": "customer@example.com",
+                        "experience_context": {
+                            "payment_method_preference": "IMMEDIATE_PAYMENT_REQUIRED",
+                            "brand_name": "EXAMPLE INC",
+                            "locale": "en-US",
+                            "landing_page": "LOGIN",
+                            "shipping_preference": "SET_PROVIDED_ADDRESS",
+                            "user_action": "PAY_NOW",
+                            "return_url": "https://example.com/returnUrl",
+                            "cancel_url": "https://example.com/cancelUrl"
+                        }
+                    }
+                },
+                "processing_instruction": "NO_INSTRUCTION"
+            })
+        );
+    }
+
+    #[test]
+    fn capture_response_helpers() {
+        let capture = |id: &str, status: &str| Capture {
+            id: id.to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        };
+        let purchase_unit = |captures| PurchaseUnit {
+            payments: Some(PaymentCollection {
+                captures: Some(captures),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let response = CapturePaymentForOrderResponse {
+            purchase_units: Some(vec![
+                purchase_unit(vec![capture("CAPTURE-1", "COMPLETED")]),
+                purchase_unit(vec![capture("CAPTURE-2", "PENDING")]),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(response.first_capture_id(), Some("CAPTURE-1"));
+        assert_eq!(
+            response.first_capture_status(),
+            Some(CaptureStatus::Completed)
+        );
+        assert_eq!(response.all_captures().len(), 2);
+        assert_eq!(
+            CapturePaymentForOrderResponse::default().first_capture_id(),
+            None
+        );
+
+        let response = CapturePaymentForOrderResponse {
+            purchase_units: Some(vec![purchase_unit(vec![capture("CAPTURE-3", "HELD")])]),
+            ..Default::default()
+        };
+        assert_eq!(response.first_capture_status(), None);
+    }
+
+    #[test]
+    fn voidable_authorization_ids() {
+        let order: Order = serde_json::from_str(
+            r#"{
+                "id": "5O190127TN364715T",
+                "intent": "AUTHORIZE",
+                "status": "COMPLETED",
+                "purchase_units": [
+                    {
+                        "reference_id": "d9f80740-38f0-11e8-b467-0ed5f89f718b",
+                        "amount": {"currency_code": "USD", "value": "100.00"},
+                        "payments": {
+                            "authorizations": [
+                                {
+                                    "id": "0AW2184448108334S",
+                                    "status": "CREATED",
+                                    "amount": {"currency_code": "USD", "value": "40.00"},
+                                    "expiration_time": "2018-05-08T21:37:48Z",
+                                    "create_time": "2018-04-09T21:37:48Z"
+                                },
+                                {
+                                    "id": "8PE41452XG9561903",
+                                    "status": "CAPTURED",
+                                    "amount": {"currency_code": "USD", "value": "30.00"}
+                                },
+                                {
+                                    "id": "3C679366HH908993F",
+                                    "status": "PARTIALLY_CAPTURED",
+                                    "amount": {"currency_code": "USD", "value": "30.00"}
+                                }
+                            ]
+                        }
+                    },
+                    {
+                        "reference_id": "a3c8e0c8-38f0-11e8-b467-0ed5f89f718b",
+                        "amount": {"currency_code": "USD", "value": "60.00"},
+                        "payments": {
+                            "authorizations": [
+                                {"id": "9VK32356BX5562826", "status": "VOIDED"},
+                                {"id": "1KV48370Y8234023U", "status": "DENIED"},
+                                {"id": "6DR965477U7140544", "status": "EXPIRED"},
+                                {"id": "7NW873794T343360M", "status": "PENDING"}
+                            ]
+                        }
+                    },
+                    {
+                        "reference_id": "default",
+                        "amount": {"currency_code": "USD", "value": "10.00"}
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            order.voidable_authorization_ids(),
+            [
+                "0AW2184448108334S",
+                "3C679366HH908993F",
+                "7NW873794T343360M"
+            ]
+        );
+        assert!(Order::default().voidable_authorization_ids().is_empty());
+    }
+
+    #[test]
+    fn authorize_order_response_helpers() {
+        let response: AuthorizePaymentForOrderResponse = serde_json::from_str(
+            r#"{
+                "id": "5O190127TN364715T",
+                "status": "COMPLETED",
+                "payment_source": {
+                    "paypal": {
+                        "name": {"given_name": "John", "surname": "Doe"},
+                        "email_address": "customer@example.com",
+                        "account_id": "QYR5Z8XDVJNXQ"
+                    }
+                },
+                "purchase_units": [
+                    {
+                        "reference_id": "d9f80740-38f0-11e8-b467-0ed5f89f718b",
+                        "shipping": {
+                            "address": {
+                                "address_line_1": "2211 N First Street",
+                                "address_line_2": "Building 17",
+                                "admin_area_2": "San Jose",
+                                "admin_area_1": "CA",
+                                "postal_code": "95131",
+                                "country_code": "US"
+                            }
+                        },
+                        "payments": {
+                            "authorizations": [
+                                {
+                                    "id": "0AW2184448108334S",
+                                    "status": "CREATED",
+                                    "amount": {"currency_code": "USD", "value": "100.00"},
+                                    "seller_protection": {
+                                        "status": "ELIGIBLE",
+                                        "dispute_categories": [
+                                            "ITEM_NOT_RECEIVED",
+                                            "UNAUTHORIZED_TRANSACTION"
+                                        ]
+                                    },
+                                    "expiration_time": "2018-05-08T21:37:48Z",
+                                    "create_time": "2018-04-09T21:37:48Z",
+                                    "update_time": "2018-04-09T21:37:48Z",
+                                    "links": [
+                                        {
+                                            "href": "https://api-m.paypal.com/v2/payments/authorizations/0AW2184448108334S",
+                                            "rel": "self",
+                                            "method": "GET"
+                                        },
+                                        {
+                                            "href": "https://api-m.paypal.com/v2/payments/authorizations/0AW2184448108334S/capture",
+                                            "rel": "capture",
+                                            "method": "POST"
+                                        }
+                                    ]
+                                }
+                            ]
+                        }
+                    }
+                ],
+                "payer": {
+                    "name": {"given_name": "John", "surname": "Doe"},
+                    "email_address": "customer@example.com",
+                    "payer_id": "QYR5Z8XDVJNXQ"
+                },
+                "links": [
+                    {
+                        "href": "https://api-m.paypal.com/v2/checkout/orders/5O190127TN364715T",
+                        "rel": "self",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.first_authorization_id(), Some("0AW2184448108334S"));
+        assert_eq!(
+            response.first_authorization_expiry(),
+            Some(&Utc.with_ymd_and_hms(2018, 5, 8, 21, 37, 48).unwrap())
+        );
+
+        let response = AuthorizePaymentForOrderResponse::default();
+        assert_eq!(response.first_authorization_id(), None);
+        assert_eq!(response.first_authorization_expiry(), None);
+    }
+
+    #[test]
+    fn payer_id_precedence() {
+        let parse = |json: &str| serde_json::from_str::<Order>(json).unwrap();
+
+        let order = parse(
+            r#"{
+                "id": "5O190127TN364715T",
+                "payment_source": {
+                    "paypal": {"email_address": "customer@example.com", "account_id": "QYR5Z8XDVJNXQ"}
+                }
+            }"#,
+        );
+        assert_eq!(order.payer_id(), Some("QYR5Z8XDVJNXQ"));
+
+        let order = parse(
+            r#"{
+                "id": "5O190127TN364715T",
+                "payer": {"email_address": "customer@example.com", "payer_id": "SXKW9AE5QW9E2"}
+            }"#,
+        );
+        assert_eq!(order.payer_id(), Some("SXKW9AE5QW9E2"));
+
+        let order = parse(
+            r#"{
+                "id": "5O190127TN364715T",
+                "payment_source": {"paypal": {"account_id": "QYR5Z8XDVJNXQ"}},
+                "payer": {"payer_id": "SXKW9AE5QW9E2"}
+            }"#,
+        );
+        assert_eq!(order.payer_id(), Some("QYR5Z8XDVJNXQ"));
+
+        let order = parse(
+            r#"{
+                "id": "5O190127TN364715T",
+                "payment_source": {"paypal": {"email_address": "customer@example.com"}},
+                "payer": {"payer_id": "SXKW9AE5QW9E2"}
+            }"#,
+        );
+        assert_eq!(order.payer_id(), Some("SXKW9AE5QW9E2"));
+
+        assert_eq!(Order::default().payer_id(), None);
+    }
+}
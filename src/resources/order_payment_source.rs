@@ -0,0 +1,385 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::card_address_portable::CardAddressPortable;
+use crate::resources::card_authentication::CardAttributes;
+use crate::resources::enums::card_verification_method::CardVerificationMethod;
+use crate::resources::enums::shipping_preference::ShippingPreference;
+use crate::resources::experience_context::ExperienceContext;
+use crate::resources::pay_upon_invoice::PayUponInvoiceRequest;
+use crate::resources::token::Token;
+use crate::resources::wallet_payment_source::{
+    ApplePayRequest, GooglePayRequest, VenmoWalletRequest,
+};
+
+/// The payment source to confirm for an order. Set exactly one of the payment sources.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OrderPaymentSource {
+    /// The payment card to use to fund the order.
+    pub card: Option<CardRequest>,
+
+    /// The tokenized payment source to fund the order.
+    pub token: Option<Token>,
+
+    /// The PayPal wallet to fund the order.
+    pub paypal: Option<PayPalWalletRequest>,
+
+    /// The Venmo wallet to fund the order.
+    pub venmo: Option<VenmoWalletRequest>,
+
+    /// Apple Pay, with the decrypted payment token.
+    pub apple_pay: Option<ApplePayRequest>,
+
+    /// Google Pay, with the decrypted payment token.
+    pub google_pay: Option<GooglePayRequest>,
+
+    /// Bancontact, used in Belgium.
+    pub bancontact: Option<AlternativePaymentMethod>,
+
+    /// BLIK, used in Poland.
+    pub blik: Option<AlternativePaymentMethod>,
+
+    /// eps, used in Austria.
+    pub eps: Option<AlternativePaymentMethod>,
+
+    /// giropay, used in Germany.
+    pub giropay: Option<AlternativePaymentMethod>,
+
+    /// iDEAL, used in the Netherlands.
+    pub ideal: Option<AlternativePaymentMethod>,
+
+    /// MyBank, used in Italy.
+    pub mybank: Option<AlternativePaymentMethod>,
+
+    /// Przelewy24, used in Poland.
+    pub p24: Option<AlternativePaymentMethod>,
+
+    /// Sofort, used in Europe.
+    pub sofort: Option<AlternativePaymentMethod>,
+
+    /// Pay Upon Invoice, used in Germany.
+    pub pay_upon_invoice: Option<PayUponInvoiceRequest>,
+}
+
+impl OrderPaymentSource {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn card(mut self, card: CardRequest) -> Self {
+        self.card = Some(card);
+        self
+    }
+
+    #[must_use]
+    pub fn token(mut self, token: Token) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    #[must_use]
+    pub fn paypal(mut self, paypal: PayPalWalletRequest) -> Self {
+        self.paypal = Some(paypal);
+        self
+    }
+
+    #[must_use]
+    pub fn venmo(mut self, venmo: VenmoWalletRequest) -> Self {
+        self.venmo = Some(venmo);
+        self
+    }
+
+    #[must_use]
+    pub fn apple_pay(mut self, apple_pay: ApplePayRequest) -> Self {
+        self.apple_pay = Some(apple_pay);
+        self
+    }
+
+    #[must_use]
+    pub fn google_pay(mut self, google_pay: GooglePayRequest) -> Self {
+        self.google_pay = Some(google_pay);
+        self
+    }
+
+    #[must_use]
+    pub fn bancontact(mut self, bancontact: AlternativePaymentMethod) -> Self {
+        self.bancontact = Some(bancontact);
+        self
+    }
+
+    #[must_use]
+    pub fn blik(mut self, blik: AlternativePaymentMethod) -> Self {
+        self.blik = Some(blik);
+        self
+    }
+
+    #[must_use]
+    pub fn eps(mut self, eps: AlternativePaymentMethod) -> Self {
+        self.eps = Some(eps);
+        self
+    }
+
+    #[must_use]
+    pub fn giropay(mut self, giropay: AlternativePaymentMethod) -> Self {
+        self.giropay = Some(giropay);
+        self
+    }
+
+    #[must_use]
+    pub fn ideal(mut self, ideal: AlternativePaymentMethod) -> Self {
+        self.ideal = Some(ideal);
+        self
+    }
+
+    #[must_use]
+    pub fn mybank(mut self, mybank: AlternativePaymentMethod) -> Self {
+        self.mybank = Some(mybank);
+        self
+    }
+
+    #[must_use]
+    pub fn p24(mut self, p24: AlternativePaymentMethod) -> Self {
+        self.p24 = Some(p24);
+        self
+    }
+
+    #[must_use]
+    pub fn sofort(mut self, sofort: AlternativePaymentMethod) -> Self {
+        self.sofort = Some(sofort);
+        self
+    }
+
+    #[must_use]
+    pub fn pay_upon_invoice(mut self, pay_upon_invoice: PayUponInvoiceRequest) -> Self {
+        self.pay_upon_invoice = Some(pay_upon_invoice);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CardRequest {
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+
+    /// The primary account number (PAN) for the payment card.
+    pub number: String,
+
+    /// The card expiration year and month, in Internet date format. For example, `2027-02`.
+    pub expiry: String,
+
+    /// The three- or four-digit security code of the card. Also known as the CVV, CVC, CVN, CVE,
+    /// or CID.
+    pub security_code: Option<String>,
+
+    /// The billing address for this card.
+    pub billing_address: Option<CardAddressPortable>,
+
+    /// Additional attributes, like the 3D Secure verification method.
+    pub attributes: Option<CardAttributes>,
+}
+
+impl CardRequest {
+    #[must_use]
+    pub const fn new(number: String, expiry: String) -> Self {
+        Self {
+            name: None,
+            number,
+            expiry,
+            security_code: None,
+            billing_address: None,
+            attributes: None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    #[must_use]
+    pub fn security_code(mut self, security_code: String) -> Self {
+        self.security_code = Some(security_code);
+        self
+    }
+
+    #[must_use]
+    pub fn billing_address(mut self, billing_address: CardAddressPortable) -> Self {
+        self.billing_address = Some(billing_address);
+        self
+    }
+
+    /// Verifies the card with 3D Secure using the given method.
+    #[must_use]
+    pub const fn verification(mut self, method: CardVerificationMethod) -> Self {
+        self.attributes = Some(CardAttributes::verification(method));
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayPalWalletRequest {
+    /// The email address of the PayPal account holder, used to pre-fill the PayPal login.
+    pub email_address: Option<String>,
+
+    /// Customizes the payer experience during the approval process with PayPal.
+    pub experience_context: Option<ExperienceContext>,
+}
+
+impl PayPalWalletRequest {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn email_address(mut self, email_address: String) -> Self {
+        self.email_address = Some(email_address);
+        self
+    }
+
+    #[must_use]
+    pub fn experience_context(mut self, experience_context: ExperienceContext) -> Self {
+        self.experience_context = Some(experience_context);
+        self
+    }
+}
+
+/// An alternative payment method (APM). The buyer is redirected to their bank or payment
+/// provider to approve the payment.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AlternativePaymentMethod {
+    /// The name of the account holder associated with this payment method.
+    pub name: String,
+
+    /// The two-character ISO 3166-1 country code.
+    pub country_code: String,
+
+    /// The email address of the account holder. Required for BLIK and Przelewy24.
+    pub email: Option<String>,
+
+    /// The business identification code (BIC). Only used for iDEAL.
+    pub bic: Option<String>,
+
+    /// Customizes the buyer experience, including where the buyer returns to after the redirect.
+    pub experience_context: Option<ApmExperienceContext>,
+}
+
+impl AlternativePaymentMethod {
+    #[must_use]
+    pub const fn new(name: String, country_code: String) -> Self {
+        Self {
+            name,
+            country_code,
+            email: None,
+            bic: None,
+            experience_context: None,
+        }
+    }
+
+    #[must_use]
+    pub fn email(mut self, email: String) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    #[must_use]
+    pub fn bic(mut self, bic: String) -> Self {
+        self.bic = Some(bic);
+        self
+    }
+
+    #[must_use]
+    pub fn experience_context(mut self, experience_context: ApmExperienceContext) -> Self {
+        self.experience_context = Some(experience_context);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApmExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the payment pages.
+    pub brand_name: Option<String>,
+
+    /// The BCP 47-formatted locale of the payment pages. For example, `nl-NL`.
+    pub locale: Option<String>,
+
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+
+    /// The URL where the buyer is redirected after approving the payment with their bank.
+    pub return_url: Option<String>,
+
+    /// The URL where the buyer is redirected after cancelling the payment.
+    pub cancel_url: Option<String>,
+}
+
+impl ApmExperienceContext {
+    #[must_use]
+    pub const fn new(return_url: String, cancel_url: String) -> Self {
+        Self {
+            brand_name: None,
+            locale: None,
+            shipping_preference: None,
+            return_url: Some(return_url),
+            cancel_url: Some(cancel_url),
+        }
+    }
+
+    #[must_use]
+    pub fn brand_name(mut self, brand_name: String) -> Self {
+        self.brand_name = Some(brand_name);
+        self
+    }
+
+    #[must_use]
+    pub fn locale(mut self, locale: String) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    #[must_use]
+    pub const fn shipping_preference(mut self, shipping_preference: ShippingPreference) -> Self {
+        self.shipping_preference = Some(shipping_preference);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AlternativePaymentMethod, ApmExperienceContext, OrderPaymentSource};
+
+    #[test]
+    fn ideal_payment_source() {
+        let payment_source = OrderPaymentSource::new().ideal(
+            AlternativePaymentMethod::new("John Doe".to_string(), "NL".to_string())
+                .bic("INGBNL2A".to_string())
+                .experience_context(ApmExperienceContext::new(
+                    "https://example.com/return".to_string(),
+                    "https://example.com/cancel".to_string(),
+                )),
+        );
+
+        assert_eq!(
+            serde_json::to_value(payment_source).unwrap(),
+            serde_json::json!({
+                "ideal": {
+                    "name": "John Doe",
+                    "country_code": "NL",
+                    "bic": "INGBNL2A",
+                    "experience_context": {
+                        "return_url": "https://example.com/return",
+                        "cancel_url": "https://example.com/cancel"
+                    }
+                }
+            })
+        );
+    }
+}
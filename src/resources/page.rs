@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::link_rel::LinkRel;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+
+/// A single page of a paginated list response.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct Page<T> {
+    /// The items on this page.
+    #[serde(default = "Vec::new")]
+    pub items: Vec<T>,
+
+    /// The total number of items. Only returned when the total count was requested.
+    pub total_items: Option<u32>,
+
+    /// The total number of pages. Only returned when the total count was requested.
+    pub total_pages: Option<u32>,
+
+    /// An array of request-related HATEOAS links, including the links to the previous and next
+    /// pages.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl<T> Page<T> {
+    /// Finds the URL of the next page in the HATEOAS links. `None` if this is the last page.
+    pub fn next_page_url(&self) -> Option<&str> {
+        Some(self.link(LinkRel::Next)?.href.as_str())
+    }
+
+    /// Whether there is a page after this one.
+    pub fn has_next_page(&self) -> bool {
+        self.next_page_url().is_some()
+    }
+}
+
+impl<T> HateoasLinks for Page<T> {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+impl<T> Default for Page<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            total_items: None,
+            total_pages: None,
+            links: None,
+        }
+    }
+}
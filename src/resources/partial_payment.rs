@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::money::Money;
+
+/// The partial payment details. Includes the minimum amount that the invoicer wants the payer to
+/// pay.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PartialPayment {
+    /// Indicates whether the invoice allows a partial payment. If false, the invoice must be paid
+    /// in full. If true, the invoice allows partial payments.
+    pub allow_partial_payment: Option<bool>,
+
+    /// The minimum amount allowed for a partial payment. Valid only when allow_partial_payment is
+    /// true.
+    pub minimum_amount_due: Option<Money>,
+}
@@ -0,0 +1,210 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::Endpoint;
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::enums::link_rel::LinkRel;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+
+pub struct PartnerReferral;
+
+impl PartnerReferral {
+    /// Creates a partner referral that is shared by the API caller. The referrals contains the
+    /// client's personal, business, financial and operations.
+    pub async fn create(
+        client: &Client,
+        dto: CreatePartnerReferralDto,
+    ) -> Result<CreatePartnerReferralResponse, PayPalError> {
+        client.post(&CreatePartnerReferral::new(dto)).await
+    }
+
+    /// Shows details for a partner referral, by ID.
+    pub async fn show(
+        client: &Client,
+        partner_referral_id: &str,
+    ) -> Result<ShowPartnerReferralResponse, PayPalError> {
+        client
+            .get(&ShowPartnerReferral::new(partner_referral_id.to_string()))
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreatePartnerReferralDto {
+    /// The partner's unique identifier for this customer in their system.
+    pub tracking_id: String,
+
+    /// The email address of the customer.
+    pub email: Option<String>,
+
+    /// The preferred language of the customer, as a BCP 47-formatted language code.
+    pub preferred_language_code: Option<String>,
+
+    /// An array of operations to perform for the customer while they share their data.
+    pub operations: Vec<ReferralOperation>,
+
+    /// An array of PayPal products to which the partner wants to onboard the customer.
+    pub products: Option<Vec<String>>,
+
+    /// An array of all consents that the partner has received from this seller.
+    pub legal_consents: Vec<LegalConsent>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReferralOperation {
+    /// The type of operation, for example `API_INTEGRATION`.
+    pub operation: String,
+
+    /// The integration details for the `API_INTEGRATION` operation.
+    pub api_integration_preference: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LegalConsent {
+    /// The type of consent, for example `SHARE_DATA_CONSENT`.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Indicates whether the customer agreed to share this type of data.
+    pub granted: bool,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CreatePartnerReferralResponse {
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl HateoasLinks for CreatePartnerReferralResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+impl CreatePartnerReferralResponse {
+    /// The ID of the partner referral, taken from the last path segment of the `self` link. Pass
+    /// it to [`PartnerReferral::show`] to look up the referral.
+    pub fn tracking_id(&self) -> Option<&str> {
+        let link = self.link(LinkRel::SelfLink)?;
+        let path = link.href.split(['?', '#']).next()?;
+        path.rsplit('/').next().filter(|id| !id.is_empty())
+    }
+
+    /// The `action_url` link, to which the merchant has to be redirected to sign up and grant
+    /// permissions.
+    pub fn merchant_approval_url(&self) -> Option<Url> {
+        Url::parse(&self.link(LinkRel::ActionUrl)?.href).ok()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ShowPartnerReferralResponse {
+    /// The ID of the partner referral.
+    pub partner_referral_id: Option<String>,
+
+    /// The ID of the partner who submitted the referral.
+    pub submitter_payer_id: Option<String>,
+
+    /// The customer's data that was shared in the referral.
+    pub referral_data: Option<CreatePartnerReferralDto>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+#[derive(Debug)]
+struct CreatePartnerReferral {
+    body: CreatePartnerReferralDto,
+}
+
+impl CreatePartnerReferral {
+    pub const fn new(body: CreatePartnerReferralDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreatePartnerReferral {
+    type QueryParams = ();
+    type RequestBody = CreatePartnerReferralDto;
+    type ResponseBody = CreatePartnerReferralResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v2/customer/partner-referrals")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ShowPartnerReferral {
+    partner_referral_id: String,
+}
+
+impl ShowPartnerReferral {
+    pub const fn new(partner_referral_id: String) -> Self {
+        Self {
+            partner_referral_id,
+        }
+    }
+}
+
+impl Endpoint for ShowPartnerReferral {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ShowPartnerReferralResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/customer/partner-referrals/{}",
+            self.partner_referral_id
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracking_id_from_self_link() {
+        let response = CreatePartnerReferralResponse {
+            links: Some(vec![
+                LinkDescription {
+                    href: "https://api-m.sandbox.paypal.com/v2/customer/partner-referrals/ZjcyODU4ZWYtYTA1OC00ODIwLTk2M2EtOTZkZWQ4NmQwYzI3"
+                        .to_string(),
+                    rel: "self".to_string(),
+                    method: None,
+                },
+                LinkDescription {
+                    href: "https://www.sandbox.paypal.com/merchantsignup/partner/onboardingentry?token=ZjcyODU4"
+                        .to_string(),
+                    rel: "action_url".to_string(),
+                    method: None,
+                },
+            ]),
+        };
+
+        assert_eq!(
+            response.tracking_id(),
+            Some("ZjcyODU4ZWYtYTA1OC00ODIwLTk2M2EtOTZkZWQ4NmQwYzI3")
+        );
+        assert_eq!(
+            response.merchant_approval_url().unwrap().host_str(),
+            Some("www.sandbox.paypal.com")
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::resources::amount_with_breakdown::AmountWithBreakdown;
 use crate::resources::enums::op::Op;
 use crate::resources::money::Money;
+use crate::resources::platform_fee::PlatformFee;
+use crate::resources::shipping_detail::ShippingDetail;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -55,6 +58,9 @@ pub enum PatchValue {
     String(String),
     Vec(Vec<PatchValue>),
     Money(Money),
+    PlatformFee(PlatformFee),
+    AmountWithBreakdown(AmountWithBreakdown),
+    ShippingDetail(ShippingDetail),
 }
 
 impl PatchValue {
@@ -82,4 +88,19 @@ impl PatchValue {
     pub fn int(self, int: i32) -> Self {
         Self::Int(int)
     }
+
+    #[must_use]
+    pub fn platform_fee(self, platform_fee: PlatformFee) -> Self {
+        Self::PlatformFee(platform_fee)
+    }
+
+    #[must_use]
+    pub fn amount_with_breakdown(self, amount: AmountWithBreakdown) -> Self {
+        Self::AmountWithBreakdown(amount)
+    }
+
+    #[must_use]
+    pub fn shipping_detail(self, shipping: ShippingDetail) -> Self {
+        Self::ShippingDetail(shipping)
+    }
 }
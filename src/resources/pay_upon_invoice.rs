@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::name::Name;
+use crate::resources::phone_with_type_phone::PhoneWithTypePhone;
+use crate::resources::shipping_detail_address_portable::ShippingDetailAddressPortable;
+
+/// Pay Upon Invoice, processed by RatePay. Only available to German merchants and buyers; the
+/// order must use the `CAPTURE` intent and `ORDER_COMPLETE_ON_PAYMENT_APPROVAL` processing
+/// instruction.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PayUponInvoiceRequest {
+    /// The name of the buyer. Given name and surname are required.
+    pub name: Name,
+
+    /// The email address of the buyer.
+    pub email: String,
+
+    /// The birth date of the buyer, in `YYYY-MM-DD` format.
+    pub birth_date: NaiveDate,
+
+    /// The phone number of the buyer.
+    pub phone: PayUponInvoicePhone,
+
+    /// The billing address of the buyer. Must be located in Germany.
+    pub billing_address: ShippingDetailAddressPortable,
+
+    /// Customizes the payment experience and the invoice sent to the buyer.
+    pub experience_context: PayUponInvoiceExperienceContext,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayUponInvoicePhone {
+    /// The country calling code, for example `49` for Germany.
+    pub country_code: String,
+
+    /// The national number, in its canonical international E.164 numbering plan format.
+    pub national_number: String,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayUponInvoiceExperienceContext {
+    /// The BCP 47-formatted locale of the invoice. For example, `de-DE`.
+    pub locale: Option<String>,
+
+    /// The label that overrides the business name in the PayPal account.
+    pub brand_name: Option<String>,
+
+    /// The URL of the merchant logo shown on the invoice.
+    pub logo_url: Option<String>,
+
+    /// The merchant's customer service instructions, shown on the invoice. Required.
+    pub customer_service_instructions: Vec<String>,
+}
+
+/// Pay Upon Invoice details returned for captured orders, including where the buyer has to
+/// transfer the money to.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayUponInvoiceResponse {
+    /// The name of the buyer.
+    pub name: Option<Name>,
+
+    /// The email address of the buyer.
+    pub email: Option<String>,
+
+    /// The birth date of the buyer.
+    pub birth_date: Option<NaiveDate>,
+
+    /// The phone number of the buyer.
+    pub phone: Option<PhoneWithTypePhone>,
+
+    /// The billing address of the buyer.
+    pub billing_address: Option<ShippingDetailAddressPortable>,
+
+    /// The reference the buyer has to use when transferring the money.
+    pub payment_reference: Option<String>,
+
+    /// The bank account to which the buyer has to transfer the money.
+    pub deposit_bank_details: Option<DepositBankDetails>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DepositBankDetails {
+    /// The business identification code (BIC) of the bank.
+    pub bic: Option<String>,
+
+    /// The name of the bank.
+    pub bank_name: Option<String>,
+
+    /// The international bank account number (IBAN).
+    pub iban: Option<String>,
+
+    /// The name of the account holder.
+    pub account_holder_name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PayUponInvoiceResponse;
+
+    #[test]
+    fn deserializes_deposit_bank_details() {
+        let response: PayUponInvoiceResponse = serde_json::from_str(
+            r#"{
+                "birth_date": "1990-01-01",
+                "name": {"given_name": "John", "surname": "Doe"},
+                "email": "buyer@example.com",
+                "payment_reference": "b8a1525dlYzu6Mn62umI",
+                "deposit_bank_details": {
+                    "bic": "DEUTDEFFXXX",
+                    "bank_name": "Deutsche Bank",
+                    "iban": "DE89370400440532013000",
+                    "account_holder_name": "Paypal - Ratepay GmbH - Test Bank Account"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let bank_details = response.deposit_bank_details.unwrap();
+        assert_eq!(bank_details.iban.as_deref(), Some("DE89370400440532013000"));
+        assert_eq!(
+            response.payment_reference.as_deref(),
+            Some("b8a1525dlYzu6Mn62umI")
+        );
+    }
+}
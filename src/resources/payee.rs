@@ -1,10 +1,33 @@
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
+/// The merchant who receives the payment. Identified by either the email address or the merchant
+/// ID; if both are omitted, the API caller is the payee.
+#[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Payee {
     /// The email address of merchant.
-    pub email_address: String,
+    pub email_address: Option<String>,
 
     /// The encrypted PayPal account ID of the merchant.
-    pub merchant_id: String,
+    pub merchant_id: Option<String>,
+}
+
+impl Payee {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn email_address(mut self, email_address: String) -> Self {
+        self.email_address = Some(email_address);
+        self
+    }
+
+    #[must_use]
+    pub fn merchant_id(mut self, merchant_id: String) -> Self {
+        self.merchant_id = Some(merchant_id);
+        self
+    }
 }
@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PayeeBase {
     /// The email address of merchant.
-    pub email_address: String,
+    pub email_address: Option<String>,
 
     /// The encrypted PayPal account ID of the merchant.
-    pub merchant_id: String,
+    pub merchant_id: Option<String>,
 }
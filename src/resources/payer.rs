@@ -1,26 +1,83 @@
 use crate::resources::address::Address;
-use crate::resources::date_no_time::DateNoTime;
 use crate::resources::name::Name;
 use crate::resources::phone_with_type::PhoneWithType;
 use crate::resources::tax_info::TaxInfo;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+/// The customer who approves and pays for the order. When creating an order, the known buyer
+/// information pre-fills the PayPal checkout, which can skip the PayPal login step.
 #[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Payer {
     /// The email address of the payer.
     pub email_address: Option<String>,
 
+    /// The PayPal-assigned ID for the payer.
     pub payer_id: Option<String>,
 
+    /// The name of the payer.
     pub name: Option<Name>,
 
+    /// The phone number of the customer. Available only when you enable the Contact Telephone
+    /// Number option in the Profile & Settings for the merchant's PayPal account.
     pub phone: Option<PhoneWithType>,
 
-    pub birth_date: Option<DateNoTime>,
+    /// The birth date of the payer, in `YYYY-MM-DD` format.
+    pub birth_date: Option<String>,
 
+    /// The tax information of the payer. Required only for Brazilian payer's.
     pub tax_info: Option<TaxInfo>,
 
-    pub address: Address,
+    /// The address of the payer.
+    pub address: Option<Address>,
+}
+
+impl Payer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn email_address(mut self, email_address: String) -> Self {
+        self.email_address = Some(email_address);
+        self
+    }
+
+    #[must_use]
+    pub fn payer_id(mut self, payer_id: String) -> Self {
+        self.payer_id = Some(payer_id);
+        self
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: Name) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    #[must_use]
+    pub fn phone(mut self, phone: PhoneWithType) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    #[must_use]
+    pub fn birth_date(mut self, birth_date: String) -> Self {
+        self.birth_date = Some(birth_date);
+        self
+    }
+
+    #[must_use]
+    pub fn tax_info(mut self, tax_info: TaxInfo) -> Self {
+        self.tax_info = Some(tax_info);
+        self
+    }
+
+    #[must_use]
+    pub fn address(mut self, address: Address) -> Self {
+        self.address = Some(address);
+        self
+    }
 }
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::error::PayPalError;
+use crate::resources::enums::failed_payment_action::FailedPaymentAction;
+use crate::resources::money::Money;
+
+/// The payment preferences for a subscription plan.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentPreferences {
+    /// Indicates whether to automatically bill the outstanding amount in the next billing cycle.
+    pub auto_bill_outstanding: Option<bool>,
+
+    /// The initial set-up fee for the service.
+    pub setup_fee: Option<Money>,
+
+    /// The action to take on the subscription if the initial payment for the setup fails.
+    pub setup_fee_failure_action: Option<FailedPaymentAction>,
+
+    /// The maximum number of consecutive payment failures before a subscription is suspended.
+    /// Must be between 1 and 999.
+    pub payment_failure_threshold: Option<u16>,
+}
+
+impl PaymentPreferences {
+    /// Checks that the payment preferences are within the limits accepted by PayPal.
+    ///
+    /// # Errors
+    /// Errors if `payment_failure_threshold` is not between 1 and 999.
+    pub fn validate(&self) -> Result<(), PayPalError> {
+        match self.payment_failure_threshold {
+            Some(threshold) if !(1..=999).contains(&threshold) => Err(PayPalError::LibraryError(
+                format!("payment_failure_threshold must be between 1 and 999, got {threshold}"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_payment_failure_threshold() {
+        let preferences = |payment_failure_threshold| PaymentPreferences {
+            payment_failure_threshold,
+            ..Default::default()
+        };
+
+        assert!(preferences(None).validate().is_ok());
+        assert!(preferences(Some(1)).validate().is_ok());
+        assert!(preferences(Some(999)).validate().is_ok());
+        assert_eq!(
+            preferences(Some(0)).validate(),
+            Err(PayPalError::LibraryError(
+                "payment_failure_threshold must be between 1 and 999, got 0".to_string()
+            ))
+        );
+        assert_eq!(
+            preferences(Some(1000)).validate(),
+            Err(PayPalError::LibraryError(
+                "payment_failure_threshold must be between 1 and 999, got 1000".to_string()
+            ))
+        );
+    }
+}
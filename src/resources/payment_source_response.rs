@@ -1,4 +1,5 @@
 use crate::resources::card_response::CardResponse;
+use crate::resources::pay_upon_invoice::PayUponInvoiceResponse;
 use crate::resources::paypal_payment_source_response::PayPalPaymentSourceResponse;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -9,4 +10,7 @@ pub struct PaymentSourceResponse {
     pub card: Option<CardResponse>,
 
     pub paypal: Option<PayPalPaymentSourceResponse>,
+
+    /// The Pay Upon Invoice details, including the bank details for the buyer's transfer.
+    pub pay_upon_invoice: Option<PayUponInvoiceResponse>,
 }
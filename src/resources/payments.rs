@@ -1,14 +1,17 @@
 use std::borrow::Cow;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::client::request::HttpRequestHeaders;
 use crate::client::{Client, Endpoint, PayPalError};
 use crate::{
     AuthorizationStatusDetails, CaptureStatus, CaptureStatusDetails, DisbursementMode,
-    LinkDescription, Money, PaymentInstruction, PaymentStatus, ProcessorResponse, RefundStatus,
-    RefundStatusDetails, SellerPayableBreakdown, SellerProtection, SellerReceivableBreakdown,
+    HateoasLinks, LinkDescription, Money, PaymentInstruction, PaymentStatus, ProcessorResponse,
+    RefundStatus, RefundStatusDetails, RefundStatusReason, SellerPayableBreakdown,
+    SellerProtection, SellerReceivableBreakdown,
 };
 
 pub struct Payment;
@@ -37,6 +40,63 @@ impl Payment {
             .await
     }
 
+    /// Refunds a captured payment, by ID, sending `request_id` as the `PayPal-Request-Id` header.
+    /// Retrying the call with the same `request_id` does not refund the payment twice.
+    pub async fn refund_captured_with_request_id(
+        client: &Client,
+        capture_id: String,
+        dto: RefundCapturedPaymentDto,
+        request_id: String,
+    ) -> Result<RefundCapturedPaymentResponse, PayPalError> {
+        client
+            .post(&RefundCapturedPayment::new(capture_id, dto).request_id(request_id))
+            .await
+    }
+
+    /// Fully refunds a captured payment, by ID, including all platform fees that were collected
+    /// for it. The capture is looked up first to determine the platform fees. The refunded amount
+    /// is left to PayPal, which refunds the captured amount minus previous refunds.
+    pub async fn refund_captured_with_platform_fees(
+        client: &Client,
+        capture_id: String,
+        note: Option<&str>,
+    ) -> Result<RefundCapturedPaymentResponse, PayPalError> {
+        let capture = Self::show_captured(client, capture_id.clone()).await?;
+
+        let mut dto = RefundCapturedPaymentDto::with_platform_fees(&capture);
+        if let Some(note) = note {
+            dto = dto.note_to_payer(note.to_string());
+        }
+
+        Self::refund_captured(client, capture_id, dto).await
+    }
+
+    /// Shows details for a refund, by ID.
+    pub async fn show_refund(
+        client: &Client,
+        refund_id: String,
+    ) -> Result<ShowRefundResponse, PayPalError> {
+        client.get(&ShowRefund::new(refund_id)).await
+    }
+
+    /// Shows details for a captured payment, by ID.
+    pub async fn show_captured(
+        client: &Client,
+        capture_id: String,
+    ) -> Result<ShowCapturedPaymentResponse, PayPalError> {
+        client.get(&ShowCapturedPayment::new(capture_id)).await
+    }
+
+    /// Shows details for an authorized payment, by ID.
+    pub async fn show_authorized(
+        client: &Client,
+        authorization_id: String,
+    ) -> Result<ShowAuthorizedPaymentResponse, PayPalError> {
+        client
+            .get(&ShowAuthorizedPayment::new(authorization_id))
+            .await
+    }
+
     /// Reauthorizes an authorized PayPal account payment, by ID. To ensure that funds are still
     /// available, reauthorize a payment after its initial three-day honor period expires. Within
     /// the 29-day authorization period, you can issue multiple re-authorizations after the honor
@@ -108,6 +168,15 @@ impl CaptureAuthorizedPaymentDto {
         Self::default()
     }
 
+    /// Captures a portion of the authorized amount. Set `is_final_capture` to `false` to keep
+    /// the remaining amount of the authorization available for additional captures.
+    #[must_use]
+    pub fn partial(amount: Money, is_final_capture: bool) -> Self {
+        Self::new()
+            .amount(amount)
+            .is_final_capture(is_final_capture)
+    }
+
     #[must_use]
     pub fn invoice_id(mut self, invoice_id: String) -> Self {
         self.invoice_id = Some(invoice_id);
@@ -197,6 +266,28 @@ pub struct CaptureAuthorizedPaymentResponse {
     pub update_time: Option<String>,
 }
 
+impl CaptureAuthorizedPaymentResponse {
+    /// The fee PayPal charged for this captured payment, in the currency of the transaction.
+    pub fn paypal_fee(&self) -> Option<&Money> {
+        self.seller_receivable_breakdown
+            .as_ref()
+            .and_then(|breakdown| breakdown.paypal_fee.as_ref())
+    }
+
+    /// The amount the payee receives for this captured payment after PayPal and platform fees.
+    pub fn net_amount(&self) -> Option<&Money> {
+        self.seller_receivable_breakdown
+            .as_ref()
+            .and_then(|breakdown| breakdown.net_amount.as_ref())
+    }
+}
+
+impl HateoasLinks for CaptureAuthorizedPaymentResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone)]
 struct CaptureAuthorizedPayment {
     authorization_id: String,
@@ -234,7 +325,7 @@ impl CaptureAuthorizedPayment {
 }
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct RefundCapturedPaymentDto {
     /// The amount to refund. To refund a portion of the captured amount, specify an amount.
     /// If amount is not specified, an amount equal to captured amount - previous refunds is refunded.
@@ -247,6 +338,84 @@ pub struct RefundCapturedPaymentDto {
 
     /// The reason for the refund. Appears in both the payer's transaction history and the emails that the payer receives.
     pub note_to_payer: Option<String>,
+
+    /// Any additional refund instructions to be set during refund payment processing.
+    pub payment_instruction: Option<RefundPaymentInstruction>,
+}
+
+impl RefundCapturedPaymentDto {
+    /// A full refund of the captured amount minus previous refunds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A full refund of the captured amount minus previous refunds, which also refunds all
+    /// platform fees collected for the capture. The platform fees are refunded in full, so use
+    /// [`RefundCapturedPaymentDto::payment_instruction`] if part of them was refunded before.
+    #[must_use]
+    pub fn with_platform_fees(capture: &ShowCapturedPaymentResponse) -> Self {
+        let platform_fees = capture
+            .seller_receivable_breakdown
+            .as_ref()
+            .and_then(|breakdown| breakdown.platform_fees.as_ref())
+            .map(|fees| {
+                fees.iter()
+                    .map(|fee| RefundPlatformFee {
+                        amount: fee.amount.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let dto = Self::new();
+        if platform_fees.is_empty() {
+            return dto;
+        }
+
+        dto.payment_instruction(RefundPaymentInstruction { platform_fees })
+    }
+
+    /// A partial refund of the given amount.
+    #[must_use]
+    pub fn partial(amount: Money) -> Self {
+        Self::new().amount(amount)
+    }
+
+    #[must_use]
+    pub fn amount(mut self, amount: Money) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    #[must_use]
+    pub fn invoice_id(mut self, invoice_id: String) -> Self {
+        self.invoice_id = Some(invoice_id);
+        self
+    }
+
+    #[must_use]
+    pub fn note_to_payer(mut self, note_to_payer: String) -> Self {
+        self.note_to_payer = Some(note_to_payer);
+        self
+    }
+
+    #[must_use]
+    pub fn payment_instruction(mut self, payment_instruction: RefundPaymentInstruction) -> Self {
+        self.payment_instruction = Some(payment_instruction);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RefundPaymentInstruction {
+    /// An array of platform fees to refund, along with the refunded payment.
+    pub platform_fees: Vec<RefundPlatformFee>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RefundPlatformFee {
+    /// The platform fee amount to refund.
+    pub amount: Money,
 }
 
 #[skip_serializing_none]
@@ -284,25 +453,43 @@ pub struct RefundCapturedPaymentResponse {
     pub update_time: Option<String>,
 }
 
+impl RefundCapturedPaymentResponse {
+    /// The reason why the refund is `PENDING` or `FAILED`, if PayPal provided one.
+    pub fn status_reason(&self) -> Option<RefundStatusReason> {
+        self.status_details
+            .as_ref()
+            .and_then(|status_details| status_details.reason)
+    }
+}
+
+impl HateoasLinks for RefundCapturedPaymentResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
 /// Refunds a captured payment, by ID. For a full refund, include an empty payload in the JSON
 /// request body. For a partial refund, include an amount object in the JSON request body.
 #[derive(Debug)]
 struct RefundCapturedPayment {
     capture_id: String,
-    amount: Option<Money>,
-    invoice_id: Option<String>,
-    note_to_payer: Option<String>,
+    body: RefundCapturedPaymentDto,
+    request_id: Option<String>,
 }
 
 impl RefundCapturedPayment {
-    pub fn new(capture_id: String, body: RefundCapturedPaymentDto) -> Self {
+    pub const fn new(capture_id: String, body: RefundCapturedPaymentDto) -> Self {
         Self {
             capture_id,
-            amount: body.amount,
-            invoice_id: body.invoice_id,
-            note_to_payer: body.note_to_payer,
+            body,
+            request_id: None,
         }
     }
+
+    fn request_id(mut self, request_id: String) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
 }
 
 impl Endpoint for RefundCapturedPayment {
@@ -314,12 +501,15 @@ impl Endpoint for RefundCapturedPayment {
         Cow::Owned(format!("v2/payments/captures/{}/refund", self.capture_id))
     }
 
+    fn headers(&self) -> HttpRequestHeaders {
+        HttpRequestHeaders {
+            paypal_request_id: self.request_id.clone(),
+            ..Default::default()
+        }
+    }
+
     fn request_body(&self) -> Option<Self::RequestBody> {
-        Some(RefundCapturedPaymentDto {
-            amount: self.amount.clone(),
-            invoice_id: self.invoice_id.clone(),
-            note_to_payer: self.note_to_payer.clone(),
-        })
+        Some(self.body.clone())
     }
 
     fn request_method(&self) -> Method {
@@ -327,13 +517,152 @@ impl Endpoint for RefundCapturedPayment {
     }
 }
 
+pub type ShowRefundResponse = RefundCapturedPaymentResponse;
+
+#[derive(Debug)]
+struct ShowRefund {
+    refund_id: String,
+}
+
+impl ShowRefund {
+    pub const fn new(refund_id: String) -> Self {
+        Self { refund_id }
+    }
+}
+
+impl Endpoint for ShowRefund {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ShowRefundResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/payments/refunds/{}", self.refund_id))
+    }
+}
+
+pub type ShowCapturedPaymentResponse = CaptureAuthorizedPaymentResponse;
+
+#[derive(Debug)]
+struct ShowCapturedPayment {
+    capture_id: String,
+}
+
+impl ShowCapturedPayment {
+    pub const fn new(capture_id: String) -> Self {
+        Self { capture_id }
+    }
+}
+
+impl Endpoint for ShowCapturedPayment {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ShowCapturedPaymentResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v2/payments/captures/{}", self.capture_id))
+    }
+}
+
 #[skip_serializing_none]
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ShowAuthorizedPaymentResponse {
+    /// The PayPal-generated ID for the authorized payment.
+    pub id: String,
+
+    /// The status for the authorized payment.
+    pub status: PaymentStatus,
+
+    /// The details of the authorized order pending status.
+    pub status_details: Option<AuthorizationStatusDetails>,
+
+    /// The amount for this authorized payment.
+    pub amount: Option<Money>,
+
+    /// The API caller-provided external invoice number for this order. Appears in both the payer's
+    /// transaction history and the emails that the payer receives.
+    pub invoice_id: Option<String>,
+
+    /// The API caller-provided external ID. Used to reconcile API caller-initiated transactions
+    /// with PayPal transactions. Appears in transaction and settlement reports.
+    pub custom_id: Option<String>,
+
+    /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
+    pub seller_protection: Option<SellerProtection>,
+
+    /// The date and time when the authorized payment expires, in Internet date and time format.
+    pub expiration_time: Option<DateTime<Utc>>,
+
+    /// An array of related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+
+    /// The date and time when the transaction occurred, in Internet date and time format.
+    pub create_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the transaction was last updated, in Internet date and time format.
+    pub update_time: Option<DateTime<Utc>>,
+}
+
+impl ShowAuthorizedPaymentResponse {
+    /// Whether the authorized payment has passed its expiration time and can no longer be
+    /// captured.
+    pub fn is_expired(&self) -> bool {
+        self.status == PaymentStatus::Expired
+            || self
+                .expiration_time
+                .map_or(false, |expiration_time| expiration_time <= Utc::now())
+    }
+}
+
+impl HateoasLinks for ShowAuthorizedPaymentResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct ShowAuthorizedPayment {
+    authorization_id: String,
+}
+
+impl ShowAuthorizedPayment {
+    pub const fn new(authorization_id: String) -> Self {
+        Self { authorization_id }
+    }
+}
+
+impl Endpoint for ShowAuthorizedPayment {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ShowAuthorizedPaymentResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v2/payments/authorizations/{}",
+            self.authorization_id
+        ))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ReauthorizeAuthorizedPaymentDto {
-    /// The amount to reauthorize for an authorized payment.
+    /// The amount to reauthorize for an authorized payment. If not specified, the original
+    /// authorized amount is reauthorized.
     pub amount: Option<Money>,
 }
 
+impl ReauthorizeAuthorizedPaymentDto {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn amount(mut self, amount: Money) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize)]
 pub struct ReauthorizeAuthorizedPaymentResponse {
@@ -373,6 +702,12 @@ pub struct ReauthorizeAuthorizedPaymentResponse {
     pub update_time: Option<String>,
 }
 
+impl HateoasLinks for ReauthorizeAuthorizedPaymentResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
 /// Reauthorizes an authorized PayPal account payment, by ID. To ensure that funds are still
 /// available, reauthorize a payment after its initial three-day honor period expires.
 /// Within the 29-day authorization period, you can issue multiple re-authorizations after the honor
@@ -458,3 +793,224 @@ impl Endpoint for VoidAuthorizedPayment {
         Method::POST
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CurrencyCode, LinkRel};
+
+    #[test]
+    fn partial_capture_request_body() {
+        let endpoint = CaptureAuthorizedPayment::new(
+            "0VF52814937998046".to_string(),
+            CaptureAuthorizedPaymentDto::partial(
+                Money::new(CurrencyCode::UnitedStatesDollar, "5.00".to_string()),
+                false,
+            )
+            .invoice_id("INVOICE-123".to_string())
+            .soft_descriptor("Bob's Custom Sweaters".to_string()),
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v2/payments/authorizations/0VF52814937998046/capture"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "amount": {"currency_code": "USD", "value": "5.00"},
+                "invoice_id": "INVOICE-123",
+                "is_final_capture": false,
+                "soft_descriptor": "Bob's Custom Sweaters"
+            })
+        );
+    }
+
+    #[test]
+    fn refund_request_id_header() {
+        let endpoint = RefundCapturedPayment::new(
+            "2GG279541U471931P".to_string(),
+            RefundCapturedPaymentDto::partial(Money::new(
+                CurrencyCode::UnitedStatesDollar,
+                "1.44".to_string(),
+            ))
+            .note_to_payer("Defective product".to_string()),
+        );
+        assert!(endpoint.headers().paypal_request_id.is_none());
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "amount": {"currency_code": "USD", "value": "1.44"},
+                "note_to_payer": "Defective product"
+            })
+        );
+
+        let endpoint = endpoint.request_id("refund-2GG279541U471931P-1".to_string());
+        assert_eq!(
+            endpoint.headers().paypal_request_id.as_deref(),
+            Some("refund-2GG279541U471931P-1")
+        );
+    }
+
+    #[test]
+    fn show_pending_refund() {
+        let response: ShowRefundResponse = serde_json::from_str(
+            r#"{
+                "id": "1JU08902781691411",
+                "status": "PENDING",
+                "status_details": {"reason": "ECHECK"},
+                "amount": {"value": "10.99", "currency_code": "USD"},
+                "seller_payable_breakdown": {
+                    "gross_amount": {"value": "10.99", "currency_code": "USD"},
+                    "paypal_fee": {"value": "0", "currency_code": "USD"},
+                    "net_amount": {"value": "10.99", "currency_code": "USD"},
+                    "total_refunded_amount": {"value": "10.99", "currency_code": "USD"}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.status, RefundStatus::Pending);
+        assert_eq!(response.status_reason(), Some(RefundStatusReason::Echeck));
+        assert!(response.seller_payable_breakdown.is_some());
+    }
+
+    #[test]
+    fn reauthorize_request_body() {
+        let endpoint = ReauthorizeAuthorizedPayment::new(
+            "0VF52814937998046".to_string(),
+            ReauthorizeAuthorizedPaymentDto::new(),
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({})
+        );
+
+        let endpoint = ReauthorizeAuthorizedPayment::new(
+            "0VF52814937998046".to_string(),
+            ReauthorizeAuthorizedPaymentDto::new().amount(Money::new(
+                CurrencyCode::UnitedStatesDollar,
+                "12.50".to_string(),
+            )),
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({"amount": {"currency_code": "USD", "value": "12.50"}})
+        );
+    }
+
+    #[test]
+    fn show_captured_payment_fees() {
+        let response: ShowCapturedPaymentResponse = serde_json::from_str(
+            r#"{
+                "id": "2GG279541U471931P",
+                "status": "COMPLETED",
+                "amount": {"value": "10.99", "currency_code": "USD"},
+                "final_capture": true,
+                "seller_receivable_breakdown": {
+                    "gross_amount": {"value": "10.99", "currency_code": "USD"},
+                    "paypal_fee": {"value": "0.33", "currency_code": "USD"},
+                    "net_amount": {"value": "10.66", "currency_code": "USD"}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.paypal_fee().unwrap().value, "0.33");
+        assert_eq!(response.net_amount().unwrap().value, "10.66");
+        assert_eq!(
+            response
+                .seller_receivable_breakdown
+                .as_ref()
+                .unwrap()
+                .gross_amount
+                .value,
+            "10.99"
+        );
+    }
+
+    #[test]
+    fn refund_with_platform_fees_request_body() {
+        let capture: ShowCapturedPaymentResponse = serde_json::from_str(
+            r#"{
+                "id": "2GG279541U471931P",
+                "status": "PARTIALLY_REFUNDED",
+                "amount": {"value": "10.99", "currency_code": "USD"},
+                "seller_receivable_breakdown": {
+                    "gross_amount": {"value": "10.99", "currency_code": "USD"},
+                    "platform_fees": [
+                        {"amount": {"value": "1.00", "currency_code": "USD"}},
+                        {"amount": {"value": "0.50", "currency_code": "USD"}}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let endpoint = RefundCapturedPayment::new(
+            "2GG279541U471931P".to_string(),
+            RefundCapturedPaymentDto::with_platform_fees(&capture)
+                .note_to_payer("Defective product".to_string()),
+        );
+        assert_eq!(
+            endpoint.path(),
+            "v2/payments/captures/2GG279541U471931P/refund"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "note_to_payer": "Defective product",
+                "payment_instruction": {
+                    "platform_fees": [
+                        {"amount": {"currency_code": "USD", "value": "1.00"}},
+                        {"amount": {"currency_code": "USD", "value": "0.50"}}
+                    ]
+                }
+            })
+        );
+
+        let capture: ShowCapturedPaymentResponse =
+            serde_json::from_str(r#"{"id": "2GG279541U471931P", "status": "COMPLETED"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_value(RefundCapturedPaymentDto::with_platform_fees(&capture)).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn show_authorized_payment_response() {
+        let response: ShowAuthorizedPaymentResponse = serde_json::from_str(
+            r#"{
+                "id": "0VF52814937998046",
+                "status": "CREATED",
+                "amount": {"value": "10.99", "currency_code": "USD"},
+                "seller_protection": {
+                    "status": "ELIGIBLE",
+                    "dispute_categories": ["ITEM_NOT_RECEIVED", "UNAUTHORIZED_TRANSACTION"]
+                },
+                "expiration_time": "2017-10-10T23:23:45Z",
+                "create_time": "2017-09-11T23:23:45Z",
+                "update_time": "2017-09-11T23:23:45Z",
+                "links": [
+                    {
+                        "rel": "capture",
+                        "method": "POST",
+                        "href": "https://api-m.paypal.com/v2/payments/authorizations/0VF52814937998046/capture"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.status, PaymentStatus::Created);
+        assert_eq!(response.amount.as_ref().unwrap().value, "10.99");
+        assert!(response
+            .seller_protection
+            .as_ref()
+            .unwrap()
+            .status
+            .is_some());
+        assert!(response.is_expired());
+        assert!(response.link(LinkRel::Capture).is_some());
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::resources::enums::currency_code::CurrencyCode;
+use crate::resources::money::Money;
+
+/// The currency and amount of a payout. Unlike [`Money`], the Payouts API names the currency
+/// field `currency`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayoutCurrency {
+    /// The three-character ISO-4217 currency code.
+    pub currency: CurrencyCode,
+
+    /// The value, which might be:
+    /// * An integer for currencies like JPY that are not typically fractional.
+    /// * A decimal fraction for currencies like TND that are subdivided into thousandths.
+    pub value: String,
+}
+
+impl PayoutCurrency {
+    #[must_use]
+    pub const fn new(currency: CurrencyCode, value: String) -> Self {
+        Self { currency, value }
+    }
+}
+
+impl From<Money> for PayoutCurrency {
+    fn from(money: Money) -> Self {
+        Self::new(money.currency_code, money.value)
+    }
+}
+
+impl From<PayoutCurrency> for Money {
+    fn from(currency: PayoutCurrency) -> Self {
+        Self::new(currency.currency, currency.value)
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::error::ErrorDetails;
+use crate::resources::enums::payout_error_name::PayoutErrorName;
+
+/// The error details for a failed payout item.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayoutItemError {
+    /// The human-readable, unique name of the error. Errors that this library does not know
+    /// are deserialized as [`PayoutErrorName::Unknown`].
+    pub name: Option<PayoutErrorName>,
+
+    /// The message that describes the error.
+    pub message: Option<String>,
+
+    /// The PayPal internal ID. Used for correlation purposes.
+    pub debug_id: Option<String>,
+
+    /// The information link, or URI, that shows detailed information about this error for the
+    /// developer.
+    pub information_link: Option<String>,
+
+    /// An array of additional details about the error.
+    pub details: Option<Vec<ErrorDetails>>,
+}
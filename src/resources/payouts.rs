@@ -0,0 +1,741 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::Endpoint;
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::client::request::HttpRequestHeaders;
+use crate::resources::enums::payout_batch_status::PayoutBatchStatus;
+use crate::resources::enums::payout_recipient_type::PayoutRecipientType;
+use crate::resources::enums::payout_transaction_status::PayoutTransactionStatus;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+use crate::resources::money::Money;
+use crate::resources::page::Page;
+use crate::resources::payout_currency::PayoutCurrency;
+use crate::resources::payout_item_error::PayoutItemError;
+
+pub struct Payout;
+
+impl Payout {
+    /// Creates a batch payout. In the JSON request body, pass a `sender_batch_header` and an
+    /// `items` array. The batch is processed asynchronously, so the response only contains the
+    /// batch header. Use the `payout_batch_id` to check the status of the batch.
+    pub async fn create_batch(
+        client: &Client,
+        dto: CreatePayoutBatchDto,
+    ) -> Result<CreatePayoutBatchResponse, PayPalError> {
+        client.post(&CreatePayoutBatch::new(dto)).await
+    }
+
+    /// Creates a batch payout, sending `request_id` as the `PayPal-Request-Id` header. Retrying
+    /// the call with the same `request_id` returns the batch created by the first call instead of
+    /// paying out twice.
+    pub async fn create_batch_with_request_id(
+        client: &Client,
+        dto: CreatePayoutBatchDto,
+        request_id: String,
+    ) -> Result<CreatePayoutBatchResponse, PayPalError> {
+        client
+            .post(&CreatePayoutBatch::new(dto).request_id(request_id))
+            .await
+    }
+
+    /// Shows the latest status of a batch payout, including the details of its payout items.
+    /// Large batches are paginated, see [`ShowPayoutBatchQuery`].
+    pub async fn show_batch(
+        client: &Client,
+        payout_batch_id: &str,
+        query: ShowPayoutBatchQuery,
+    ) -> Result<ShowPayoutBatchResponse, PayPalError> {
+        client
+            .get(&ShowPayoutBatch::new(payout_batch_id.to_string(), query))
+            .await
+    }
+
+    /// Shows the details for a payout item, by ID. Failed items carry the reason of the failure
+    /// in `errors`.
+    pub async fn show_item(
+        client: &Client,
+        payout_item_id: &str,
+    ) -> Result<PayoutItemDetails, PayPalError> {
+        client
+            .get(&ShowPayoutItem::new(payout_item_id.to_string()))
+            .await
+    }
+
+    /// Cancels an unclaimed payout item, by ID. If no one claims the unclaimed item within 30
+    /// days, the funds are automatically returned to the sender. Use this call to return the
+    /// funds earlier. Only items with the `UNCLAIMED` status can be cancelled.
+    pub async fn cancel_unclaimed_item(
+        client: &Client,
+        payout_item_id: &str,
+    ) -> Result<PayoutItemDetails, PayPalError> {
+        client
+            .post(&CancelUnclaimedPayoutItem::new(payout_item_id.to_string()))
+            .await
+    }
+}
+
+/// The sender-provided header for a batch payout.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SenderBatchHeader {
+    /// A sender-specified ID number. Tracks the payout in an accounting system. PayPal rejects a
+    /// batch with a `sender_batch_id` that was used within the last 30 days.
+    pub sender_batch_id: Option<String>,
+
+    /// The type of ID that identifies the payment receiver. Applies to all items that do not
+    /// set their own `recipient_type`.
+    pub recipient_type: Option<PayoutRecipientType>,
+
+    /// The subject line for the email that PayPal sends when payment for a payout item completes.
+    pub email_subject: Option<String>,
+
+    /// The email message that PayPal sends when the payout item completes.
+    pub email_message: Option<String>,
+
+    /// The payouts and item-level notes are concatenated in the email.
+    pub note: Option<String>,
+}
+
+impl SenderBatchHeader {
+    #[must_use]
+    pub fn new(sender_batch_id: String) -> Self {
+        Self {
+            sender_batch_id: Some(sender_batch_id),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub const fn recipient_type(mut self, recipient_type: PayoutRecipientType) -> Self {
+        self.recipient_type = Some(recipient_type);
+        self
+    }
+
+    #[must_use]
+    pub fn email_subject(mut self, email_subject: String) -> Self {
+        self.email_subject = Some(email_subject);
+        self
+    }
+
+    #[must_use]
+    pub fn email_message(mut self, email_message: String) -> Self {
+        self.email_message = Some(email_message);
+        self
+    }
+
+    #[must_use]
+    pub fn note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+}
+
+/// A single payment to a receiver within a batch payout.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PayoutItem {
+    /// The type of ID that identifies the payment receiver.
+    pub recipient_type: Option<PayoutRecipientType>,
+
+    /// The currency and amount to pay the receiver.
+    pub amount: PayoutCurrency,
+
+    /// The sender-specified note for notifications.
+    pub note: Option<String>,
+
+    /// The receiver of the payment. Corresponds to the `recipient_type` value.
+    pub receiver: String,
+
+    /// The sender-specified ID number. Tracks the payout in an accounting system.
+    pub sender_item_id: Option<String>,
+
+    /// The recipient wallet, for example `PAYPAL` or `VENMO`.
+    pub recipient_wallet: Option<String>,
+
+    /// The language in which to show the payout recipient's email message.
+    pub notification_language: Option<String>,
+}
+
+impl PayoutItem {
+    /// Pays out `amount` to the PayPal account registered with the email address.
+    #[must_use]
+    pub fn email(email_address: String, amount: Money) -> Self {
+        Self::new(PayoutRecipientType::Email, email_address, amount)
+    }
+
+    /// Pays out `amount` to the PayPal account registered with the phone number.
+    #[must_use]
+    pub fn phone(phone_number: String, amount: Money) -> Self {
+        Self::new(PayoutRecipientType::Phone, phone_number, amount)
+    }
+
+    /// Pays out `amount` to the PayPal account with the encrypted account number.
+    #[must_use]
+    pub fn paypal_id(payer_id: String, amount: Money) -> Self {
+        Self::new(PayoutRecipientType::PayPalId, payer_id, amount)
+    }
+
+    fn new(recipient_type: PayoutRecipientType, receiver: String, amount: Money) -> Self {
+        Self {
+            recipient_type: Some(recipient_type),
+            amount: amount.into(),
+            receiver,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn note(mut self, note: String) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    #[must_use]
+    pub fn sender_item_id(mut self, sender_item_id: String) -> Self {
+        self.sender_item_id = Some(sender_item_id);
+        self
+    }
+
+    #[must_use]
+    pub fn notification_language(mut self, notification_language: String) -> Self {
+        self.notification_language = Some(notification_language);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreatePayoutBatchDto {
+    /// The sender-provided payout header for a payout request.
+    pub sender_batch_header: SenderBatchHeader,
+
+    /// An array of individual payout items.
+    pub items: Vec<PayoutItem>,
+}
+
+impl CreatePayoutBatchDto {
+    #[must_use]
+    pub const fn new(sender_batch_header: SenderBatchHeader) -> Self {
+        Self {
+            sender_batch_header,
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds a payout item to the batch.
+    #[must_use]
+    pub fn item(mut self, item: PayoutItem) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// The PayPal-generated header of a batch payout.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct PayoutBatchHeader {
+    /// The PayPal-generated ID for a batch payout.
+    pub payout_batch_id: String,
+
+    /// The PayPal-generated batch status.
+    pub batch_status: PayoutBatchStatus,
+
+    /// The date and time when processing for the batch began, in Internet date and time format.
+    pub time_created: Option<String>,
+
+    /// The date and time when processing for the batch completed, in Internet date and time
+    /// format.
+    pub time_completed: Option<String>,
+
+    /// The date and time when the batch was closed, in Internet date and time format.
+    pub time_closed: Option<String>,
+
+    /// The original batch header as provided by the payment sender.
+    pub sender_batch_header: Option<SenderBatchHeader>,
+
+    /// The ID of the funding source, for example `BALANCE`.
+    pub funding_source: Option<String>,
+
+    /// The currency and total amount requested for the payouts.
+    pub amount: Option<PayoutCurrency>,
+
+    /// The currency and amount of the total fee for the payouts.
+    pub fees: Option<PayoutCurrency>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreatePayoutBatchResponse {
+    /// The PayPal-generated batch header.
+    pub batch_header: PayoutBatchHeader,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl HateoasLinks for CreatePayoutBatchResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ShowPayoutBatchQuery {
+    /// The number of items to return in the response.
+    pub page_size: Option<u32>,
+
+    /// The page number that indicates which set of items to return in the response.
+    pub page: Option<u32>,
+
+    /// Indicates whether to show the total items and total pages in the response.
+    pub total_required: Option<bool>,
+}
+
+impl ShowPayoutBatchQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_total_count(mut self) -> Self {
+        self.total_required = Some(true);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ShowPayoutBatchResponse {
+    /// The PayPal-generated batch header.
+    pub batch_header: PayoutBatchHeader,
+
+    /// The payout items on this page.
+    #[serde(default = "Vec::new")]
+    pub items: Vec<PayoutItemDetails>,
+
+    /// The total number of items. Only returned when `total_required` is set.
+    pub total_items: Option<u32>,
+
+    /// The total number of pages. Only returned when `total_required` is set.
+    pub total_pages: Option<u32>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ShowPayoutBatchResponse {
+    /// Converts the response into a generic [`Page`] of payout items, dropping the batch header.
+    pub fn into_page(self) -> Page<PayoutItemDetails> {
+        Page {
+            items: self.items,
+            total_items: self.total_items,
+            total_pages: self.total_pages,
+            links: self.links,
+        }
+    }
+}
+
+/// The details of a payout item, as returned by PayPal.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct PayoutItemDetails {
+    /// The ID for the payout item.
+    pub payout_item_id: String,
+
+    /// The PayPal-generated ID for the transaction.
+    pub transaction_id: Option<String>,
+
+    /// The unique PayPal-generated common ID that links the sender- and receiver-side
+    /// transactions.
+    pub activity_id: Option<String>,
+
+    /// The transaction status.
+    pub transaction_status: Option<PayoutTransactionStatus>,
+
+    /// The fee, in U.S. dollars.
+    pub payout_item_fee: Option<PayoutCurrency>,
+
+    /// The PayPal-generated ID for the batch payout.
+    pub payout_batch_id: Option<String>,
+
+    /// The sender-specified ID for the batch payout.
+    pub sender_batch_id: Option<String>,
+
+    /// The sender-provided information for the payout item.
+    pub payout_item: PayoutItem,
+
+    /// The date and time when this item was last processed, in Internet date and time format.
+    pub time_processed: Option<String>,
+
+    /// The error details if the payout item failed.
+    pub errors: Option<PayoutItemError>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl PayoutItemDetails {
+    /// Whether the recipient has not claimed the payout yet, so it can still be cancelled.
+    pub fn is_unclaimed(&self) -> bool {
+        self.transaction_status == Some(PayoutTransactionStatus::Unclaimed)
+    }
+}
+
+impl HateoasLinks for PayoutItemDetails {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct CreatePayoutBatch {
+    body: CreatePayoutBatchDto,
+    request_id: Option<String>,
+}
+
+impl CreatePayoutBatch {
+    pub const fn new(body: CreatePayoutBatchDto) -> Self {
+        Self {
+            body,
+            request_id: None,
+        }
+    }
+
+    fn request_id(mut self, request_id: String) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+}
+
+impl Endpoint for CreatePayoutBatch {
+    type QueryParams = ();
+    type RequestBody = CreatePayoutBatchDto;
+    type ResponseBody = CreatePayoutBatchResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/payments/payouts")
+    }
+
+    fn headers(&self) -> HttpRequestHeaders {
+        HttpRequestHeaders {
+            paypal_request_id: self.request_id.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ShowPayoutBatch {
+    payout_batch_id: String,
+    query: ShowPayoutBatchQuery,
+}
+
+impl ShowPayoutBatch {
+    pub const fn new(payout_batch_id: String, query: ShowPayoutBatchQuery) -> Self {
+        Self {
+            payout_batch_id,
+            query,
+        }
+    }
+}
+
+impl Endpoint for ShowPayoutBatch {
+    type QueryParams = ShowPayoutBatchQuery;
+    type RequestBody = ();
+    type ResponseBody = ShowPayoutBatchResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/payments/payouts/{}", self.payout_batch_id))
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[derive(Debug)]
+struct ShowPayoutItem {
+    payout_item_id: String,
+}
+
+impl ShowPayoutItem {
+    pub const fn new(payout_item_id: String) -> Self {
+        Self { payout_item_id }
+    }
+}
+
+impl Endpoint for ShowPayoutItem {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = PayoutItemDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/payments/payouts-item/{}", self.payout_item_id))
+    }
+}
+
+#[derive(Debug)]
+struct CancelUnclaimedPayoutItem {
+    payout_item_id: String,
+}
+
+impl CancelUnclaimedPayoutItem {
+    pub const fn new(payout_item_id: String) -> Self {
+        Self { payout_item_id }
+    }
+}
+
+impl Endpoint for CancelUnclaimedPayoutItem {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = PayoutItemDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/payments/payouts-item/{}/cancel",
+            self.payout_item_id
+        ))
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::enums::currency_code::CurrencyCode;
+    use crate::resources::enums::payout_error_name::PayoutErrorName;
+
+    #[test]
+    fn create_payout_batch_request_body() {
+        let usd = |value: &str| Money::new(CurrencyCode::UnitedStatesDollar, value.to_string());
+        let endpoint = CreatePayoutBatch::new(
+            CreatePayoutBatchDto::new(
+                SenderBatchHeader::new("Payouts_2018_100007".to_string())
+                    .email_subject("You have a payout!".to_string()),
+            )
+            .item(
+                PayoutItem::email("receiver@example.com".to_string(), usd("9.87"))
+                    .note("Thanks for your patronage!".to_string())
+                    .sender_item_id("201403140001".to_string()),
+            )
+            .item(PayoutItem::phone("5551232368".to_string(), usd("112.34")))
+            .item(PayoutItem::paypal_id(
+                "G83JXTJ5EHCQ2".to_string(),
+                usd("5.32"),
+            )),
+        )
+        .request_id("Payouts_2018_100007".to_string());
+
+        assert_eq!(
+            endpoint.headers().paypal_request_id.as_deref(),
+            Some("Payouts_2018_100007")
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "sender_batch_header": {
+                    "sender_batch_id": "Payouts_2018_100007",
+                    "email_subject": "You have a payout!"
+                },
+                "items": [
+                    {
+                        "recipient_type": "EMAIL",
+                        "amount": {"currency": "USD", "value": "9.87"},
+                        "note": "Thanks for your patronage!",
+                        "receiver": "receiver@example.com",
+                        "sender_item_id": "201403140001"
+                    },
+                    {
+                        "recipient_type": "PHONE",
+                        "amount": {"currency": "USD", "value": "112.34"},
+                        "receiver": "5551232368"
+                    },
+                    {
+                        "recipient_type": "PAYPAL_ID",
+                        "amount": {"currency": "USD", "value": "5.32"},
+                        "receiver": "G83JXTJ5EHCQ2"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn show_payout_batch_page() {
+        let endpoint = ShowPayoutBatch::new(
+            "FYXMPQTX4JC9N".to_string(),
+            ShowPayoutBatchQuery::new()
+                .page(2)
+                .page_size(1000)
+                .with_total_count(),
+        );
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "page_size=1000&page=2&total_required=true"
+        );
+
+        let response: ShowPayoutBatchResponse = serde_json::from_str(
+            r#"{
+                "batch_header": {
+                    "payout_batch_id": "FYXMPQTX4JC9N",
+                    "batch_status": "PROCESSING",
+                    "amount": {"currency": "USD", "value": "127.53"}
+                },
+                "items": [
+                    {
+                        "payout_item_id": "DUCD6DYN2D7C2",
+                        "transaction_id": "5K7U2XSP7M2VJ",
+                        "transaction_status": "SUCCESS",
+                        "payout_batch_id": "FYXMPQTX4JC9N",
+                        "payout_item": {
+                            "recipient_type": "EMAIL",
+                            "amount": {"currency": "USD", "value": "9.87"},
+                            "receiver": "receiver@example.com"
+                        }
+                    }
+                ],
+                "total_items": 1001,
+                "total_pages": 2,
+                "links": [
+                    {"href": "https://api-m.paypal.com/v1/payments/payouts/FYXMPQTX4JC9N?page_size=1000&page=1", "rel": "prev", "method": "GET"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(response.batch_header.payout_batch_id, "FYXMPQTX4JC9N");
+        assert_eq!(
+            response.batch_header.batch_status,
+            PayoutBatchStatus::Processing
+        );
+
+        let page = response.into_page();
+        assert_eq!(page.items[0].payout_item.receiver, "receiver@example.com");
+        assert_eq!(page.total_items, Some(1001));
+        assert!(!page.has_next_page());
+    }
+
+    #[test]
+    fn show_failed_payout_item() {
+        let item: PayoutItemDetails = serde_json::from_str(
+            r#"{
+                "payout_item_id": "8AELMXH8UB2P8",
+                "transaction_id": "0C413693MN970190K",
+                "transaction_status": "FAILED",
+                "payout_item_fee": {"currency": "USD", "value": "0.00"},
+                "payout_batch_id": "Q8KVJG9TZTNN4",
+                "payout_item": {
+                    "recipient_type": "EMAIL",
+                    "amount": {"currency": "USD", "value": "9.87"},
+                    "receiver": "receiver@example.com"
+                },
+                "time_processed": "2018-01-27T10:17:41Z",
+                "errors": {
+                    "name": "RECEIVER_UNREGISTERED",
+                    "message": "Receiver is unregistered",
+                    "information_link": "https://developer.paypal.com/docs/api/payments.payouts-batch/#errors"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            item.transaction_status,
+            Some(PayoutTransactionStatus::Failed)
+        );
+        assert!(!item.is_unclaimed());
+        assert_eq!(item.payout_item_fee.unwrap().value, "0.00");
+        assert_eq!(
+            item.errors.unwrap().name,
+            Some(PayoutErrorName::ReceiverUnregistered)
+        );
+    }
+
+    #[test]
+    fn cancel_unclaimed_payout_item() {
+        let endpoint = CancelUnclaimedPayoutItem::new("5KUDKLF8SDC7S".to_string());
+        assert_eq!(
+            endpoint.path(),
+            "v1/payments/payouts-item/5KUDKLF8SDC7S/cancel"
+        );
+        assert_eq!(endpoint.request_method(), Method::POST);
+
+        let item: PayoutItemDetails = serde_json::from_str(
+            r#"{
+                "payout_item_id": "5KUDKLF8SDC7S",
+                "transaction_status": "UNCLAIMED",
+                "payout_item": {
+                    "recipient_type": "EMAIL",
+                    "amount": {"currency": "USD", "value": "9.87"},
+                    "receiver": "unregistered@example.com"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(item.is_unclaimed());
+    }
+
+    #[test]
+    fn unknown_payout_error_name() {
+        let error: PayoutItemError =
+            serde_json::from_str(r#"{"name": "SOME_NEW_ERROR", "message": "Something new"}"#)
+                .unwrap();
+
+        assert_eq!(error.name, Some(PayoutErrorName::Unknown));
+    }
+
+    #[test]
+    fn unknown_batch_and_transaction_status() {
+        let header: PayoutBatchHeader = serde_json::from_str(
+            r#"{"payout_batch_id": "Q8KVJG9TZTNN4", "batch_status": "ON_HOLD"}"#,
+        )
+        .unwrap();
+        assert_eq!(header.batch_status, PayoutBatchStatus::Unknown);
+
+        let item: PayoutItemDetails = serde_json::from_str(
+            r#"{
+                "payout_item_id": "8AELMXH8UB2P8",
+                "transaction_status": "UNDER_REVIEW",
+                "payout_item": {
+                    "recipient_type": "EMAIL",
+                    "amount": {"currency": "USD", "value": "9.87"},
+                    "receiver": "receiver@example.com"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            item.transaction_status,
+            Some(PayoutTransactionStatus::Unknown)
+        );
+        assert!(!item.is_unclaimed());
+    }
+}
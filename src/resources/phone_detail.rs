@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::phone_type::PhoneType;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PhoneDetail {
+    /// The country calling code (CC), in its canonical international E.164 numbering plan format.
+    pub country_code: String,
+
+    /// The national number, in its canonical international E.164 numbering plan format.
+    pub national_number: String,
+
+    /// The extension number.
+    pub extension_number: Option<String>,
+
+    /// The phone type.
+    pub phone_type: Option<PhoneType>,
+}
@@ -1,12 +1,30 @@
 use crate::resources::money::Money;
 use crate::resources::payee_base::PayeeBase;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PlatformFee {
     /// The fee for this transaction.
     pub amount: Money,
 
     /// The recipient of the fee for this transaction. If you omit this value, the default is the API caller.
-    pub payee: PayeeBase,
+    pub payee: Option<PayeeBase>,
+}
+
+impl PlatformFee {
+    #[must_use]
+    pub const fn new(amount: Money) -> Self {
+        Self {
+            amount,
+            payee: None,
+        }
+    }
+
+    #[must_use]
+    pub fn payee(mut self, payee: PayeeBase) -> Self {
+        self.payee = Some(payee);
+        self
+    }
 }
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::tier_mode::TierMode;
+use crate::resources::money::Money;
+
+/// The pricing scheme of a billing cycle. Plans are either billed a fixed price per cycle, or
+/// priced by quantity in tiers.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PricingScheme {
+    /// Quantity-based pricing in tiers.
+    Tiered(TieredPricing),
+    /// A fixed price per billing cycle.
+    Fixed(FixedPricing),
+    /// A pricing scheme as returned for auto billing plans, which only carries its version and
+    /// timestamps. Never matches a scheme with a price, so a malformed fixed or tiered scheme
+    /// fails to deserialize instead of silently becoming an auto billing scheme.
+    AutoBilling(AutoBillingPricing),
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FixedPricing {
+    /// The fixed amount to charge for the subscription.
+    pub fixed_price: Money,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AutoBillingPricing {
+    /// The version of the pricing scheme.
+    pub version: Option<u32>,
+
+    /// The date and time when the pricing scheme was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the pricing scheme was last updated, in Internet date and time
+    /// format.
+    pub update_time: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TieredPricing {
+    /// The pricing model for the tiers.
+    pub pricing_model: TierMode,
+
+    /// An array of pricing tiers.
+    pub tiers: Vec<PricingTier>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PricingTier {
+    /// The starting quantity for the tier.
+    pub starting_quantity: String,
+
+    /// The ending quantity for the tier. Optional for the last tier.
+    pub ending_quantity: Option<String>,
+
+    /// The price for the tier.
+    pub amount: Money,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::enums::currency_code::CurrencyCode;
+
+    #[test]
+    fn fixed_pricing_serializes_to_paypal_format() {
+        let scheme = PricingScheme::Fixed(FixedPricing {
+            fixed_price: Money::new(CurrencyCode::UnitedStatesDollar, "10".to_string()),
+        });
+
+        assert_eq!(
+            serde_json::to_string(&scheme).unwrap(),
+            r#"{"fixed_price":{"currency_code":"USD","value":"10"}}"#
+        );
+    }
+
+    #[test]
+    fn tiered_pricing_round_trips_paypal_format() {
+        let json = r#"{"pricing_model":"TIERED","tiers":[{"starting_quantity":"1","ending_quantity":"1000","amount":{"currency_code":"USD","value":"150"}},{"starting_quantity":"1001","amount":{"currency_code":"USD","value":"250"}}]}"#;
+
+        let scheme: PricingScheme = serde_json::from_str(json).unwrap();
+        match &scheme {
+            PricingScheme::Tiered(tiered) => {
+                assert_eq!(tiered.pricing_model, TierMode::Graduated);
+                assert_eq!(tiered.tiers.len(), 2);
+                assert!(tiered.tiers[1].ending_quantity.is_none());
+            }
+            other => panic!("expected tiered pricing, got {other:?}"),
+        }
+
+        assert_eq!(serde_json::to_string(&scheme).unwrap(), json);
+    }
+
+    #[test]
+    fn auto_billing_pricing_only_matches_schemes_without_price() {
+        let scheme: PricingScheme = serde_json::from_str(
+            r#"{"version":1,"create_time":"2020-02-20T10:31:12Z","update_time":"2020-02-20T10:31:12Z"}"#,
+        )
+        .unwrap();
+        assert!(matches!(scheme, PricingScheme::AutoBilling(_)));
+
+        let missing_currency = r#"{"fixed_price":{"value":"10"}}"#;
+        assert!(serde_json::from_str::<PricingScheme>(missing_currency).is_err());
+
+        let missing_tiers = r#"{"pricing_model":"VOLUME","create_time":"2020-02-20T10:31:12Z"}"#;
+        assert!(serde_json::from_str::<PricingScheme>(missing_tiers).is_err());
+    }
+}
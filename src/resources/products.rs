@@ -0,0 +1,562 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::client::request::HttpRequestHeaders;
+use crate::resources::enums::op::Op;
+use crate::resources::enums::product_category::ProductCategory;
+use crate::resources::enums::product_type::ProductType;
+use crate::resources::link_description::LinkDescription;
+use crate::resources::page::Page;
+use crate::resources::patch::{Patch, PatchValue};
+
+pub struct Product;
+
+impl Product {
+    /// Creates a product.
+    pub async fn create(
+        client: &Client,
+        dto: CreateProductDto,
+    ) -> Result<ProductDetails, PayPalError> {
+        client.post(&CreateProduct::new(dto)).await
+    }
+
+    /// Creates a product, sending `request_id` as the `PayPal-Request-Id` header. Retrying the
+    /// call with the same `request_id` returns the product created by the first call.
+    pub async fn create_with_request_id(
+        client: &Client,
+        dto: CreateProductDto,
+        request_id: String,
+    ) -> Result<ProductDetails, PayPalError> {
+        client
+            .post(&CreateProduct::new(dto).request_id(request_id))
+            .await
+    }
+
+    /// Shows details for a product, by ID.
+    pub async fn show(client: &Client, product_id: &str) -> Result<ProductDetails, PayPalError> {
+        client.get(&ShowProduct::new(product_id.to_string())).await
+    }
+
+    /// Updates a product, by ID. Only the `description`, `category`, `image_url` and `home_url`
+    /// attributes can be updated. See [`ProductPatch`] for the supported operations.
+    pub async fn update(
+        client: &Client,
+        product_id: &str,
+        patch: Vec<Patch>,
+    ) -> Result<(), PayPalError> {
+        client
+            .patch(&UpdateProduct::new(product_id.to_string(), patch))
+            .await?;
+        Ok(())
+    }
+
+    /// Lists catalog products.
+    pub async fn list(
+        client: &Client,
+        query: ListProductsQuery,
+    ) -> Result<ListProductsResponse, PayPalError> {
+        client.get(&ListProducts::new(query)).await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateProductDto {
+    /// The ID of the product. If omitted, PayPal generates an ID with a `PROD-` prefix.
+    pub id: Option<String>,
+
+    /// The product name.
+    pub name: String,
+
+    /// The product description.
+    pub description: Option<String>,
+
+    /// The product type. Indicates whether the product is physical or digital goods, or a service.
+    #[serde(rename = "type")]
+    pub type_: ProductType,
+
+    /// The product category.
+    pub category: Option<ProductCategory>,
+
+    /// The image URL for the product.
+    pub image_url: Option<String>,
+
+    /// The home page URL for the product.
+    pub home_url: Option<String>,
+}
+
+impl CreateProductDto {
+    #[must_use]
+    pub const fn new(name: String, type_: ProductType) -> Self {
+        Self {
+            id: None,
+            name,
+            description: None,
+            type_,
+            category: None,
+            image_url: None,
+            home_url: None,
+        }
+    }
+
+    #[must_use]
+    pub fn id(mut self, id: String) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    #[must_use]
+    pub const fn category(mut self, category: ProductCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    #[must_use]
+    pub fn image_url(mut self, image_url: String) -> Self {
+        self.image_url = Some(image_url);
+        self
+    }
+
+    #[must_use]
+    pub fn home_url(mut self, home_url: String) -> Self {
+        self.home_url = Some(home_url);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProductDetails {
+    /// The ID of the product.
+    pub id: String,
+
+    /// The product name.
+    pub name: String,
+
+    /// The product description.
+    pub description: Option<String>,
+
+    /// The product type.
+    #[serde(rename = "type")]
+    pub type_: Option<ProductType>,
+
+    /// The product category.
+    pub category: Option<ProductCategory>,
+
+    /// The image URL for the product.
+    pub image_url: Option<String>,
+
+    /// The home page URL for the product.
+    pub home_url: Option<String>,
+
+    /// The date and time when the product was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the product was last updated, in Internet date and time format.
+    pub update_time: Option<String>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+#[derive(Debug)]
+struct CreateProduct {
+    body: CreateProductDto,
+    request_id: Option<String>,
+}
+
+impl CreateProduct {
+    pub const fn new(body: CreateProductDto) -> Self {
+        Self {
+            body,
+            request_id: None,
+        }
+    }
+
+    fn request_id(mut self, request_id: String) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+}
+
+impl Endpoint for CreateProduct {
+    type QueryParams = ();
+    type RequestBody = CreateProductDto;
+    type ResponseBody = ProductDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/catalogs/products")
+    }
+
+    fn headers(&self) -> HttpRequestHeaders {
+        HttpRequestHeaders {
+            paypal_request_id: self.request_id.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ShowProduct {
+    product_id: String,
+}
+
+impl ShowProduct {
+    pub const fn new(product_id: String) -> Self {
+        Self { product_id }
+    }
+}
+
+impl Endpoint for ShowProduct {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ProductDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/catalogs/products/{}", self.product_id))
+    }
+}
+
+/// Typed patch operations for [`Product::update`].
+pub struct ProductPatch;
+
+impl ProductPatch {
+    /// Replaces the description of the product, or adds one if the product has none yet.
+    pub fn replace_description(description: String) -> Patch {
+        Self::replace("/description", PatchValue::String(description))
+    }
+
+    /// Replaces the category of the product.
+    pub fn replace_category(category: ProductCategory) -> Patch {
+        Self::replace(
+            "/category",
+            PatchValue::String(category.as_str().to_string()),
+        )
+    }
+
+    /// Replaces the image URL of the product.
+    pub fn replace_image_url(image_url: String) -> Patch {
+        Self::replace("/image_url", PatchValue::String(image_url))
+    }
+
+    /// Replaces the home page URL of the product.
+    pub fn replace_home_url(home_url: String) -> Patch {
+        Self::replace("/home_url", PatchValue::String(home_url))
+    }
+
+    /// Removes the description of the product.
+    pub fn remove_description() -> Patch {
+        Patch::new(Op::Remove).path("/description".to_string())
+    }
+
+    fn replace(path: &str, value: PatchValue) -> Patch {
+        Patch::new(Op::Replace).path(path.to_string()).value(value)
+    }
+}
+
+#[derive(Debug)]
+struct UpdateProduct {
+    product_id: String,
+    body: Vec<Patch>,
+}
+
+impl UpdateProduct {
+    pub const fn new(product_id: String, body: Vec<Patch>) -> Self {
+        Self { product_id, body }
+    }
+}
+
+impl Endpoint for UpdateProduct {
+    type QueryParams = ();
+    type RequestBody = Vec<Patch>;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/catalogs/products/{}", self.product_id))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::PATCH
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListProductsQuery {
+    /// Filters the response by the product type.
+    #[serde(rename = "type")]
+    pub type_: Option<ProductType>,
+
+    /// Filters the response by the product category.
+    pub category: Option<ProductCategory>,
+
+    /// The number of items to return in the response.
+    pub page_size: Option<u32>,
+
+    /// A non-zero integer which is the start index of the entire list of items to return in the
+    /// response.
+    pub page: Option<u32>,
+
+    /// Indicates whether to show the total items and total pages in the response.
+    pub total_required: Option<bool>,
+
+    /// The cursor of the page to return, as returned in `next_page_token` of a previous response.
+    pub page_token: Option<String>,
+}
+
+impl ListProductsQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn type_(mut self, type_: ProductType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    #[must_use]
+    pub const fn category(mut self, category: ProductCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    #[must_use]
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    #[must_use]
+    pub const fn total_required(mut self, total_required: bool) -> Self {
+        self.total_required = Some(total_required);
+        self
+    }
+
+    /// Requests the `total_items` and `total_pages` fields in the response.
+    #[must_use]
+    pub const fn with_total_count(self) -> Self {
+        self.total_required(true)
+    }
+
+    #[must_use]
+    pub fn page_token(mut self, page_token: String) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ListProductsResponse {
+    /// An array of products, in their short form.
+    #[serde(rename = "products", default = "Vec::new")]
+    pub product_summaries: Vec<ProductSummary>,
+
+    /// The total number of items. Only returned when `total_required` is set.
+    pub total_items: Option<u32>,
+
+    /// The total number of pages. Only returned when `total_required` is set.
+    pub total_pages: Option<u32>,
+
+    /// The cursor of the next page. Pass it as `page_token` to fetch the next page.
+    pub next_page_token: Option<String>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ListProductsResponse {
+    /// Converts the response into a generic [`Page`] of products.
+    pub fn into_page(self) -> Page<ProductSummary> {
+        Page {
+            items: self.product_summaries,
+            total_items: self.total_items,
+            total_pages: self.total_pages,
+            links: self.links,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ProductSummary {
+    /// The ID of the product.
+    pub id: String,
+
+    /// The product name.
+    pub name: Option<String>,
+
+    /// The product description.
+    pub description: Option<String>,
+
+    /// The date and time when the product was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+#[derive(Debug)]
+struct ListProducts {
+    query: ListProductsQuery,
+}
+
+impl ListProducts {
+    pub const fn new(query: ListProductsQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListProducts {
+    type QueryParams = ListProductsQuery;
+    type RequestBody = ();
+    type ResponseBody = ListProductsResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/catalogs/products")
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_product_request() {
+        let endpoint = CreateProduct::new(
+            CreateProductDto::new("Video Streaming Service".to_string(), ProductType::Service)
+                .description("Video streaming service".to_string())
+                .category(ProductCategory::Software)
+                .home_url("https://example.com/home".to_string()),
+        )
+        .request_id("PRODUCT-18062020-001".to_string());
+
+        assert_eq!(
+            endpoint.headers().paypal_request_id.as_deref(),
+            Some("PRODUCT-18062020-001")
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "name": "Video Streaming Service",
+                "description": "Video streaming service",
+                "type": "SERVICE",
+                "category": "SOFTWARE",
+                "home_url": "https://example.com/home"
+            })
+        );
+    }
+
+    #[test]
+    fn update_product_request_body() {
+        let endpoint = UpdateProduct::new(
+            "72255d4849af8ed6e0df1173".to_string(),
+            vec![
+                ProductPatch::replace_description("Premium video streaming".to_string()),
+                ProductPatch::replace_home_url("https://example.com/streaming".to_string()),
+                ProductPatch::remove_description(),
+            ],
+        );
+
+        assert_eq!(
+            endpoint.path(),
+            "v1/catalogs/products/72255d4849af8ed6e0df1173"
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!([
+                {"op": "replace", "path": "/description", "value": "Premium video streaming"},
+                {"op": "replace", "path": "/home_url", "value": "https://example.com/streaming"},
+                {"op": "remove", "path": "/description"}
+            ])
+        );
+    }
+
+    #[test]
+    fn list_products_page() {
+        let query = ListProductsQuery::new()
+            .page_size(2)
+            .page(1)
+            .with_total_count();
+        assert_eq!(
+            serde_qs::to_string(&query).unwrap(),
+            "page_size=2&page=1&total_required=true"
+        );
+
+        let response: ListProductsResponse = serde_json::from_str(
+            r#"{
+                "products": [
+                    {"id": "72255d4849af8ed6e0df1173", "name": "Video Streaming Service"},
+                    {"id": "PROD-XYAB12ABSB7868434", "name": "Video Streaming Service"}
+                ],
+                "total_items": 20,
+                "total_pages": 10,
+                "links": [
+                    {
+                        "href": "https://api-m.paypal.com/v1/catalogs/products?page_size=2&page=2",
+                        "rel": "next",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let page = response.into_page();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.total_pages, Some(10));
+        assert!(page.has_next_page());
+    }
+
+    #[test]
+    fn product_with_unknown_type_and_category() {
+        let product: ProductDetails = serde_json::from_str(
+            r#"{
+                "id": "72255d4849af8ed6e0df1173",
+                "name": "Video Streaming Service",
+                "type": "SUBSCRIPTION_BOX",
+                "category": "SPACE_TOURISM"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(product.type_, Some(ProductType::Unknown));
+        assert_eq!(product.category, Some(ProductCategory::Unknown));
+    }
+}
@@ -13,6 +13,7 @@ pub struct PurchaseUnitRequest {
     /// The API caller-provided external ID for the purchase unit.
     /// Required for multiple purchase units when you must update the order through PATCH.
     /// If you omit this value and the order contains only one purchase unit, PayPal sets this value to default.
+    #[serde(rename = "reference_id")]
     pub reference_ids: Option<String>,
 
     /// The total order amount with an optional breakdown that provides details,
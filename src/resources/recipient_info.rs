@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::name::Name;
+use crate::resources::phone_detail::PhoneDetail;
+use crate::resources::shipping_detail_address_portable::ShippingDetailAddressPortable;
+
+/// The billing and shipping information of an invoice recipient.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RecipientInfo {
+    /// The billing information for the invoice recipient. Includes name, address, email, phone,
+    /// and language.
+    pub billing_info: Option<BillingInfo>,
+
+    /// The recipient's shipping information. Includes the user's contact information, which
+    /// includes name and address.
+    pub shipping_info: Option<ContactInformation>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingInfo {
+    /// Required. The business name of the party.
+    pub business_name: Option<String>,
+
+    /// The first and last name of the party.
+    pub name: Option<Name>,
+
+    /// The portable international postal address.
+    pub address: Option<ShippingDetailAddressPortable>,
+
+    /// The invoice recipient email address. If you omit this value, the invoice is payable and a
+    /// notification email is not sent.
+    pub email_address: Option<String>,
+
+    /// The invoice recipient's phone numbers.
+    pub phones: Option<Vec<PhoneDetail>>,
+
+    /// Any additional information about the recipient.
+    pub additional_info: Option<String>,
+
+    /// The language in which to show the invoice recipient's email message.
+    pub language: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ContactInformation {
+    /// Required. The business name of the party.
+    pub business_name: Option<String>,
+
+    /// The first and last name of the party.
+    pub name: Option<Name>,
+
+    /// The portable international postal address.
+    pub address: Option<ShippingDetailAddressPortable>,
+}
@@ -0,0 +1,460 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::Endpoint;
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::enums::link_rel::LinkRel;
+use crate::resources::enums::referenced_payout_item_status::ReferencedPayoutItemStatus;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+use crate::resources::money::Money;
+use crate::resources::page::Page;
+use crate::resources::payout_item_error::PayoutItemError;
+
+pub struct ReferencedPayout;
+
+impl ReferencedPayout {
+    /// Creates a referenced batch payout for asynchronous, offline processing. Each item
+    /// disburses the funds of a transaction that was captured with the `DELAYED` disbursement
+    /// mode. Use the ID of the created batch to list the status of its items.
+    pub async fn create_batch(
+        client: &Client,
+        dto: CreateRefPayoutBatchDto,
+    ) -> Result<CreateRefPayoutBatchResponse, PayPalError> {
+        client.post(&CreateRefPayoutBatch::new(dto)).await
+    }
+
+    /// Lists details for the items in a referenced batch payout, by batch ID.
+    pub async fn list_items(
+        client: &Client,
+        batch_id: &str,
+        query: ListRefPayoutItemsQuery,
+    ) -> Result<ListRefPayoutItemsResponse, PayPalError> {
+        client
+            .get(&ListRefPayoutItems::new(batch_id.to_string(), query))
+            .await
+    }
+
+    /// Creates a referenced payout item, which disburses the funds of a single transaction
+    /// synchronously.
+    pub async fn create_item(
+        client: &Client,
+        dto: CreateRefPayoutItemDto,
+    ) -> Result<ReferencedPayoutItemDetail, PayPalError> {
+        client.post(&CreateRefPayoutItem::new(dto)).await
+    }
+
+    /// Shows details for a referenced payout item, by ID.
+    pub async fn show_item(
+        client: &Client,
+        payout_item_id: &str,
+    ) -> Result<ReferencedPayoutItemDetail, PayPalError> {
+        client
+            .get(&ShowRefPayoutItem::new(payout_item_id.to_string()))
+            .await
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateRefPayoutItemDto {
+    /// The ID of the transaction to disburse, usually a capture ID.
+    pub reference_id: String,
+
+    /// The type of the reference. Only `TRANSACTION_ID` is supported.
+    pub reference_type: String,
+}
+
+impl CreateRefPayoutItemDto {
+    /// Disburses the funds of the transaction with the given ID.
+    #[must_use]
+    pub fn transaction(transaction_id: String) -> Self {
+        Self {
+            reference_id: transaction_id,
+            reference_type: "TRANSACTION_ID".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateRefPayoutBatchDto {
+    /// An array of the transactions to disburse.
+    pub referenced_payouts: Vec<CreateRefPayoutItemDto>,
+}
+
+impl CreateRefPayoutBatchDto {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the transaction with the given ID to the batch.
+    #[must_use]
+    pub fn transaction(mut self, transaction_id: String) -> Self {
+        self.referenced_payouts
+            .push(CreateRefPayoutItemDto::transaction(transaction_id));
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateRefPayoutBatchResponse {
+    /// An array of request-related HATEOAS links, including the link to the created batch.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl CreateRefPayoutBatchResponse {
+    /// The ID of the created batch, taken from the `self` link.
+    pub fn batch_id(&self) -> Option<&str> {
+        let href = self.link(LinkRel::SelfLink)?.href.as_str();
+        href.trim_end_matches('/').rsplit('/').next()
+    }
+}
+
+impl HateoasLinks for CreateRefPayoutBatchResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListRefPayoutItemsQuery {
+    /// The number of items to return in the response.
+    pub page_size: Option<u32>,
+
+    /// The page number that indicates which set of items to return in the response.
+    pub page: Option<u32>,
+
+    /// Indicates whether to show the total count in the response.
+    pub total_required: Option<bool>,
+}
+
+impl ListRefPayoutItemsQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    #[must_use]
+    pub const fn total_required(mut self, total_required: bool) -> Self {
+        self.total_required = Some(total_required);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListRefPayoutItemsResponse {
+    /// An array of the items in the referenced batch payout.
+    #[serde(default = "Vec::new")]
+    pub referenced_payouts: Vec<ReferencedPayoutItemDetail>,
+
+    /// An array of request-related HATEOAS links, including the links to the previous and next
+    /// pages.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ListRefPayoutItemsResponse {
+    /// Converts the response into a generic [`Page`] of referenced payout items.
+    pub fn into_page(self) -> Page<ReferencedPayoutItemDetail> {
+        Page {
+            items: self.referenced_payouts,
+            total_items: None,
+            total_pages: None,
+            links: self.links,
+        }
+    }
+}
+
+impl HateoasLinks for ListRefPayoutItemsResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReferencedPayoutItemDetail {
+    /// The ID of the referenced payout item.
+    #[serde(alias = "item_id")]
+    pub payout_item_id: String,
+
+    /// The PayPal-generated ID for the payout transaction.
+    #[serde(alias = "payout_transaction_id")]
+    pub transaction_id: Option<String>,
+
+    /// The transaction status of the payout item.
+    pub transaction_status: Option<ReferencedPayoutItemStatus>,
+
+    /// The amount that is paid out to the payee.
+    pub payout_amount: Option<Money>,
+
+    /// The encrypted PayPal account number of the payee.
+    pub payout_destination: Option<String>,
+
+    /// The ID of the transaction that is referenced by this payout, usually a capture ID.
+    pub reference_id: Option<String>,
+
+    /// The type of the referenced transaction.
+    pub reference_type: Option<String>,
+
+    /// The processing state of the payout item.
+    pub processing_state: Option<ReferencedPayoutProcessingState>,
+
+    /// The date and time when the payout item was created, in Internet date and time format.
+    pub time_created: Option<String>,
+
+    /// The date and time when the payout item was processed, in Internet date and time format.
+    pub time_processed: Option<String>,
+
+    /// The error details if the payout item failed.
+    pub errors: Option<PayoutItemError>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReferencedPayoutProcessingState {
+    /// The processing status of the payout item.
+    pub status: ReferencedPayoutItemStatus,
+
+    /// The reason for the processing status, if any.
+    pub reason: Option<String>,
+}
+
+#[derive(Debug)]
+struct ListRefPayoutItems {
+    batch_id: String,
+    query: ListRefPayoutItemsQuery,
+}
+
+impl ListRefPayoutItems {
+    pub const fn new(batch_id: String, query: ListRefPayoutItemsQuery) -> Self {
+        Self { batch_id, query }
+    }
+}
+
+impl Endpoint for ListRefPayoutItems {
+    type QueryParams = ListRefPayoutItemsQuery;
+    type RequestBody = ();
+    type ResponseBody = ListRefPayoutItemsResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/payments/referenced-payouts/{}", self.batch_id))
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[derive(Debug)]
+struct CreateRefPayoutBatch {
+    body: CreateRefPayoutBatchDto,
+}
+
+impl CreateRefPayoutBatch {
+    pub const fn new(body: CreateRefPayoutBatchDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateRefPayoutBatch {
+    type QueryParams = ();
+    type RequestBody = CreateRefPayoutBatchDto;
+    type ResponseBody = CreateRefPayoutBatchResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/payments/referenced-payouts")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct CreateRefPayoutItem {
+    body: CreateRefPayoutItemDto,
+}
+
+impl CreateRefPayoutItem {
+    pub const fn new(body: CreateRefPayoutItemDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateRefPayoutItem {
+    type QueryParams = ();
+    type RequestBody = CreateRefPayoutItemDto;
+    type ResponseBody = ReferencedPayoutItemDetail;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/payments/referenced-payouts-items")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ShowRefPayoutItem {
+    payout_item_id: String,
+}
+
+impl ShowRefPayoutItem {
+    pub const fn new(payout_item_id: String) -> Self {
+        Self { payout_item_id }
+    }
+}
+
+impl Endpoint for ShowRefPayoutItem {
+    type QueryParams = ();
+    type RequestBody = ();
+    type ResponseBody = ReferencedPayoutItemDetail;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/payments/referenced-payouts-items/{}",
+            self.payout_item_id
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_referenced_payout_batch() {
+        let endpoint = CreateRefPayoutBatch::new(
+            CreateRefPayoutBatchDto::new()
+                .transaction("2KP03934U4415543C".to_string())
+                .transaction("8TA4226978212399L".to_string()),
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "referenced_payouts": [
+                    {"reference_id": "2KP03934U4415543C", "reference_type": "TRANSACTION_ID"},
+                    {"reference_id": "8TA4226978212399L", "reference_type": "TRANSACTION_ID"}
+                ]
+            })
+        );
+
+        let response: CreateRefPayoutBatchResponse = serde_json::from_str(
+            r#"{
+                "links": [
+                    {
+                        "href": "https://api-m.paypal.com/v1/payments/referenced-payouts/CDZEC5MJ8R5HY",
+                        "rel": "self",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(response.batch_id(), Some("CDZEC5MJ8R5HY"));
+    }
+
+    #[test]
+    fn list_referenced_payout_items_response() {
+        let endpoint = ListRefPayoutItems::new(
+            "CDZEC5MJ8R5HY".to_string(),
+            ListRefPayoutItemsQuery::new().page_size(1),
+        );
+        assert_eq!(
+            endpoint.path(),
+            "v1/payments/referenced-payouts/CDZEC5MJ8R5HY"
+        );
+
+        let response: ListRefPayoutItemsResponse = serde_json::from_str(
+            r#"{
+                "referenced_payouts": [
+                    {
+                        "item_id": "CDZEC5MJ8R5HY-1",
+                        "processing_state": {
+                            "status": "SUCCESS"
+                        },
+                        "reference_id": "2KP03934U4415543C",
+                        "reference_type": "TRANSACTION_ID",
+                        "payout_amount": {
+                            "currency_code": "USD",
+                            "value": "10.00"
+                        },
+                        "payout_destination": "9C8SEAESMWFKA",
+                        "payout_transaction_id": "0SH34856GA1823709"
+                    }
+                ],
+                "links": [
+                    {
+                        "href": "https://api-m.paypal.com/v1/payments/referenced-payouts/CDZEC5MJ8R5HY?page_size=1&page=2",
+                        "rel": "next",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let page = response.into_page();
+        assert_eq!(page.items.len(), 1);
+        assert!(page.has_next_page());
+
+        let item = &page.items[0];
+        assert_eq!(item.payout_item_id, "CDZEC5MJ8R5HY-1");
+        assert_eq!(item.transaction_id.as_deref(), Some("0SH34856GA1823709"));
+        assert_eq!(item.reference_id.as_deref(), Some("2KP03934U4415543C"));
+        assert_eq!(
+            item.processing_state.as_ref().unwrap().status,
+            ReferencedPayoutItemStatus::Success
+        );
+    }
+
+    #[test]
+    fn unknown_referenced_payout_item_status() {
+        let item: ReferencedPayoutItemDetail = serde_json::from_str(
+            r#"{
+                "item_id": "CDZEC5MJ8R5HY-1",
+                "transaction_status": "PARTIALLY_REVERSED",
+                "processing_state": {
+                    "status": "UNDER_REVIEW"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            item.transaction_status,
+            Some(ReferencedPayoutItemStatus::Unknown)
+        );
+        assert_eq!(
+            item.processing_state.unwrap().status,
+            ReferencedPayoutItemStatus::Unknown
+        );
+    }
+}
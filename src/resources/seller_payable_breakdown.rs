@@ -31,6 +31,7 @@ pub struct SellerPayableBreakdown {
     pub paypal_fee_in_receivable_currency: Option<Money>,
 
     /// An array of platform or partner fees, commissions, or brokerage fees for the refund.
+    #[serde(default)]
     pub platform_fees: Vec<PlatformFee>,
 
     /// The total amount refunded from the original capture to date. For example, if a payer makes a $100 purchase and was refunded $20 a week
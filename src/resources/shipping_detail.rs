@@ -1,6 +1,7 @@
 use crate::resources::enums::shipping_type::ShippingType;
 use crate::resources::shipping_detail_address_portable::ShippingDetailAddressPortable;
 use crate::resources::shipping_detail_name::ShippingDetailName;
+use crate::resources::tracker::Tracker;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -18,4 +19,35 @@ pub struct ShippingDetail {
     /// The address of the person to whom to ship the items. Supports only the address_line_1, address_line_2, admin_area_1, admin_area_2,
     /// postal_code, and country_code properties.
     pub address: Option<ShippingDetailAddressPortable>,
+
+    /// The trackers of the shipments of a captured order. Only returned by PayPal.
+    pub trackers: Option<Vec<Tracker>>,
+}
+
+impl ShippingDetail {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn name(mut self, full_name: String) -> Self {
+        self.name = Some(ShippingDetailName {
+            full_name,
+            type_: None,
+        });
+        self
+    }
+
+    #[must_use]
+    pub const fn type_(mut self, type_: ShippingType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    #[must_use]
+    pub fn address(mut self, address: ShippingDetailAddressPortable) -> Self {
+        self.address = Some(address);
+        self
+    }
 }
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::name::Name;
+use crate::resources::phone_with_type::PhoneWithType;
+use crate::resources::shipping_detail::ShippingDetail;
+
+/// The subscriber of a subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Subscriber {
+    /// The name of the subscriber.
+    pub name: Option<Name>,
+
+    /// The email address of the subscriber.
+    pub email_address: Option<String>,
+
+    /// The PayPal-assigned ID for the subscriber. Only returned by PayPal.
+    pub payer_id: Option<String>,
+
+    /// The phone number of the subscriber.
+    pub phone: Option<PhoneWithType>,
+
+    /// The shipping details of the subscriber.
+    pub shipping_address: Option<ShippingDetail>,
+}
+
+impl Subscriber {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: Name) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    #[must_use]
+    pub fn email_address(mut self, email_address: String) -> Self {
+        self.email_address = Some(email_address);
+        self
+    }
+
+    #[must_use]
+    pub fn phone(mut self, phone: PhoneWithType) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    #[must_use]
+    pub fn shipping_address(mut self, shipping_address: ShippingDetail) -> Self {
+        self.shipping_address = Some(shipping_address);
+        self
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::payee_preferred::PayeePreferred;
+use crate::resources::enums::shipping_preference::ShippingPreference;
+use crate::resources::enums::subscription_user_action::SubscriptionUserAction;
+
+/// Customizes the payer experience during the subscription approval process with PayPal.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionApplicationContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+
+    /// The BCP 47-formatted locale of pages that the PayPal payment experience shows.
+    pub locale: Option<String>,
+
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+
+    /// Configures the label name to Continue or Subscribe Now for subscription consent experience.
+    pub user_action: Option<SubscriptionUserAction>,
+
+    /// The customer and merchant payment preferences.
+    pub payment_method: Option<SubscriptionPaymentMethod>,
+
+    /// The URL where the customer is redirected after the customer approves the payment.
+    pub return_url: Option<String>,
+
+    /// The URL where the customer is redirected after the customer cancels the payment.
+    pub cancel_url: Option<String>,
+}
+
+impl SubscriptionApplicationContext {
+    /// An application context redirecting the customer to `return_url` after approving and to
+    /// `cancel_url` after cancelling the subscription.
+    #[must_use]
+    pub fn new(return_url: String, cancel_url: String) -> Self {
+        Self {
+            return_url: Some(return_url),
+            cancel_url: Some(cancel_url),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn brand_name(mut self, brand_name: String) -> Self {
+        self.brand_name = Some(brand_name);
+        self
+    }
+
+    #[must_use]
+    pub fn locale(mut self, locale: String) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    #[must_use]
+    pub const fn shipping_preference(mut self, shipping_preference: ShippingPreference) -> Self {
+        self.shipping_preference = Some(shipping_preference);
+        self
+    }
+
+    #[must_use]
+    pub const fn user_action(mut self, user_action: SubscriptionUserAction) -> Self {
+        self.user_action = Some(user_action);
+        self
+    }
+
+    #[must_use]
+    pub fn payment_method(mut self, payment_method: SubscriptionPaymentMethod) -> Self {
+        self.payment_method = Some(payment_method);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionPaymentMethod {
+    /// The customer-selected payment method on the merchant site, for example `PAYPAL`.
+    pub payer_selected: Option<String>,
+
+    /// The merchant-preferred payment methods.
+    pub payee_preferred: Option<PayeePreferred>,
+}
@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::tenure_type::TenureType;
+use crate::resources::money::Money;
+
+/// The billing details of a subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionBillingInfo {
+    /// The total pending bill amount, to be paid by the subscriber.
+    pub outstanding_balance: Option<Money>,
+
+    /// The trial and regular billing executions.
+    #[serde(default = "Vec::new")]
+    pub cycle_executions: Vec<CycleExecution>,
+
+    /// The details for the last payment of the subscription.
+    pub last_payment: Option<LastPaymentDetails>,
+
+    /// The next date and time for billing this subscription, in Internet date and time format.
+    pub next_billing_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the final billing cycle occurs, in Internet date and time format.
+    pub final_payment_time: Option<DateTime<Utc>>,
+
+    /// The number of consecutive payment failures. Resets to `0` after a successful payment.
+    #[serde(default)]
+    pub failed_payments_count: u32,
+
+    /// The details for the last failed payment of the subscription. Only returned when showing a
+    /// subscription with the `last_failed_payment` field.
+    pub last_failed_payment: Option<FailedPaymentDetails>,
+}
+
+impl SubscriptionBillingInfo {
+    /// Whether the subscriber has an outstanding balance to pay.
+    pub fn has_outstanding_balance(&self) -> bool {
+        self.outstanding_balance
+            .as_ref()
+            .and_then(|balance| balance.value.parse::<f64>().ok())
+            .map_or(false, |value| value > 0.0)
+    }
+}
+
+/// The details of the executions of a billing cycle.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CycleExecution {
+    /// The type of the billing cycle.
+    pub tenure_type: TenureType,
+
+    /// The order in which to run this cycle among other billing cycles.
+    pub sequence: u32,
+
+    /// The number of billing cycles that have completed.
+    pub cycles_completed: u32,
+
+    /// For a finite billing cycle, the number of cycles that remain.
+    pub cycles_remaining: Option<u32>,
+
+    /// The active pricing scheme version for the billing cycle.
+    pub current_pricing_scheme_version: Option<u32>,
+
+    /// The number of times this billing cycle gets executed. `0` means infinite cycles.
+    pub total_cycles: Option<u32>,
+}
+
+/// The details of the last payment of a subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LastPaymentDetails {
+    /// The last payment amount.
+    pub amount: Money,
+
+    /// The date and time when the last payment was made, in Internet date and time format.
+    pub time: DateTime<Utc>,
+}
+
+/// The details of the last failed payment of a subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FailedPaymentDetails {
+    /// The failed payment amount.
+    pub amount: Money,
+
+    /// The date and time when the failed payment was made, in Internet date and time format.
+    pub time: DateTime<Utc>,
+
+    /// The reason code for the payment failure, for example `PAYMENT_DENIED`.
+    pub reason_code: Option<String>,
+
+    /// The time when the retry attempt for the failed payment occurs, in Internet date and time
+    /// format.
+    pub next_payment_retry_time: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::subscription_transaction_status::SubscriptionTransactionStatus;
+use crate::resources::money::Money;
+use crate::resources::name::Name;
+
+/// A payment transaction of a subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionTransaction {
+    /// The PayPal-generated transaction ID.
+    pub id: Option<String>,
+
+    /// The status of the captured payment.
+    pub status: Option<SubscriptionTransactionStatus>,
+
+    /// The breakdown details for the amount. Includes the gross, tax, fee, and shipping amounts.
+    pub amount_with_breakdown: Option<SubscriptionAmountWithBreakdown>,
+
+    /// The name of the customer.
+    pub payer_name: Option<Name>,
+
+    /// The email ID of the customer.
+    pub payer_email: Option<String>,
+
+    /// The date and time when the transaction was processed, in Internet date and time format.
+    pub time: Option<DateTime<Utc>>,
+}
+
+/// The breakdown details for the amount of a subscription transaction.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionAmountWithBreakdown {
+    /// The amount for this subscribed payment.
+    pub gross_amount: Money,
+
+    /// The fee details for the transaction.
+    pub fee_amount: Option<Money>,
+
+    /// The shipping amount for the transaction.
+    pub shipping_amount: Option<Money>,
+
+    /// The tax amount for the transaction.
+    pub tax_amount: Option<Money>,
+
+    /// The net amount that the payee receives for this transaction in their PayPal account.
+    pub net_amount: Option<Money>,
+}
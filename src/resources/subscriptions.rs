@@ -0,0 +1,1173 @@
+use std::borrow::Cow;
+
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::billing_plans::BillingPlanDetails;
+use crate::resources::enums::capture_type::CaptureType;
+use crate::resources::enums::subscription_status::SubscriptionStatus;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+use crate::resources::money::Money;
+use crate::resources::page::Page;
+use crate::resources::payment_preferences::PaymentPreferences;
+use crate::resources::pricing_scheme::PricingScheme;
+use crate::resources::shipping_detail::ShippingDetail;
+use crate::resources::subscriber::Subscriber;
+use crate::resources::subscription_application_context::SubscriptionApplicationContext;
+use crate::resources::subscription_billing_info::SubscriptionBillingInfo;
+use crate::resources::subscription_transaction::SubscriptionTransaction;
+use crate::resources::taxes::Taxes;
+
+pub struct Subscription;
+
+impl Subscription {
+    /// Creates a subscription for a plan. The buyer has to approve the subscription on the URL
+    /// returned by [`HateoasLinks::approve_url`] before it becomes active.
+    pub async fn create(
+        client: &Client,
+        dto: CreateSubscriptionDto,
+    ) -> Result<SubscriptionDetails, PayPalError> {
+        client.post(&CreateSubscription::new(dto)).await
+    }
+
+    /// Updates the quantity of the product or service in a subscription, or switches it to
+    /// another plan of the same product. If the buyer has to consent to the change, the response
+    /// contains the approval link, see [`HateoasLinks::approve_url`].
+    pub async fn revise(
+        client: &Client,
+        subscription_id: &str,
+        dto: ReviseSubscriptionDto,
+    ) -> Result<ReviseSubscriptionResponse, PayPalError> {
+        client
+            .post(&ReviseSubscription::new(subscription_id.to_string(), dto))
+            .await
+    }
+
+    /// Suspends a subscription, by ID. No payments are collected while it is suspended.
+    pub async fn suspend(
+        client: &Client,
+        subscription_id: &str,
+        reason: &str,
+    ) -> Result<(), PayPalError> {
+        client
+            .post(&SuspendSubscription::new(
+                subscription_id.to_string(),
+                SubscriptionReasonDto::new(reason),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Activates a suspended subscription, by ID.
+    pub async fn activate(
+        client: &Client,
+        subscription_id: &str,
+        reason: Option<&str>,
+    ) -> Result<(), PayPalError> {
+        client
+            .post(&ActivateSubscription::new(
+                subscription_id.to_string(),
+                SubscriptionReasonDto {
+                    reason: reason.map(ToString::to_string),
+                },
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Cancels a subscription, by ID. A cancelled subscription cannot be reactivated.
+    pub async fn cancel(
+        client: &Client,
+        subscription_id: &str,
+        reason: &str,
+    ) -> Result<(), PayPalError> {
+        client
+            .post(&CancelSubscription::new(
+                subscription_id.to_string(),
+                SubscriptionReasonDto::new(reason),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Captures an authorized payment from the subscriber on the subscription, by ID. Used to
+    /// collect the outstanding balance, for example of a suspended subscription.
+    pub async fn capture(
+        client: &Client,
+        subscription_id: &str,
+        dto: CaptureSubscriptionDto,
+    ) -> Result<SubscriptionTransaction, PayPalError> {
+        client
+            .post(&CaptureSubscription::new(subscription_id.to_string(), dto))
+            .await
+    }
+
+    /// Lists the transactions of a subscription, by ID, that were processed between the start and
+    /// end time of the query.
+    pub async fn list_transactions(
+        client: &Client,
+        subscription_id: &str,
+        query: ListSubscriptionTransactionsQuery,
+    ) -> Result<ListSubscriptionTransactionsResponse, PayPalError> {
+        client
+            .get(&ListSubscriptionTransactions::new(
+                subscription_id.to_string(),
+                query,
+            ))
+            .await
+    }
+
+    /// Shows details for a subscription, by ID. Use the query to also return the last failed
+    /// payment and the plan of the subscription.
+    pub async fn show(
+        client: &Client,
+        subscription_id: &str,
+        query: ShowSubscriptionQuery,
+    ) -> Result<SubscriptionDetails, PayPalError> {
+        client
+            .get(&ShowSubscription::new(subscription_id.to_string(), query))
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateSubscriptionDto {
+    /// The ID of the plan.
+    pub plan_id: String,
+
+    /// The date and time when the subscription started. Defaults to the current time.
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// The quantity of the product in the subscription.
+    pub quantity: Option<String>,
+
+    /// The shipping charges.
+    pub shipping_amount: Option<Money>,
+
+    /// The subscriber request information.
+    pub subscriber: Option<Subscriber>,
+
+    /// Whether the subscription auto-renews after the billing cycles complete.
+    pub auto_renewal: Option<bool>,
+
+    /// The application context, which customizes the payer experience during the subscriber
+    /// approval process with PayPal.
+    pub application_context: Option<SubscriptionApplicationContext>,
+
+    /// The custom id for the subscription. Can be invoice id.
+    pub custom_id: Option<String>,
+
+    /// An inline plan object to customise the subscription. You can override plan level default
+    /// attributes by providing customised values for the subscription in this object.
+    pub plan: Option<PlanOverride>,
+}
+
+impl CreateSubscriptionDto {
+    #[must_use]
+    pub const fn new(plan_id: String) -> Self {
+        Self {
+            plan_id,
+            start_time: None,
+            quantity: None,
+            shipping_amount: None,
+            subscriber: None,
+            auto_renewal: None,
+            application_context: None,
+            custom_id: None,
+            plan: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    #[must_use]
+    pub fn quantity(mut self, quantity: u32) -> Self {
+        self.quantity = Some(quantity.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn shipping_amount(mut self, shipping_amount: Money) -> Self {
+        self.shipping_amount = Some(shipping_amount);
+        self
+    }
+
+    #[must_use]
+    pub fn subscriber(mut self, subscriber: Subscriber) -> Self {
+        self.subscriber = Some(subscriber);
+        self
+    }
+
+    #[must_use]
+    pub const fn auto_renewal(mut self, auto_renewal: bool) -> Self {
+        self.auto_renewal = Some(auto_renewal);
+        self
+    }
+
+    #[must_use]
+    pub fn application_context(
+        mut self,
+        application_context: SubscriptionApplicationContext,
+    ) -> Self {
+        self.application_context = Some(application_context);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_id(mut self, custom_id: String) -> Self {
+        self.custom_id = Some(custom_id);
+        self
+    }
+
+    #[must_use]
+    pub fn plan(mut self, plan: PlanOverride) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+}
+
+/// Overrides the plan level defaults for a single subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlanOverride {
+    /// An array of billing cycles for trial billing and regular billing. The subscription
+    /// overrides the billing cycles of the plan with the same sequence.
+    pub billing_cycles: Option<Vec<BillingCycleOverride>>,
+
+    /// The payment preferences to override at subscription level.
+    pub payment_preferences: Option<PaymentPreferences>,
+
+    /// The tax details.
+    pub taxes: Option<Taxes>,
+}
+
+impl PlanOverride {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn billing_cycle(mut self, billing_cycle: BillingCycleOverride) -> Self {
+        self.billing_cycles
+            .get_or_insert_with(Vec::new)
+            .push(billing_cycle);
+        self
+    }
+
+    #[must_use]
+    pub fn payment_preferences(mut self, payment_preferences: PaymentPreferences) -> Self {
+        self.payment_preferences = Some(payment_preferences);
+        self
+    }
+
+    #[must_use]
+    pub fn taxes(mut self, taxes: Taxes) -> Self {
+        self.taxes = Some(taxes);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingCycleOverride {
+    /// The order of the billing cycle of the plan to override.
+    pub sequence: u32,
+
+    /// The active pricing scheme for this billing cycle.
+    pub pricing_scheme: Option<PricingScheme>,
+
+    /// The number of times this billing cycle gets executed. `0` means infinite cycles.
+    pub total_cycles: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct SubscriptionDetails {
+    /// The PayPal-generated ID for the subscription.
+    pub id: String,
+
+    /// The ID of the plan.
+    pub plan_id: Option<String>,
+
+    /// The status of the subscription.
+    pub status: Option<SubscriptionStatus>,
+
+    /// The reason or notes for the status of the subscription.
+    pub status_change_note: Option<String>,
+
+    /// The date and time when the status of the subscription was last updated, in Internet date
+    /// and time format.
+    pub status_update_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the subscription started, in Internet date and time format.
+    pub start_time: Option<DateTime<Utc>>,
+
+    /// The quantity of the product in the subscription.
+    pub quantity: Option<String>,
+
+    /// The shipping charges.
+    pub shipping_amount: Option<Money>,
+
+    /// The subscriber information.
+    pub subscriber: Option<Subscriber>,
+
+    /// The custom id for the subscription.
+    pub custom_id: Option<String>,
+
+    /// Indicates whether the subscription has overridden any plan attributes.
+    pub plan_overridden: Option<bool>,
+
+    /// The billing details for the subscription. Only returned once the subscription is active.
+    pub billing_info: Option<SubscriptionBillingInfo>,
+
+    /// The plan of the subscription, including the subscription level overrides. Only returned
+    /// when showing a subscription with the `plan` field.
+    pub plan: Option<BillingPlanDetails>,
+
+    /// The date and time when the subscription was created, in Internet date and time format.
+    pub create_time: Option<DateTime<Utc>>,
+
+    /// The date and time when the subscription was last updated, in Internet date and time format.
+    pub update_time: Option<DateTime<Utc>>,
+
+    /// An array of request-related HATEOAS links. Contains the approval link while the
+    /// subscription is pending approval.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl HateoasLinks for SubscriptionDetails {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct CreateSubscription {
+    body: CreateSubscriptionDto,
+}
+
+impl CreateSubscription {
+    pub const fn new(body: CreateSubscriptionDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateSubscription {
+    type QueryParams = ();
+    type RequestBody = CreateSubscriptionDto;
+    type ResponseBody = SubscriptionDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v1/billing/subscriptions")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReviseSubscriptionDto {
+    /// The unique PayPal-generated ID for the plan to switch to.
+    pub plan_id: Option<String>,
+
+    /// The quantity of the product or service in the subscription.
+    pub quantity: Option<String>,
+
+    /// The shipping charges.
+    pub shipping_amount: Option<Money>,
+
+    /// The shipping address of the subscriber.
+    pub shipping_address: Option<ShippingDetail>,
+
+    /// The application context, which customizes the payer experience during the subscriber
+    /// approval process with PayPal.
+    pub application_context: Option<SubscriptionApplicationContext>,
+
+    /// An inline plan object to customise the subscription.
+    pub plan: Option<PlanOverride>,
+}
+
+impl ReviseSubscriptionDto {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn plan_id(mut self, plan_id: String) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    #[must_use]
+    pub fn quantity(mut self, quantity: u32) -> Self {
+        self.quantity = Some(quantity.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn shipping_amount(mut self, shipping_amount: Money) -> Self {
+        self.shipping_amount = Some(shipping_amount);
+        self
+    }
+
+    #[must_use]
+    pub fn shipping_address(mut self, shipping_address: ShippingDetail) -> Self {
+        self.shipping_address = Some(shipping_address);
+        self
+    }
+
+    #[must_use]
+    pub fn application_context(
+        mut self,
+        application_context: SubscriptionApplicationContext,
+    ) -> Self {
+        self.application_context = Some(application_context);
+        self
+    }
+
+    #[must_use]
+    pub fn plan(mut self, plan: PlanOverride) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReviseSubscriptionResponse {
+    /// The unique PayPal-generated ID for the plan.
+    pub plan_id: Option<String>,
+
+    /// The quantity of the product or service in the subscription.
+    pub quantity: Option<String>,
+
+    /// The date and time when the revision takes effect, in Internet date and time format.
+    pub effective_time: Option<DateTime<Utc>>,
+
+    /// The shipping charges.
+    pub shipping_amount: Option<Money>,
+
+    /// The shipping address of the subscriber.
+    pub shipping_address: Option<ShippingDetail>,
+
+    /// Indicates whether the subscription has overridden any plan attributes.
+    pub plan_overridden: Option<bool>,
+
+    /// The plan of the subscription, including the subscription level overrides.
+    pub plan: Option<PlanOverride>,
+
+    /// An array of request-related HATEOAS links. Contains the approval link if the buyer has to
+    /// consent to the revision.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ReviseSubscriptionResponse {
+    /// Whether the buyer has to approve the revision before it takes effect.
+    pub fn requires_approval(&self) -> bool {
+        self.approve_url().is_some()
+    }
+}
+
+impl HateoasLinks for ReviseSubscriptionResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct ReviseSubscription {
+    subscription_id: String,
+    body: ReviseSubscriptionDto,
+}
+
+impl ReviseSubscription {
+    pub const fn new(subscription_id: String, body: ReviseSubscriptionDto) -> Self {
+        Self {
+            subscription_id,
+            body,
+        }
+    }
+}
+
+impl Endpoint for ReviseSubscription {
+    type QueryParams = ();
+    type RequestBody = ReviseSubscriptionDto;
+    type ResponseBody = ReviseSubscriptionResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/subscriptions/{}/revise",
+            self.subscription_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+/// The reason for suspending, activating or cancelling a subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SubscriptionReasonDto {
+    /// The reason for the status change of the subscription.
+    pub reason: Option<String>,
+}
+
+impl SubscriptionReasonDto {
+    #[must_use]
+    pub fn new(reason: &str) -> Self {
+        Self {
+            reason: Some(reason.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SuspendSubscription {
+    subscription_id: String,
+    body: SubscriptionReasonDto,
+}
+
+impl SuspendSubscription {
+    pub const fn new(subscription_id: String, body: SubscriptionReasonDto) -> Self {
+        Self {
+            subscription_id,
+            body,
+        }
+    }
+}
+
+impl Endpoint for SuspendSubscription {
+    type QueryParams = ();
+    type RequestBody = SubscriptionReasonDto;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/subscriptions/{}/suspend",
+            self.subscription_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct ActivateSubscription {
+    subscription_id: String,
+    body: SubscriptionReasonDto,
+}
+
+impl ActivateSubscription {
+    pub const fn new(subscription_id: String, body: SubscriptionReasonDto) -> Self {
+        Self {
+            subscription_id,
+            body,
+        }
+    }
+}
+
+impl Endpoint for ActivateSubscription {
+    type QueryParams = ();
+    type RequestBody = SubscriptionReasonDto;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/subscriptions/{}/activate",
+            self.subscription_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        self.body.reason.as_ref().map(|_| self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Debug)]
+struct CancelSubscription {
+    subscription_id: String,
+    body: SubscriptionReasonDto,
+}
+
+impl CancelSubscription {
+    pub const fn new(subscription_id: String, body: SubscriptionReasonDto) -> Self {
+        Self {
+            subscription_id,
+            body,
+        }
+    }
+}
+
+impl Endpoint for CancelSubscription {
+    type QueryParams = ();
+    type RequestBody = SubscriptionReasonDto;
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/subscriptions/{}/cancel",
+            self.subscription_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CaptureSubscriptionDto {
+    /// The reason or note for the subscription charge.
+    pub note: String,
+
+    /// The type of capture.
+    pub capture_type: CaptureType,
+
+    /// The amount of the outstanding balance. Must not be greater than the current outstanding
+    /// balance amount.
+    pub amount: Money,
+}
+
+impl CaptureSubscriptionDto {
+    /// Captures `amount` of the outstanding balance of the subscription.
+    #[must_use]
+    pub const fn outstanding_balance(amount: Money, note: String) -> Self {
+        Self {
+            note,
+            capture_type: CaptureType::OutstandingBalance,
+            amount,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CaptureSubscription {
+    subscription_id: String,
+    body: CaptureSubscriptionDto,
+}
+
+impl CaptureSubscription {
+    pub const fn new(subscription_id: String, body: CaptureSubscriptionDto) -> Self {
+        Self {
+            subscription_id,
+            body,
+        }
+    }
+}
+
+impl Endpoint for CaptureSubscription {
+    type QueryParams = ();
+    type RequestBody = CaptureSubscriptionDto;
+    type ResponseBody = SubscriptionTransaction;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/subscriptions/{}/capture",
+            self.subscription_id
+        ))
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ListSubscriptionTransactionsQuery {
+    /// The start time of the range of transactions to list.
+    pub start_time: DateTime<Utc>,
+
+    /// The end time of the range of transactions to list.
+    pub end_time: DateTime<Utc>,
+}
+
+impl ListSubscriptionTransactionsQuery {
+    #[must_use]
+    pub const fn new(start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Self {
+        Self {
+            start_time,
+            end_time,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ListSubscriptionTransactionsResponse {
+    /// An array of transactions.
+    #[serde(default = "Vec::new")]
+    pub transactions: Vec<SubscriptionTransaction>,
+
+    /// The total number of items.
+    pub total_items: Option<u32>,
+
+    /// The total number of pages.
+    pub total_pages: Option<u32>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl ListSubscriptionTransactionsResponse {
+    /// Converts the response into a generic [`Page`] of transactions.
+    pub fn into_page(self) -> Page<SubscriptionTransaction> {
+        Page {
+            items: self.transactions,
+            total_items: self.total_items,
+            total_pages: self.total_pages,
+            links: self.links,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ListSubscriptionTransactions {
+    subscription_id: String,
+    query: ListSubscriptionTransactionsQuery,
+}
+
+impl ListSubscriptionTransactions {
+    pub const fn new(subscription_id: String, query: ListSubscriptionTransactionsQuery) -> Self {
+        Self {
+            subscription_id,
+            query,
+        }
+    }
+}
+
+impl Endpoint for ListSubscriptionTransactions {
+    type QueryParams = ListSubscriptionTransactionsQuery;
+    type RequestBody = ();
+    type ResponseBody = ListSubscriptionTransactionsResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!(
+            "v1/billing/subscriptions/{}/transactions",
+            self.subscription_id
+        ))
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ShowSubscriptionQuery {
+    /// A comma-separated list of additional fields to return, `last_failed_payment` and `plan`.
+    pub fields: Option<String>,
+}
+
+impl ShowSubscriptionQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last failed payment in the billing info of the subscription.
+    #[must_use]
+    pub fn with_last_failed_payment(self) -> Self {
+        self.field("last_failed_payment")
+    }
+
+    /// Returns the plan of the subscription.
+    #[must_use]
+    pub fn with_plan(self) -> Self {
+        self.field("plan")
+    }
+
+    fn field(mut self, field: &str) -> Self {
+        self.fields = Some(match self.fields {
+            Some(fields) => format!("{fields},{field}"),
+            None => field.to_string(),
+        });
+        self
+    }
+}
+
+#[derive(Debug)]
+struct ShowSubscription {
+    subscription_id: String,
+    query: ShowSubscriptionQuery,
+}
+
+impl ShowSubscription {
+    pub const fn new(subscription_id: String, query: ShowSubscriptionQuery) -> Self {
+        Self {
+            subscription_id,
+            query,
+        }
+    }
+}
+
+impl Endpoint for ShowSubscription {
+    type QueryParams = ShowSubscriptionQuery;
+    type RequestBody = ();
+    type ResponseBody = SubscriptionDetails;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v1/billing/subscriptions/{}", self.subscription_id))
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::resources::enums::currency_code::CurrencyCode;
+    use crate::resources::enums::subscription_transaction_status::SubscriptionTransactionStatus;
+    use crate::resources::enums::subscription_user_action::SubscriptionUserAction;
+    use crate::resources::enums::tenure_type::TenureType;
+
+    #[test]
+    fn create_subscription_request_body() {
+        let endpoint = CreateSubscription::new(
+            CreateSubscriptionDto::new("P-5ML4271244454362WXNWU5NQ".to_string())
+                .start_time(Utc.with_ymd_and_hms(2018, 11, 1, 0, 0, 0).unwrap())
+                .quantity(20)
+                .subscriber(Subscriber::new().email_address("customer@example.com".to_string()))
+                .application_context(
+                    SubscriptionApplicationContext::new(
+                        "https://example.com/returnUrl".to_string(),
+                        "https://example.com/cancelUrl".to_string(),
+                    )
+                    .user_action(SubscriptionUserAction::SubscribeNow),
+                )
+                .plan(PlanOverride::new().taxes(Taxes::new("10".to_string()))),
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "plan_id": "P-5ML4271244454362WXNWU5NQ",
+                "start_time": "2018-11-01T00:00:00Z",
+                "quantity": "20",
+                "subscriber": {"email_address": "customer@example.com"},
+                "application_context": {
+                    "user_action": "SUBSCRIBE_NOW",
+                    "return_url": "https://example.com/returnUrl",
+                    "cancel_url": "https://example.com/cancelUrl"
+                },
+                "plan": {"taxes": {"percentage": "10"}}
+            })
+        );
+    }
+
+    #[test]
+    fn created_subscription_approve_url() {
+        let subscription: SubscriptionDetails = serde_json::from_str(
+            r#"{
+                "id": "I-BW452GLLEP1G",
+                "status": "APPROVAL_PENDING",
+                "status_update_time": "2018-12-10T21:20:49Z",
+                "links": [
+                    {
+                        "href": "https://www.paypal.com/webapps/billing/subscriptions?ba_token=BA-2M539689T3856352J",
+                        "rel": "approve",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            subscription.status,
+            Some(SubscriptionStatus::ApprovalPending)
+        );
+        assert_eq!(
+            subscription.approve_url().unwrap().as_str(),
+            "https://www.paypal.com/webapps/billing/subscriptions?ba_token=BA-2M539689T3856352J"
+        );
+    }
+
+    #[test]
+    fn show_subscription_with_billing_info() {
+        let endpoint = ShowSubscription::new(
+            "I-BW452GLLEP1G".to_string(),
+            ShowSubscriptionQuery::new()
+                .with_last_failed_payment()
+                .with_plan(),
+        );
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "fields=last_failed_payment%2Cplan"
+        );
+
+        let subscription: SubscriptionDetails = serde_json::from_str(
+            r#"{
+                "id": "I-BW452GLLEP1G",
+                "plan_id": "P-5ML4271244454362WXNWU5NQ",
+                "status": "ACTIVE",
+                "billing_info": {
+                    "outstanding_balance": {"currency_code": "USD", "value": "10.00"},
+                    "cycle_executions": [
+                        {
+                            "tenure_type": "TRIAL",
+                            "sequence": 1,
+                            "cycles_completed": 1,
+                            "cycles_remaining": 0,
+                            "current_pricing_scheme_version": 1,
+                            "total_cycles": 1
+                        },
+                        {
+                            "tenure_type": "REGULAR",
+                            "sequence": 2,
+                            "cycles_completed": 1,
+                            "cycles_remaining": 0,
+                            "total_cycles": 0
+                        }
+                    ],
+                    "last_payment": {
+                        "amount": {"currency_code": "USD", "value": "500.00"},
+                        "time": "2018-12-01T01:20:49Z"
+                    },
+                    "next_billing_time": "2019-01-01T00:20:49Z",
+                    "failed_payments_count": 1,
+                    "last_failed_payment": {
+                        "amount": {"currency_code": "USD", "value": "10.00"},
+                        "time": "2018-12-15T01:20:49Z",
+                        "reason_code": "PAYMENT_DENIED",
+                        "next_payment_retry_time": "2018-12-20T01:20:49Z"
+                    }
+                },
+                "plan": {"id": "P-5ML4271244454362WXNWU5NQ", "status": "ACTIVE"}
+            }"#,
+        )
+        .unwrap();
+
+        let billing_info = subscription.billing_info.unwrap();
+        assert!(billing_info.has_outstanding_balance());
+        assert_eq!(billing_info.cycle_executions.len(), 2);
+        assert_eq!(
+            billing_info.cycle_executions[1].tenure_type,
+            TenureType::Regular
+        );
+        assert_eq!(billing_info.last_payment.unwrap().amount.value, "500.00");
+        assert!(billing_info.next_billing_time.is_some());
+        assert_eq!(
+            billing_info
+                .last_failed_payment
+                .unwrap()
+                .reason_code
+                .as_deref(),
+            Some("PAYMENT_DENIED")
+        );
+        assert!(subscription.plan.is_some());
+    }
+
+    #[test]
+    fn revise_subscription() {
+        let endpoint = ReviseSubscription::new(
+            "I-BW452GLLEP1G".to_string(),
+            ReviseSubscriptionDto::new()
+                .plan_id("P-5ML4271244454362WXNWU5NQ".to_string())
+                .quantity(2),
+        );
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({"plan_id": "P-5ML4271244454362WXNWU5NQ", "quantity": "2"})
+        );
+
+        let response: ReviseSubscriptionResponse = serde_json::from_str(
+            r#"{
+                "plan_id": "P-5ML4271244454362WXNWU5NQ",
+                "quantity": "2",
+                "effective_time": "2018-11-01T00:00:00Z",
+                "plan_overridden": false,
+                "links": [
+                    {
+                        "href": "https://www.paypal.com/webapps/billing/subscriptions/update?ba_token=BA-2M539689T3856352J",
+                        "rel": "approve",
+                        "method": "GET"
+                    },
+                    {
+                        "href": "https://api-m.paypal.com/v1/billing/subscriptions/I-BW452GLLEP1G",
+                        "rel": "self",
+                        "method": "GET"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(response.requires_approval());
+        assert!(response.effective_time.is_some());
+    }
+
+    #[test]
+    fn subscription_status_change_request_bodies() {
+        let suspend = SuspendSubscription::new(
+            "I-BW452GLLEP1G".to_string(),
+            SubscriptionReasonDto::new("Item out of stock"),
+        );
+        assert_eq!(
+            suspend.path(),
+            "v1/billing/subscriptions/I-BW452GLLEP1G/suspend"
+        );
+        assert_eq!(
+            serde_json::to_value(suspend.request_body()).unwrap(),
+            serde_json::json!({"reason": "Item out of stock"})
+        );
+
+        let activate = ActivateSubscription::new(
+            "I-BW452GLLEP1G".to_string(),
+            SubscriptionReasonDto::default(),
+        );
+        assert!(activate.request_body().is_none());
+
+        let cancel = CancelSubscription::new(
+            "I-BW452GLLEP1G".to_string(),
+            SubscriptionReasonDto::new("Not satisfied with the service"),
+        );
+        assert_eq!(
+            serde_json::to_value(cancel.request_body()).unwrap(),
+            serde_json::json!({"reason": "Not satisfied with the service"})
+        );
+    }
+
+    #[test]
+    fn capture_outstanding_balance_request_body() {
+        let endpoint = CaptureSubscription::new(
+            "I-BW452GLLEP1G".to_string(),
+            CaptureSubscriptionDto::outstanding_balance(
+                Money::new(CurrencyCode::UnitedStatesDollar, "100".to_string()),
+                "Charging as the balance reached the limit".to_string(),
+            ),
+        );
+
+        assert_eq!(
+            serde_json::to_value(endpoint.request_body()).unwrap(),
+            serde_json::json!({
+                "note": "Charging as the balance reached the limit",
+                "capture_type": "OUTSTANDING_BALANCE",
+                "amount": {"currency_code": "USD", "value": "100"}
+            })
+        );
+    }
+
+    #[test]
+    fn list_subscription_transactions() {
+        let endpoint = ListSubscriptionTransactions::new(
+            "I-BW452GLLEP1G".to_string(),
+            ListSubscriptionTransactionsQuery::new(
+                Utc.with_ymd_and_hms(2018, 1, 21, 7, 50, 20).unwrap(),
+                Utc.with_ymd_and_hms(2018, 8, 21, 7, 50, 20).unwrap(),
+            ),
+        );
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "start_time=2018-01-21T07%3A50%3A20Z&end_time=2018-08-21T07%3A50%3A20Z"
+        );
+
+        let response: ListSubscriptionTransactionsResponse = serde_json::from_str(
+            r#"{
+                "transactions": [
+                    {
+                        "status": "COMPLETED",
+                        "id": "TRFGHNJKOIIOJKL",
+                        "amount_with_breakdown": {
+                            "gross_amount": {"currency_code": "USD", "value": "10.00"},
+                            "fee_amount": {"currency_code": "USD", "value": "1.00"},
+                            "net_amount": {"currency_code": "USD", "value": "9.00"}
+                        },
+                        "payer_name": {"given_name": "John", "surname": "Doe"},
+                        "payer_email": "customer@example.com",
+                        "time": "2018-03-16T07:40:20.940Z"
+                    }
+                ],
+                "total_items": 1,
+                "total_pages": 1
+            }"#,
+        )
+        .unwrap();
+
+        let page = response.into_page();
+        let transaction = &page.items[0];
+        assert_eq!(
+            transaction.status,
+            Some(SubscriptionTransactionStatus::Completed)
+        );
+        assert_eq!(
+            transaction
+                .amount_with_breakdown
+                .as_ref()
+                .unwrap()
+                .net_amount
+                .as_ref()
+                .unwrap()
+                .value,
+            "9.00"
+        );
+        assert_eq!(
+            transaction.payer_email.as_deref(),
+            Some("customer@example.com")
+        );
+        assert!(!page.has_next_page());
+    }
+
+    #[test]
+    fn subscription_transaction_with_unknown_status() {
+        let transaction: SubscriptionTransaction = serde_json::from_str(
+            r#"{
+                "status": "ON_HOLD",
+                "id": "TRFGHNJKOIIOJKL",
+                "time": "2018-03-16T07:40:20.940Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            transaction.status,
+            Some(SubscriptionTransactionStatus::Unknown)
+        );
+    }
+}
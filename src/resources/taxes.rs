@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The tax details of a billing plan or subscription.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Taxes {
+    /// The tax percentage on the billing amount, for example `"10"` for 10%.
+    pub percentage: String,
+
+    /// Indicates whether the tax was already included in the billing amount.
+    pub inclusive: Option<bool>,
+}
+
+impl Taxes {
+    #[must_use]
+    pub const fn new(percentage: String) -> Self {
+        Self {
+            percentage,
+            inclusive: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+}
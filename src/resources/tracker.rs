@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::enums::tracker_status::TrackerStatus;
+use crate::resources::link_description::LinkDescription;
+
+/// The tracking information of a shipment, attached to a captured order.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Tracker {
+    /// The ID of the tracker, composed of the capture ID and the tracking number.
+    pub id: Option<String>,
+
+    /// The status of the shipment.
+    pub status: Option<TrackerStatus>,
+
+    /// The items that were shipped.
+    pub items: Option<Vec<TrackerItem>>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+
+    /// The date and time when the tracker was created, in Internet date and time format.
+    pub create_time: Option<String>,
+
+    /// The date and time when the tracker was last updated, in Internet date and time format.
+    pub update_time: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TrackerItem {
+    /// The item name or title.
+    pub name: Option<String>,
+
+    /// The number of shipped items. Must be a whole number.
+    pub quantity: Option<String>,
+
+    /// The stock keeping unit (SKU) for the item.
+    pub sku: Option<String>,
+
+    /// The URL to the item being purchased.
+    pub url: Option<String>,
+
+    /// The URL of the item's image.
+    pub image_url: Option<String>,
+}
@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::client::endpoint::{EmptyResponseBody, Endpoint};
+use crate::client::error::PayPalError;
+use crate::client::paypal::Client;
+use crate::resources::link_description::{HateoasLinks, LinkDescription};
+
+pub struct VaultSetupToken;
+
+impl VaultSetupToken {
+    /// Creates a setup token, which temporarily saves a payment source until the buyer approves
+    /// it and it is converted into a payment token.
+    pub async fn create(
+        client: &Client,
+        dto: CreateSetupTokenDto,
+    ) -> Result<CreateSetupTokenResponse, PayPalError> {
+        client.post(&CreateSetupToken::new(dto)).await
+    }
+}
+
+pub struct VaultPaymentToken;
+
+impl VaultPaymentToken {
+    /// Deletes the payment token and the associated payment method, by ID.
+    pub async fn delete(client: &Client, token_id: &str) -> Result<(), PayPalError> {
+        client
+            .delete(&DeletePaymentToken::new(token_id.to_string(), None))
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a payment token that belongs to the given customer, by ID. Use this in a
+    /// marketplace context where the customer ID is required to authorize the deletion.
+    pub async fn delete_for_customer(
+        client: &Client,
+        customer_id: &str,
+        token_id: &str,
+    ) -> Result<(), PayPalError> {
+        client
+            .delete(&DeletePaymentToken::new(
+                token_id.to_string(),
+                Some(customer_id.to_string()),
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+struct DeletePaymentTokenQuery {
+    /// The ID of the customer the payment token belongs to.
+    customer_id: Option<String>,
+}
+
+#[derive(Debug)]
+struct DeletePaymentToken {
+    token_id: String,
+    query: DeletePaymentTokenQuery,
+}
+
+impl DeletePaymentToken {
+    pub const fn new(token_id: String, customer_id: Option<String>) -> Self {
+        Self {
+            token_id,
+            query: DeletePaymentTokenQuery { customer_id },
+        }
+    }
+}
+
+impl Endpoint for DeletePaymentToken {
+    type QueryParams = DeletePaymentTokenQuery;
+    type RequestBody = ();
+    type ResponseBody = EmptyResponseBody;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Owned(format!("v3/vault/payment-tokens/{}", self.token_id))
+    }
+
+    fn query(&self) -> Option<Self::QueryParams> {
+        Some(self.query.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::DELETE
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VaultCustomer {
+    /// The unique ID for a customer generated by PayPal.
+    pub id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SetupTokenPaymentSource {
+    /// A PayPal wallet to save.
+    pub paypal: Option<VaultPayPalWallet>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VaultPayPalWallet {
+    /// The description displayed to the buyer on the consent screen and in their PayPal profile.
+    pub description: Option<String>,
+
+    /// The usage type associated with the PayPal payment token, either `MERCHANT` or `PLATFORM`.
+    pub usage_type: Option<String>,
+
+    /// The customer-facing experience used while the buyer approves the setup token.
+    pub experience_context: Option<VaultExperienceContext>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VaultExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+
+    /// The BCP 47-formatted locale of pages that the PayPal payment experience shows.
+    pub locale: Option<String>,
+
+    /// The URL where the buyer is redirected after approving the setup token.
+    pub return_url: Option<String>,
+
+    /// The URL where the buyer is redirected after cancelling the approval.
+    pub cancel_url: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateSetupTokenDto {
+    /// The customer who owns the payment source. A new customer is created when omitted.
+    pub customer: Option<VaultCustomer>,
+
+    /// The payment source to save.
+    pub payment_source: SetupTokenPaymentSource,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CreateSetupTokenResponse {
+    /// The PayPal-generated ID for the setup token.
+    pub id: String,
+
+    /// The customer who owns the payment source.
+    pub customer: Option<VaultCustomer>,
+
+    /// The status of the setup token, for example `PAYER_ACTION_REQUIRED`.
+    pub status: Option<String>,
+
+    /// The payment source that is saved by the setup token.
+    pub payment_source: Option<SetupTokenPaymentSource>,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl CreateSetupTokenResponse {
+    /// The [`approve_url`](HateoasLinks::approve_url) of the setup token, with the given
+    /// `return_url` appended as a query parameter. The buyer has to be redirected to this URL to
+    /// approve saving the payment source.
+    ///
+    /// The buyer must approve the setup token within its validity window, which is typically one
+    /// hour. Afterwards, a new setup token has to be created.
+    pub fn approval_url_for_return(&self, return_url: &str) -> Option<Url> {
+        let mut url = self.approve_url()?;
+        url.query_pairs_mut().append_pair("return_url", return_url);
+        Some(url)
+    }
+}
+
+impl HateoasLinks for CreateSetupTokenResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+struct CreateSetupToken {
+    body: CreateSetupTokenDto,
+}
+
+impl CreateSetupToken {
+    pub const fn new(body: CreateSetupTokenDto) -> Self {
+        Self { body }
+    }
+}
+
+impl Endpoint for CreateSetupToken {
+    type QueryParams = ();
+    type RequestBody = CreateSetupTokenDto;
+    type ResponseBody = CreateSetupTokenResponse;
+
+    fn path(&self) -> Cow<str> {
+        Cow::Borrowed("v3/vault/setup-tokens")
+    }
+
+    fn request_body(&self) -> Option<Self::RequestBody> {
+        Some(self.body.clone())
+    }
+
+    fn request_method(&self) -> Method {
+        Method::POST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delete_payment_token_request() {
+        let endpoint = DeletePaymentToken::new("8kk8451t".to_string(), None);
+        assert_eq!(endpoint.path(), "v3/vault/payment-tokens/8kk8451t");
+        assert_eq!(endpoint.request_method(), Method::DELETE);
+        assert_eq!(serde_qs::to_string(&endpoint.query()).unwrap(), "");
+
+        let endpoint = DeletePaymentToken::new(
+            "8kk8451t".to_string(),
+            Some("customer_4029352050".to_string()),
+        );
+        assert_eq!(endpoint.path(), "v3/vault/payment-tokens/8kk8451t");
+        assert_eq!(
+            serde_qs::to_string(&endpoint.query()).unwrap(),
+            "customer_id=customer_4029352050"
+        );
+    }
+
+    #[test]
+    fn approval_url_for_return_appends_query() {
+        let response = CreateSetupTokenResponse {
+            id: "5C991763VB2781612".to_string(),
+            links: Some(vec![LinkDescription {
+                href: "https://www.sandbox.paypal.com/agreements/approve?approval_session_id=5C9"
+                    .to_string(),
+                rel: "approve".to_string(),
+                method: None,
+            }]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            response.approve_url().unwrap().as_str(),
+            "https://www.sandbox.paypal.com/agreements/approve?approval_session_id=5C9"
+        );
+
+        let url = response
+            .approval_url_for_return("https://example.com/return")
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://www.sandbox.paypal.com/agreements/approve?approval_session_id=5C9&return_url=https%3A%2F%2Fexample.com%2Freturn"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::card_address_portable::CardAddressPortable;
+use crate::resources::enums::shipping_preference::ShippingPreference;
+use crate::resources::money::Money;
+use crate::resources::phone_with_type_phone::PhoneWithTypePhone;
+
+/// A Venmo wallet used as payment source. Only available to US buyers.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VenmoWalletRequest {
+    /// The email address of the Venmo account holder.
+    pub email_address: Option<String>,
+
+    /// Customizes the buyer experience during the approval process.
+    pub experience_context: Option<VenmoExperienceContext>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct VenmoExperienceContext {
+    /// The label that overrides the business name in the Venmo account.
+    pub brand_name: Option<String>,
+
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+}
+
+/// An Apple Pay payment source. The payment token of Apple Pay has to be decrypted by the merchant
+/// before it is passed to PayPal.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplePayRequest {
+    /// The ID of the Apple Pay transaction.
+    pub id: Option<String>,
+
+    /// The name of the buyer as provided by Apple Pay.
+    pub name: Option<String>,
+
+    /// The email address of the buyer as provided by Apple Pay.
+    pub email_address: Option<String>,
+
+    /// The phone number of the buyer as provided by Apple Pay.
+    pub phone_number: Option<PhoneWithTypePhone>,
+
+    /// The decrypted payment token of Apple Pay.
+    pub decrypted_token: Option<ApplePayDecryptedToken>,
+
+    /// The PayPal-generated ID of a vaulted Apple Pay payment source.
+    pub vault_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplePayDecryptedToken {
+    /// The name of the card holder.
+    pub cardholder_name: Option<String>,
+
+    /// The tokenized card, also known as the device primary account number (DPAN).
+    pub tokenized_card: ApplePayTokenizedCard,
+
+    /// The Apple Pay device manufacturer ID.
+    pub device_manufacturer_id: Option<String>,
+
+    /// The type of the payment data, either `3DSECURE` or `EMV`.
+    pub payment_data_type: Option<String>,
+
+    /// The transaction amount for the payment that the payer has approved on the Apple Pay sheet.
+    pub transaction_amount: Option<Money>,
+
+    /// The payment data of the token.
+    pub payment_data: Option<ApplePayPaymentData>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplePayTokenizedCard {
+    /// The name of the card holder.
+    pub name: Option<String>,
+
+    /// The device primary account number (DPAN).
+    pub number: String,
+
+    /// The expiration year and month of the token, in Internet date format.
+    pub expiry: String,
+
+    /// The card brand or network.
+    pub card_type: Option<String>,
+
+    /// The billing address for this card.
+    pub billing_address: Option<CardAddressPortable>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ApplePayPaymentData {
+    /// The online payment cryptogram, for the `3DSECURE` payment data type.
+    pub cryptogram: Option<String>,
+
+    /// The electronic commerce indicator (ECI) for the `3DSECURE` payment data type.
+    pub eci_indicator: Option<String>,
+
+    /// The encoded EMV payment structure, for the `EMV` payment data type.
+    pub emv_data: Option<String>,
+
+    /// The encrypted PIN, for the `EMV` payment data type.
+    pub pin: Option<String>,
+}
+
+/// A Google Pay payment source.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GooglePayRequest {
+    /// The name of the buyer as provided by Google Pay.
+    pub name: Option<String>,
+
+    /// The email address of the buyer as provided by Google Pay.
+    pub email_address: Option<String>,
+
+    /// The phone number of the buyer as provided by Google Pay.
+    pub phone_number: Option<PhoneWithTypePhone>,
+
+    /// The decrypted payment token of Google Pay.
+    pub decrypted_token: Option<GooglePayDecryptedToken>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GooglePayDecryptedToken {
+    /// The unique ID of the message from Google Pay.
+    pub message_id: Option<String>,
+
+    /// The date and time when the message expires, in milliseconds since the epoch.
+    pub message_expiration: Option<String>,
+
+    /// The type of the payment method. Only `CARD` is supported.
+    pub payment_method: String,
+
+    /// The tokenized card.
+    pub card: GooglePayTokenizedCard,
+
+    /// The authentication method, either `PAN_ONLY` or `CRYPTOGRAM_3DS`.
+    pub authentication_method: String,
+
+    /// The 3D Secure cryptogram, for the `CRYPTOGRAM_3DS` authentication method.
+    pub cryptogram: Option<String>,
+
+    /// The electronic commerce indicator (ECI), for the `CRYPTOGRAM_3DS` authentication method.
+    pub eci_indicator: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GooglePayTokenizedCard {
+    /// The name of the card holder.
+    pub name: Option<String>,
+
+    /// The primary account number (PAN) or device primary account number (DPAN).
+    pub number: String,
+
+    /// The expiration year and month of the card, in Internet date format.
+    pub expiry: String,
+
+    /// The last digits of the card.
+    pub last_digits: Option<String>,
+
+    /// The billing address for this card.
+    pub billing_address: Option<CardAddressPortable>,
+}
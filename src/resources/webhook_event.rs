@@ -0,0 +1,177 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::resources::link_description::LinkDescription;
+
+/// A webhook event notification, as sent to the webhook listener. The resource defaults to the raw
+/// JSON payload and can be deserialized into a typed resource instead.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WebhookEvent<T = serde_json::Value> {
+    /// The ID of the webhook event notification.
+    pub id: String,
+
+    /// The date and time when the webhook event notification was created, in Internet date and
+    /// time format.
+    pub create_time: Option<String>,
+
+    /// The name of the resource related to the webhook notification event.
+    pub resource_type: Option<String>,
+
+    /// The event version in the webhook notification.
+    pub event_version: Option<String>,
+
+    /// The event that triggered the webhook event notification.
+    pub event_type: String,
+
+    /// A summary description for the event notification.
+    pub summary: Option<String>,
+
+    /// The resource version in the webhook notification.
+    pub resource_version: Option<String>,
+
+    /// The resource that triggered the webhook event notification.
+    pub resource: T,
+
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl WebhookEvent<serde_json::Value> {
+    /// Deserializes the raw resource into a typed resource, for example a
+    /// [`CaptureAuthorizedPaymentResponse`](crate::CaptureAuthorizedPaymentResponse) for
+    /// `PAYMENT.CAPTURE.*` events.
+    pub fn into_typed<T: DeserializeOwned>(self) -> Result<WebhookEvent<T>, serde_json::Error> {
+        Ok(WebhookEvent {
+            resource: serde_json::from_value(self.resource)?,
+            id: self.id,
+            create_time: self.create_time,
+            resource_type: self.resource_type,
+            event_version: self.event_version,
+            event_type: self.event_type,
+            summary: self.summary,
+            resource_version: self.resource_version,
+            links: self.links,
+        })
+    }
+
+    /// The ID of the order the event relates to. Looks up the related order ID of payment
+    /// resources, then `resource.order_id`, and finally `resource.id` for order events.
+    pub fn order_id(&self) -> Option<&str> {
+        self.resource
+            .pointer("/supplementary_data/related_ids/order_id")
+            .or_else(|| self.resource.get("order_id"))
+            .or_else(|| {
+                self.is_order_resource()
+                    .then(|| self.resource.get("id"))
+                    .flatten()
+            })?
+            .as_str()
+    }
+
+    /// The ID of the transaction the event relates to, which is `resource.id` for all events that
+    /// do not relate to an order resource, for example captures, authorizations and refunds.
+    pub fn transaction_id(&self) -> Option<&str> {
+        if self.is_order_resource() {
+            return None;
+        }
+
+        self.resource.get("id")?.as_str()
+    }
+
+    fn is_order_resource(&self) -> bool {
+        self.resource_type.as_deref() == Some("checkout-order")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookEvent;
+    use crate::{CaptureAuthorizedPaymentResponse, SellerProtectionStatus};
+
+    #[test]
+    fn ids_of_capture_event() {
+        let event: WebhookEvent = serde_json::from_str(
+            r#"{
+                "id": "WH-58D329510W468432D-8HN650336L201105X",
+                "event_type": "PAYMENT.CAPTURE.COMPLETED",
+                "resource_type": "capture",
+                "resource": {
+                    "id": "42311647XV020574X",
+                    "supplementary_data": {"related_ids": {"order_id": "5O190127TN364715T"}}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.order_id(), Some("5O190127TN364715T"));
+        assert_eq!(event.transaction_id(), Some("42311647XV020574X"));
+    }
+
+    #[test]
+    fn ids_of_order_event() {
+        let event: WebhookEvent = serde_json::from_str(
+            r#"{
+                "id": "WH-COC11055RA711503B-4YM959094A144403T",
+                "event_type": "CHECKOUT.ORDER.APPROVED",
+                "resource_type": "checkout-order",
+                "resource": {"id": "5O190127TN364715T"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.order_id(), Some("5O190127TN364715T"));
+        assert_eq!(event.transaction_id(), None);
+    }
+
+    #[test]
+    fn typed_capture_event() {
+        let event: WebhookEvent = serde_json::from_str(
+            r#"{
+                "id": "WH-58D329510W468432D-8HN650336L201105X",
+                "event_type": "PAYMENT.CAPTURE.COMPLETED",
+                "resource_type": "capture",
+                "resource": {
+                    "id": "42311647XV020574X",
+                    "status": "COMPLETED",
+                    "amount": {"currency_code": "EUR", "value": "100.00"},
+                    "seller_protection": {"status": "NOT_ELIGIBLE"},
+                    "seller_receivable_breakdown": {
+                        "gross_amount": {"currency_code": "EUR", "value": "100.00"},
+                        "paypal_fee": {"currency_code": "EUR", "value": "3.25"},
+                        "net_amount": {"currency_code": "EUR", "value": "91.75"},
+                        "platform_fees": [
+                            {
+                                "amount": {"currency_code": "EUR", "value": "5.00"},
+                                "payee": {"merchant_id": "C7CYMKZDG8D6E"}
+                            }
+                        ],
+                        "exchange_rate": {}
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let event = event
+            .into_typed::<CaptureAuthorizedPaymentResponse>()
+            .unwrap();
+        let breakdown = event.resource.seller_receivable_breakdown.unwrap();
+
+        assert_eq!(
+            event.resource.seller_protection.unwrap().status,
+            Some(SellerProtectionStatus::NotEligible)
+        );
+        assert_eq!(breakdown.paypal_fee.unwrap().value, "3.25");
+        assert_eq!(
+            breakdown.platform_fees.unwrap()[0]
+                .payee
+                .as_ref()
+                .unwrap()
+                .merchant_id
+                .as_deref(),
+            Some("C7CYMKZDG8D6E")
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -9,9 +11,11 @@ use crate::client::error::PayPalError;
 use crate::client::paypal::Client;
 use crate::client::EmptyResponseBody;
 use crate::resources::enums::verification_status::VerificationStatus;
-use crate::{AnchorType, CreateWebhookEventType, LinkDescription, Op, ShowWebhookEventType};
+use crate::{
+    AnchorType, CreateWebhookEventType, HateoasLinks, LinkDescription, Op, ShowWebhookEventType,
+};
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct Webhook {
     /// The ID of the webhook.
     pub id: String,
@@ -29,6 +33,18 @@ pub struct Webhook {
 }
 
 impl Webhook {
+    /// Whether the webhook listens on the given URL and is subscribed to exactly the given event
+    /// types, in any order. Duplicate event types are ignored.
+    pub fn same_url_and_events(&self, url: &str, events: &[&str]) -> bool {
+        let subscribed = self
+            .event_types
+            .iter()
+            .map(|event| event.name.as_str())
+            .collect::<BTreeSet<_>>();
+
+        self.url == url && subscribed == events.iter().copied().collect()
+    }
+
     /// Verifies a webhook signature.
     pub async fn verify(
         client: &Client,
@@ -70,6 +86,32 @@ impl Webhook {
         client.patch(&UpdateWebhook::new(id, dto)).await
     }
 
+    /// Creates a webhook, or adds the event types to the existing webhook if a webhook for the URL
+    /// already exists. PayPal rejects creating a second webhook for the same URL.
+    pub async fn create_or_update(
+        client: &Client,
+        dto: CreateWebhookDto,
+    ) -> Result<ShowWebhookDetailsResponse, PayPalError> {
+        let webhooks = Self::list(client, ListWebhooksQuery { anchor_type: None }).await?;
+
+        match WebhookChange::for_webhooks(&webhooks, dto) {
+            WebhookChange::Create(dto) => Self::create(client, dto).await,
+            WebhookChange::Update(id, dto) => Self::update(client, id, dto).await,
+            WebhookChange::Unchanged(webhook) => Ok(webhook),
+        }
+    }
+
+    /// Makes sure a webhook for the URL is subscribed to all given event types, creating the webhook
+    /// or adding the missing event types as needed. Returns the resulting webhook. Calling this
+    /// repeatedly with the same arguments leaves the webhook unchanged.
+    pub async fn ensure_subscribed(
+        client: &Client,
+        url: &str,
+        event_types: &[&str],
+    ) -> Result<ShowWebhookDetailsResponse, PayPalError> {
+        Self::create_or_update(client, CreateWebhookDto::subscribe(url, event_types)).await
+    }
+
     /// Deletes a webhook.
     pub async fn delete(client: &Client, id: String) -> Result<(), PayPalError> {
         client.delete(&DeleteWebhook::new(id)).await?;
@@ -170,6 +212,27 @@ pub struct ListWebhooksResponse {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl ListWebhooksResponse {
+    /// Finds the webhook registered for the given URL.
+    pub fn webhook_for_url(&self, url: &str) -> Option<&Webhook> {
+        self.webhooks.iter().find(|webhook| webhook.url == url)
+    }
+
+    /// Finds a webhook by its ID.
+    pub fn webhook_for_id(&self, id: &str) -> Option<&Webhook> {
+        self.webhooks.iter().find(|webhook| webhook.id == id)
+    }
+}
+
+impl<'a> IntoIterator for &'a ListWebhooksResponse {
+    type Item = &'a Webhook;
+    type IntoIter = std::slice::Iter<'a, Webhook>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.webhooks.iter()
+    }
+}
+
 #[derive(Debug)]
 struct ListWebhooks {
     query_params: ListWebhooksQuery,
@@ -195,7 +258,7 @@ impl Endpoint for ListWebhooks {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct ShowWebhookDetailsResponse {
     /// The ID of the webhook.
     pub id: Option<String>,
@@ -213,6 +276,44 @@ pub struct ShowWebhookDetailsResponse {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl ShowWebhookDetailsResponse {
+    /// The names of the event types the webhook is subscribed to.
+    pub fn event_type_names(&self) -> Vec<&str> {
+        self.event_types
+            .iter()
+            .map(|event| event.name.as_str())
+            .collect()
+    }
+
+    /// Whether the webhook is subscribed to the event type with the given name. Only checks for
+    /// the exact name, see [`ShowWebhookDetailsResponse::has_wildcard_subscription`].
+    pub fn has_event_type(&self, name: &str) -> bool {
+        self.event_types.iter().any(|event| event.name == name)
+    }
+
+    /// Whether the webhook is subscribed to all events using the `*` wildcard.
+    pub fn has_wildcard_subscription(&self) -> bool {
+        self.has_event_type("*")
+    }
+}
+
+impl HateoasLinks for ShowWebhookDetailsResponse {
+    fn hateoas_links(&self) -> &[LinkDescription] {
+        self.links.as_deref().unwrap_or_default()
+    }
+}
+
+impl From<Webhook> for ShowWebhookDetailsResponse {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: Some(webhook.id),
+            url: webhook.url,
+            event_types: webhook.event_types,
+            links: webhook.links,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct ShowWebhookDetails {
     id: String,
@@ -240,8 +341,58 @@ pub struct CreateWebhookDto {
     pub event_types: Vec<CreateWebhookEventType>,
 }
 
+impl CreateWebhookDto {
+    /// A webhook for the URL, subscribed to the event types with the given names.
+    fn subscribe(url: &str, event_types: &[&str]) -> Self {
+        Self {
+            url: url.to_string(),
+            event_types: event_types
+                .iter()
+                .map(|name| CreateWebhookEventType::new(name))
+                .collect(),
+        }
+    }
+}
+
 type CreateWebhookResponse = ShowWebhookDetailsResponse;
 
+/// The change `Webhook::create_or_update` makes to the webhooks of the app.
+#[derive(Debug)]
+enum WebhookChange {
+    /// No webhook exists for the URL yet.
+    Create(CreateWebhookDto),
+    /// The webhook with the ID misses some of the event types.
+    Update(String, UpdateWebhookDto),
+    /// The webhook is already subscribed to all event types.
+    Unchanged(ShowWebhookDetailsResponse),
+}
+
+impl WebhookChange {
+    fn for_webhooks(webhooks: &ListWebhooksResponse, dto: CreateWebhookDto) -> Self {
+        let webhook = match webhooks.webhook_for_url(&dto.url) {
+            Some(webhook) => ShowWebhookDetailsResponse::from(webhook.clone()),
+            None => return Self::Create(dto),
+        };
+
+        if webhook.has_wildcard_subscription() {
+            return Self::Unchanged(webhook);
+        }
+
+        let update = dto
+            .event_types
+            .into_iter()
+            .fold(UpdateWebhookBuilder::new(&webhook), |builder, event| {
+                builder.add_event_type(event)
+            })
+            .build();
+
+        match (update.is_empty(), webhook.id.clone()) {
+            (false, Some(id)) => Self::Update(id, update),
+            _ => Self::Unchanged(webhook),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct CreateWebhook {
     body: CreateWebhookDto,
@@ -273,6 +424,7 @@ impl Endpoint for CreateWebhook {
 
 pub type UpdateWebhookDto = Vec<UpdateWebhookDtoItem>;
 
+#[skip_serializing_none]
 #[derive(Clone, Debug, Serialize)]
 pub struct UpdateWebhookDtoItem {
     /// The operation.
@@ -280,12 +432,115 @@ pub struct UpdateWebhookDtoItem {
     /// The JSON Pointer to the target document location at which to complete the operation.
     pub path: String,
     /// The value to apply. The remove operation does not require a value.
-    pub value: Option<String>,
+    pub value: Option<UpdateWebhookValue>,
     /// The JSON Pointer to the target document location from which to move the value.
     /// Required for the move operation.
     pub from: Option<String>,
 }
 
+/// The value of a webhook update operation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum UpdateWebhookValue {
+    /// A plain value, for example the new webhook URL.
+    String(String),
+    /// The event types to subscribe the webhook to.
+    EventTypes(Vec<CreateWebhookEventType>),
+}
+
+impl From<String> for UpdateWebhookValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// Builds the operations to update a webhook. PayPal only supports replacing the URL and the full
+/// list of event types, so the builder starts from the current event types of the webhook and
+/// replaces them as a whole when they were changed.
+#[derive(Clone, Debug)]
+pub struct UpdateWebhookBuilder {
+    url: Option<String>,
+    event_types: Vec<CreateWebhookEventType>,
+    event_types_changed: bool,
+}
+
+impl UpdateWebhookBuilder {
+    #[must_use]
+    pub fn new(webhook: &ShowWebhookDetailsResponse) -> Self {
+        Self {
+            url: None,
+            event_types: webhook
+                .event_types
+                .iter()
+                .map(CreateWebhookEventType::from_show_type)
+                .collect(),
+            event_types_changed: false,
+        }
+    }
+
+    #[must_use]
+    pub fn replace_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Subscribes the webhook to the event type, if it is not subscribed yet.
+    #[must_use]
+    pub fn add_event_type(mut self, event: CreateWebhookEventType) -> Self {
+        if !self
+            .event_types
+            .iter()
+            .any(|existing| existing.name == event.name)
+        {
+            self.event_types.push(event);
+            self.event_types_changed = true;
+        }
+        self
+    }
+
+    /// Unsubscribes the webhook from the event type with the given name.
+    #[must_use]
+    pub fn remove_event_type(mut self, event_name: &str) -> Self {
+        let count = self.event_types.len();
+        self.event_types.retain(|event| event.name != event_name);
+        self.event_types_changed |= self.event_types.len() != count;
+        self
+    }
+
+    #[must_use]
+    pub fn replace_all_event_types(mut self, types: Vec<CreateWebhookEventType>) -> Self {
+        self.event_types = types;
+        self.event_types_changed = true;
+        self
+    }
+
+    /// Returns the patch operations. Empty if nothing was changed.
+    #[must_use]
+    pub fn build(self) -> UpdateWebhookDto {
+        let mut operations = Vec::new();
+
+        if let Some(url) = self.url {
+            operations.push(UpdateWebhookDtoItem {
+                op: Op::Replace,
+                path: "/url".to_string(),
+                value: Some(url.into()),
+                from: None,
+            });
+        }
+
+        if self.event_types_changed {
+            operations.push(UpdateWebhookDtoItem {
+                op: Op::Replace,
+                path: "/event_types".to_string(),
+                value: Some(UpdateWebhookValue::EventTypes(self.event_types)),
+                from: None,
+            });
+        }
+
+        operations
+    }
+}
+
 #[derive(Debug)]
 struct UpdateWebhook {
     id: String,
@@ -364,7 +619,10 @@ pub struct SimulateWebhookEventResponse {
 
     /// The date and time when the webhook event notification was created, in Internet date and
     /// time format.
-    pub create_time: Option<String>,
+    pub create_time: Option<DateTime<Utc>>,
+
+    /// The status of the simulated event. `CREATED` once the event notification was created.
+    pub status: Option<String>,
 
     /// The name of the resource related to the webhook notification event.
     pub resource_type: Option<String>,
@@ -378,7 +636,8 @@ pub struct SimulateWebhookEventResponse {
     /// A summary description for the event notification.
     pub summary: Option<String>,
 
-    /// The resource version in the webhook notification.
+    /// The resource version in the webhook notification. Unlike the event version, this is the
+    /// version of the resource schema, for example `2.0`.
     pub resource_version: Option<String>,
 
     /// The resource that triggered the webhook event notification.
@@ -388,6 +647,13 @@ pub struct SimulateWebhookEventResponse {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl SimulateWebhookEventResponse {
+    /// Whether PayPal created the simulated event notification.
+    pub fn is_completed(&self) -> bool {
+        self.status.as_deref() == Some("CREATED")
+    }
+}
+
 #[derive(Debug)]
 struct SimulateWebhookEvent {
     body: SimulateWebhookEventDto,
@@ -422,6 +688,30 @@ pub struct ListAvailableWebhookEventsResponse {
     pub event_types: Vec<ShowWebhookEventType>,
 }
 
+impl ListAvailableWebhookEventsResponse {
+    /// Finds an available event type by its name.
+    pub fn find_event(&self, name: &str) -> Option<&ShowWebhookEventType> {
+        self.event_types.iter().find(|event| event.name == name)
+    }
+
+    /// The names of all available event types.
+    pub fn all_event_names(&self) -> Vec<&str> {
+        self.event_types
+            .iter()
+            .map(|event| event.name.as_str())
+            .collect()
+    }
+
+    /// Whether the event type with the given name is available in resource version `2.0`.
+    pub fn supports_v2(&self, name: &str) -> bool {
+        self.find_event(name)
+            .and_then(|event| event.resource_versions.as_ref())
+            .map_or(false, |versions| {
+                versions.iter().any(|version| version == "2.0")
+            })
+    }
+}
+
 #[derive(Debug)]
 struct ListAvailableWebhookEvents;
 
@@ -440,3 +730,354 @@ impl Endpoint for ListAvailableWebhookEvents {
         Cow::Borrowed("v1/notifications/webhooks-event-types")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{
+        CreateWebhookDto, ListAvailableWebhookEventsResponse, ListWebhooksResponse,
+        ShowWebhookDetailsResponse, SimulateWebhookEventResponse, UpdateWebhookBuilder,
+        WebhookChange,
+    };
+    use crate::{CreateWebhookEventType, ShowWebhookEventType};
+
+    #[test]
+    fn webhook_details_event_types() {
+        let webhook: ShowWebhookDetailsResponse = serde_json::from_str(
+            r#"{
+                "id": "0EH40505U7160970P",
+                "url": "https://example.com/example_webhook",
+                "event_types": [
+                    {"name": "PAYMENT.AUTHORIZATION.CREATED"},
+                    {"name": "PAYMENT.AUTHORIZATION.VOIDED"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            webhook.event_type_names(),
+            vec![
+                "PAYMENT.AUTHORIZATION.CREATED",
+                "PAYMENT.AUTHORIZATION.VOIDED"
+            ]
+        );
+        assert!(webhook.has_event_type("PAYMENT.AUTHORIZATION.VOIDED"));
+        assert!(!webhook.has_event_type("PAYMENT.CAPTURE.COMPLETED"));
+        assert!(!webhook.has_wildcard_subscription());
+    }
+
+    #[test]
+    fn available_webhook_events() {
+        let events: ListAvailableWebhookEventsResponse = serde_json::from_str(
+            r#"{
+                "event_types": [
+                    {
+                        "name": "PAYMENT.CAPTURE.COMPLETED",
+                        "description": "A payment capture completes.",
+                        "status": "ENABLED",
+                        "resource_versions": ["1.0", "2.0"]
+                    },
+                    {
+                        "name": "PAYMENT.SALE.COMPLETED",
+                        "description": "A sale completes.",
+                        "status": "ENABLED",
+                        "resource_versions": ["1.0"]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            events.all_event_names(),
+            vec!["PAYMENT.CAPTURE.COMPLETED", "PAYMENT.SALE.COMPLETED"]
+        );
+        assert!(events.find_event("PAYMENT.SALE.COMPLETED").is_some());
+        assert!(events.find_event("PAYMENT.SALE.DENIED").is_none());
+        assert!(events.supports_v2("PAYMENT.CAPTURE.COMPLETED"));
+        assert!(!events.supports_v2("PAYMENT.SALE.COMPLETED"));
+        assert!(!events.supports_v2("PAYMENT.SALE.DENIED"));
+    }
+
+    #[test]
+    fn update_webhook_builder() {
+        let webhook: ShowWebhookDetailsResponse = serde_json::from_str(
+            r#"{
+                "id": "0EH40505U7160970P",
+                "url": "https://example.com/example_webhook",
+                "event_types": [
+                    {"name": "PAYMENT.AUTHORIZATION.CREATED"},
+                    {"name": "PAYMENT.AUTHORIZATION.VOIDED"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(UpdateWebhookBuilder::new(&webhook)
+            .add_event_type(CreateWebhookEventType::new("PAYMENT.AUTHORIZATION.CREATED"))
+            .remove_event_type("PAYMENT.CAPTURE.COMPLETED")
+            .build()
+            .is_empty());
+
+        let dto = UpdateWebhookBuilder::new(&webhook)
+            .replace_url("https://example.com/example_webhook_2".to_string())
+            .add_event_type(CreateWebhookEventType::new("PAYMENT.CAPTURE.COMPLETED"))
+            .remove_event_type("PAYMENT.AUTHORIZATION.VOIDED")
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(dto).unwrap(),
+            serde_json::json!([
+                {
+                    "op": "replace",
+                    "path": "/url",
+                    "value": "https://example.com/example_webhook_2"
+                },
+                {
+                    "op": "replace",
+                    "path": "/event_types",
+                    "value": [
+                        {"name": "PAYMENT.AUTHORIZATION.CREATED"},
+                        {"name": "PAYMENT.CAPTURE.COMPLETED"}
+                    ]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn list_webhooks_lookup() {
+        let webhooks: ListWebhooksResponse = serde_json::from_str(
+            r#"{
+                "webhooks": [
+                    {
+                        "id": "40Y916089Y8324740",
+                        "url": "https://example.com/example_webhook",
+                        "event_types": [{"name": "PAYMENT.AUTHORIZATION.CREATED"}]
+                    },
+                    {
+                        "id": "0EH40505U7160970P",
+                        "url": "https://example.com/another_example_webhook",
+                        "event_types": [{"name": "*"}]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            webhooks
+                .webhook_for_url("https://example.com/another_example_webhook")
+                .map(|webhook| webhook.id.as_str()),
+            Some("0EH40505U7160970P")
+        );
+        assert!(webhooks.webhook_for_url("https://example.com").is_none());
+        assert!(webhooks.webhook_for_id("40Y916089Y8324740").is_some());
+        assert_eq!((&webhooks).into_iter().count(), 2);
+
+        let webhook = webhooks.webhook_for_id("40Y916089Y8324740").unwrap();
+        assert!(webhook.same_url_and_events(
+            "https://example.com/example_webhook",
+            &["PAYMENT.AUTHORIZATION.CREATED"]
+        ));
+        assert!(!webhook.same_url_and_events("https://example.com/example_webhook", &["*"]));
+        assert!(webhook.same_url_and_events(
+            "https://example.com/example_webhook",
+            &[
+                "PAYMENT.AUTHORIZATION.CREATED",
+                "PAYMENT.AUTHORIZATION.CREATED"
+            ]
+        ));
+        assert_eq!(
+            ShowWebhookDetailsResponse::from(webhook.clone()),
+            ShowWebhookDetailsResponse {
+                id: Some("40Y916089Y8324740".to_string()),
+                url: "https://example.com/example_webhook".to_string(),
+                event_types: vec![ShowWebhookEventType {
+                    name: "PAYMENT.AUTHORIZATION.CREATED".to_string(),
+                    ..Default::default()
+                }],
+                links: None,
+            }
+        );
+    }
+
+    #[test]
+    fn same_url_and_events_with_duplicates() {
+        let webhooks: ListWebhooksResponse = serde_json::from_str(
+            r#"{
+                "webhooks": [
+                    {
+                        "id": "40Y916089Y8324740",
+                        "url": "https://example.com/example_webhook",
+                        "event_types": [
+                            {"name": "PAYMENT.AUTHORIZATION.CREATED"},
+                            {"name": "PAYMENT.AUTHORIZATION.VOIDED"}
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let webhook = &webhooks.webhooks[0];
+
+        assert!(!webhook.same_url_and_events(
+            "https://example.com/example_webhook",
+            &[
+                "PAYMENT.AUTHORIZATION.CREATED",
+                "PAYMENT.AUTHORIZATION.CREATED"
+            ]
+        ));
+        assert!(webhook.same_url_and_events(
+            "https://example.com/example_webhook",
+            &[
+                "PAYMENT.AUTHORIZATION.VOIDED",
+                "PAYMENT.AUTHORIZATION.CREATED",
+                "PAYMENT.AUTHORIZATION.VOIDED"
+            ]
+        ));
+    }
+
+    #[test]
+    fn create_or_update_changes() {
+        let webhooks: ListWebhooksResponse = serde_json::from_str(
+            r#"{
+                "webhooks": [
+                    {
+                        "id": "40Y916089Y8324740",
+                        "url": "https://example.com/example_webhook",
+                        "event_types": [{"name": "PAYMENT.AUTHORIZATION.CREATED"}]
+                    },
+                    {
+                        "id": "0EH40505U7160970P",
+                        "url": "https://example.com/another_example_webhook",
+                        "event_types": [{"name": "*"}]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        match WebhookChange::for_webhooks(
+            &webhooks,
+            CreateWebhookDto::subscribe(
+                "https://example.com/new_webhook",
+                &["PAYMENT.CAPTURE.COMPLETED"],
+            ),
+        ) {
+            WebhookChange::Create(dto) => assert_eq!(
+                serde_json::to_value(dto).unwrap(),
+                serde_json::json!({
+                    "url": "https://example.com/new_webhook",
+                    "event_types": [{"name": "PAYMENT.CAPTURE.COMPLETED"}]
+                })
+            ),
+            other => panic!("expected a new webhook, got {other:?}"),
+        }
+
+        match WebhookChange::for_webhooks(
+            &webhooks,
+            CreateWebhookDto::subscribe(
+                "https://example.com/example_webhook",
+                &["PAYMENT.AUTHORIZATION.CREATED", "PAYMENT.CAPTURE.COMPLETED"],
+            ),
+        ) {
+            WebhookChange::Update(id, dto) => {
+                assert_eq!(id, "40Y916089Y8324740");
+                assert_eq!(
+                    serde_json::to_value(dto).unwrap(),
+                    serde_json::json!([{
+                        "op": "replace",
+                        "path": "/event_types",
+                        "value": [
+                            {"name": "PAYMENT.AUTHORIZATION.CREATED"},
+                            {"name": "PAYMENT.CAPTURE.COMPLETED"}
+                        ]
+                    }])
+                );
+            }
+            other => panic!("expected an update, got {other:?}"),
+        }
+
+        // Subscribing again to the same event types leaves the webhook unchanged.
+        assert!(matches!(
+            WebhookChange::for_webhooks(
+                &webhooks,
+                CreateWebhookDto::subscribe(
+                    "https://example.com/example_webhook",
+                    &["PAYMENT.AUTHORIZATION.CREATED"],
+                ),
+            ),
+            WebhookChange::Unchanged(_)
+        ));
+        assert!(matches!(
+            WebhookChange::for_webhooks(
+                &webhooks,
+                CreateWebhookDto::subscribe(
+                    "https://example.com/another_example_webhook",
+                    &["PAYMENT.CAPTURE.COMPLETED"],
+                ),
+            ),
+            WebhookChange::Unchanged(_)
+        ));
+    }
+
+    #[test]
+    fn simulate_webhook_event_response() {
+        let response: SimulateWebhookEventResponse = serde_json::from_str(
+            r#"{
+                "id": "8PT597110X687430LKGECATA",
+                "create_time": "2013-06-25T21:41:28Z",
+                "status": "CREATED",
+                "resource_type": "authorization",
+                "event_type": "PAYMENT.AUTHORIZATION.CREATED",
+                "summary": "A payment authorization was created",
+                "resource": {
+                    "id": "2DC87612EK520411B",
+                    "create_time": "2013-06-25T21:39:15Z",
+                    "update_time": "2013-06-25T21:39:17Z",
+                    "state": "authorized",
+                    "amount": {
+                        "total": "7.47",
+                        "currency": "USD",
+                        "details": {"subtotal": "7.47"}
+                    },
+                    "parent_payment": "PAY-36246664YD343335CKHFA4AY",
+                    "valid_until": "2013-07-24T21:39:15Z"
+                },
+                "links": [
+                    {
+                        "href": "https://api-m.paypal.com/v1/notifications/webhooks-events/8PT597110X687430LKGECATA",
+                        "rel": "self",
+                        "method": "GET"
+                    },
+                    {
+                        "href": "https://api-m.paypal.com/v1/notifications/webhooks-events/8PT597110X687430LKGECATA/resend",
+                        "rel": "resend",
+                        "method": "POST"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(response.is_completed());
+        assert_eq!(response.status.as_deref(), Some("CREATED"));
+        assert_eq!(
+            response.create_time,
+            Some(Utc.with_ymd_and_hms(2013, 6, 25, 21, 41, 28).unwrap())
+        );
+        assert_eq!(
+            response.event_type.as_deref(),
+            Some("PAYMENT.AUTHORIZATION.CREATED")
+        );
+        assert_eq!(response.resource.unwrap()["id"], "2DC87612EK520411B");
+
+        let response: SimulateWebhookEventResponse =
+            serde_json::from_str(r#"{"id": "8PT597110X687430LKGECATA"}"#).unwrap();
+        assert!(!response.is_completed());
+        assert_eq!(response.create_time, None);
+    }
+}
@@ -0,0 +1,60 @@
+//! Assertion macros for tests against error conditions. Enabled by the `test-utils` feature.
+
+/// Asserts that a `PayPalError` is an API error with the status code `404 Not Found`.
+///
+/// ```
+/// use paypal_rust::client::PayPalError;
+/// use paypal_rust::assert_is_not_found;
+///
+/// let error = PayPalError::ApiError {
+///     status: 404,
+///     name: "RESOURCE_NOT_FOUND".to_string(),
+///     message: "The specified resource does not exist.".to_string(),
+///     debug_id: None,
+///     details: Vec::new(),
+/// };
+/// assert_is_not_found!(error);
+/// ```
+#[macro_export]
+macro_rules! assert_is_not_found {
+    ($err:expr) => {
+        match &$err {
+            $crate::client::PayPalError::ApiError { status: 404, .. } => {}
+            other => panic!("expected a 404 Not Found API error, got: {other:?}"),
+        }
+    };
+}
+
+/// Asserts that a `PayPalError` is an API error with a detail that has the expected issue.
+///
+/// ```
+/// use paypal_rust::client::{ErrorDetails, PayPalError};
+/// use paypal_rust::assert_is_validation_error;
+///
+/// let error = PayPalError::ApiError {
+///     status: 422,
+///     name: "UNPROCESSABLE_ENTITY".to_string(),
+///     message: "The requested action could not be performed.".to_string(),
+///     debug_id: None,
+///     details: vec![ErrorDetails {
+///         issue: Some("ORDER_ALREADY_CAPTURED".to_string()),
+///         ..Default::default()
+///     }],
+/// };
+/// assert_is_validation_error!(error, "ORDER_ALREADY_CAPTURED");
+/// ```
+#[macro_export]
+macro_rules! assert_is_validation_error {
+    ($err:expr, $expected_issue:expr) => {
+        match &$err {
+            $crate::client::PayPalError::ApiError { details, .. }
+                if details
+                    .iter()
+                    .any(|detail| detail.issue.as_deref() == Some($expected_issue)) => {}
+            other => panic!(
+                "expected an API error with the issue {:?}, got: {other:?}",
+                $expected_issue
+            ),
+        }
+    };
+}
@@ -1,18 +1,11 @@
-use crate::Order;
+use crate::{HateoasLinks, LinkRel, Order};
 
 impl Order {
     /// Finds the `approve` URL in an order's HATEOAS links. If the `approve` URL is not found, then
     /// `None` is returned. The URL is used to redirect the user to PayPal to approve the
     /// order.
     pub fn get_approval_url(&self) -> Option<String> {
-        Some(
-            self.links
-                .as_ref()?
-                .iter()
-                .find(|link| link.rel == "approve")?
-                .href
-                .clone(),
-        )
+        Some(self.link(LinkRel::Approve)?.href.clone())
     }
 }
 
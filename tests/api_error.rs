@@ -0,0 +1,53 @@
+//! Checks that errors returned by the PayPal API are propagated with all their details. Talks to
+//! the PayPal sandbox, so it needs the `CLIENT_ID` and `CLIENT_SECRET` environment variables:
+//!
+//! `cargo test --features test-utils -- --ignored`
+#![cfg(feature = "test-utils")]
+
+use dotenv::dotenv;
+use paypal_rust::client::PayPalError;
+use paypal_rust::{
+    AmountWithBreakdown, Client, CreateOrderDto, CurrencyCode, Environment, Order, OrderIntent,
+    PurchaseUnitRequest,
+};
+
+#[tokio::test]
+#[ignore = "requires PayPal sandbox credentials"]
+async fn invalid_order_returns_api_error_with_details() {
+    dotenv().ok();
+    let username = std::env::var("CLIENT_ID").expect("CLIENT_ID must be set");
+    let password = std::env::var("CLIENT_SECRET").expect("CLIENT_SECRET must be set");
+
+    let client = Client::new(username, password, Environment::Sandbox).unwrap();
+    client.authenticate().await.unwrap();
+
+    let error = Order::create(
+        &client,
+        CreateOrderDto {
+            intent: OrderIntent::Capture,
+            payer: None,
+            payment_source: None,
+            purchase_units: vec![PurchaseUnitRequest::new(AmountWithBreakdown::new(
+                CurrencyCode::Euro,
+                "not-a-number".to_string(),
+            ))],
+            application_context: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    match error {
+        PayPalError::ApiError {
+            status,
+            debug_id,
+            details,
+            ..
+        } => {
+            assert!((400..500).contains(&status));
+            assert!(!debug_id.unwrap_or_default().is_empty());
+            assert!(!details.is_empty());
+        }
+        other => panic!("expected an API error, got: {other:?}"),
+    }
+}